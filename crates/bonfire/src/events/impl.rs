@@ -21,7 +21,9 @@ impl Cache {
     pub async fn can_view_channel(&self, db: &Database, channel: &Channel) -> bool {
         #[allow(deprecated)]
         match &channel {
-            Channel::TextChannel { server, .. } => {
+            Channel::TextChannel { server, .. }
+            | Channel::ForumChannel { server, .. }
+            | Channel::Thread { server, .. } => {
                 let member = self.members.get(server);
                 let server = self.servers.get(server);
                 let mut query =
@@ -29,6 +31,12 @@ impl Cache {
                         .channel(channel);
                 // let mut perms = perms(self.users.get(&self.user_id).unwrap()).channel(channel);
 
+                if let Channel::Thread { parent, .. } = channel {
+                    if let Some(parent) = self.channels.get(parent) {
+                        query = query.parent(parent);
+                    }
+                }
+
                 if let Some(member) = member {
                     query = query.member(member);
                 }
@@ -142,7 +150,21 @@ impl State {
         channels.append(&mut db.fetch_channels(&channel_ids).await?);
 
         // Filter server channels by permission.
-        let channels = self.cache.filter_accessible_channels(db, channels).await;
+        let mut channels = self.cache.filter_accessible_channels(db, channels).await;
+
+        // Fetch active threads and forum posts in visible server channels.
+        let parent_ids: Vec<String> = channels
+            .iter()
+            .filter(|channel| {
+                matches!(
+                    channel,
+                    Channel::TextChannel { .. } | Channel::ForumChannel { .. }
+                )
+            })
+            .map(|channel| channel.id().to_string())
+            .collect();
+
+        channels.append(&mut db.fetch_threads(&parent_ids, false).await?);
 
         // Append known user IDs from DMs.
         for channel in &channels {
@@ -375,9 +397,19 @@ impl State {
 
             if !unknowns.is_empty() {
                 if let Ok(channels) = db.fetch_channels(&unknowns).await {
-                    let viewable_channels =
+                    let mut viewable_channels =
                         self.cache.filter_accessible_channels(db, channels).await;
 
+                    // Threads are dropped alongside their parent, bring them back too.
+                    let parent_ids: Vec<String> = viewable_channels
+                        .iter()
+                        .map(|channel| channel.id().to_string())
+                        .collect();
+
+                    if let Ok(mut threads) = db.fetch_threads(&parent_ids, false).await {
+                        viewable_channels.append(&mut threads);
+                    }
+
                     for channel in viewable_channels {
                         self.cache
                             .channels
@@ -514,6 +546,38 @@ impl State {
                     self.remove_subscription(user).await;
                 }
             }
+            EventV1::ThreadCreate(channel) => {
+                let channel: Channel = channel.clone().into();
+                if !self.cache.can_view_channel(db, &channel).await {
+                    return false;
+                }
+
+                let id = channel.id().to_string();
+                self.insert_subscription(id.clone()).await;
+                self.cache.channels.insert(id, channel);
+            }
+            EventV1::ThreadUpdate { id, data, clear } => {
+                // Thread events are sent to the whole server, ignore unknown threads.
+                let Some(channel) = self.cache.channels.get_mut(id) else {
+                    return false;
+                };
+
+                for field in clear {
+                    channel.remove_field(&field.clone().into());
+                }
+
+                channel.apply_options(data.clone().into());
+            }
+            EventV1::ThreadArchive { id, archived } => {
+                let Some(Channel::Thread {
+                    archived: current, ..
+                }) = self.cache.channels.get_mut(id)
+                else {
+                    return false;
+                };
+
+                *current = *archived;
+            }
 
             EventV1::ServerCreate {
                 id,
@@ -266,6 +266,12 @@ pub struct ApiAuditLogs {
     pub expires_after: u64,
 }
 
+#[derive(Deserialize, Debug, Clone)]
+pub struct ApiSearch {
+    /// Full-text search backend, either "database" or "embedded"
+    pub backend: String,
+}
+
 #[derive(Deserialize, Debug, Clone)]
 pub struct Api {
     pub registration: ApiRegistration,
@@ -275,6 +281,7 @@ pub struct Api {
     pub livekit: ApiLiveKit,
     pub users: ApiUsers,
     pub audit_logs: ApiAuditLogs,
+    pub search: ApiSearch,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -380,6 +387,9 @@ pub struct GlobalLimits {
     pub server_emoji: usize,
     pub server_roles: usize,
     pub server_channels: usize,
+    pub forum_tags: usize,
+    pub poll_options: usize,
+    pub message_revisions: usize,
 
     pub new_user_hours: usize,
 
@@ -463,6 +473,7 @@ pub struct Sentry {
     pub proxy: String,
     pub pushd: String,
     pub crond: String,
+    pub eventd: String,
     pub gifbox: String,
 }
 
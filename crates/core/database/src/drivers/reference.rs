@@ -3,9 +3,10 @@ use std::{collections::HashMap, sync::Arc};
 use futures::lock::Mutex;
 
 use crate::{
-    Account, AccountInvite, AuditLogEntry, Bot, Channel, ChannelCompositeKey, ChannelUnread, Emoji,
-    File, FileHash, Invite, MFATicket, Member, MemberCompositeKey, Message, PolicyChange,
-    RatelimitEvent, Report, Server, ServerBan, Session, Snapshot, User, UserSettings, Webhook,
+    Account, AccountInvite, AuditLogEntry, Bot, BotCommand, Channel, ChannelCompositeKey,
+    ChannelExport, ChannelUnread, Emoji, EventSubscription, File, FileHash, Invite, MFATicket,
+    Member, MemberCompositeKey, Message, PolicyChange, RatelimitEvent, Report, ScheduledMessage,
+    Server, ServerBan, ServerTemplate, Session, Snapshot, User, UserSettings, Webhook,
 };
 
 database_derived!(
@@ -14,11 +15,14 @@ database_derived!(
     pub struct ReferenceDb {
         pub audit_logs: Arc<Mutex<HashMap<String, AuditLogEntry>>>,
         pub bots: Arc<Mutex<HashMap<String, Bot>>>,
+        pub bot_commands: Arc<Mutex<HashMap<String, BotCommand>>>,
         pub channels: Arc<Mutex<HashMap<String, Channel>>>,
+        pub channel_exports: Arc<Mutex<HashMap<String, ChannelExport>>>,
         pub channel_invites: Arc<Mutex<HashMap<String, Invite>>>,
         pub channel_unreads: Arc<Mutex<HashMap<ChannelCompositeKey, ChannelUnread>>>,
         pub channel_webhooks: Arc<Mutex<HashMap<String, Webhook>>>,
         pub emojis: Arc<Mutex<HashMap<String, Emoji>>>,
+        pub event_subscriptions: Arc<Mutex<HashMap<String, EventSubscription>>>,
         pub file_hashes: Arc<Mutex<HashMap<String, FileHash>>>,
         pub files: Arc<Mutex<HashMap<String, File>>>,
         pub messages: Arc<Mutex<HashMap<String, Message>>>,
@@ -28,9 +32,11 @@ database_derived!(
         pub users: Arc<Mutex<HashMap<String, User>>>,
         pub server_bans: Arc<Mutex<HashMap<MemberCompositeKey, ServerBan>>>,
         pub server_members: Arc<Mutex<HashMap<MemberCompositeKey, Member>>>,
+        pub server_templates: Arc<Mutex<HashMap<String, ServerTemplate>>>,
         pub servers: Arc<Mutex<HashMap<String, Server>>>,
         pub safety_reports: Arc<Mutex<HashMap<String, Report>>>,
         pub safety_snapshots: Arc<Mutex<HashMap<String, Snapshot>>>,
+        pub scheduled_messages: Arc<Mutex<HashMap<String, ScheduledMessage>>>,
         pub accounts: Arc<Mutex<HashMap<String, Account>>>,
         pub account_invites: Arc<Mutex<HashMap<String, AccountInvite>>>,
         pub sessions: Arc<Mutex<HashMap<String, Session>>>,
@@ -3,12 +3,12 @@ use revolt_result::Error;
 use serde::{Deserialize, Serialize};
 
 use revolt_models::v0::{
-    AppendMessage, Channel, ChannelSlowmode, ChannelUnread, ChannelVoiceState, Emoji,
-    FieldsChannel, FieldsMember, FieldsMessage, FieldsRole, FieldsServer, FieldsUser,
-    FieldsWebhook, Member, MemberCompositeKey, Message, PartialChannel, PartialEmoji,
-    PartialMember, PartialMessage, PartialRole, PartialServer, PartialUser, PartialUserVoiceState,
-    PartialWebhook, PolicyChange, RemovalIntention, Report, Server, User, UserSettings,
-    UserVoiceState, Webhook,
+    AppendMessage, Channel, ChannelExport, ChannelSlowmode, ChannelUnread, ChannelVoiceState,
+    CommandInvocation, Emoji, FieldsChannel, FieldsMember, FieldsMessage, FieldsRole, FieldsServer,
+    FieldsUser, FieldsWebhook, Interaction, Member, MemberCompositeKey, Message, PartialChannel,
+    PartialEmoji, PartialMember, PartialMessage, PartialRole, PartialServer, PartialUser,
+    PartialUserVoiceState, PartialWebhook, PolicyChange, RemovalIntention, Report, Server, User,
+    UserSettings, UserVoiceState, Webhook,
 };
 
 use crate::{Account, Database, Session};
@@ -148,6 +148,24 @@ pub enum EventV1 {
         emoji_id: String,
     },
 
+    /// Vote cast or retracted on a message's poll
+    ///
+    /// `user_id` is omitted for anonymous polls
+    MessagePollVote {
+        id: String,
+        channel_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        user_id: Option<String>,
+        option_id: String,
+        voted: bool,
+    },
+
+    /// Component on a bot's message was used
+    InteractionCreate(Interaction),
+
+    /// Command belonging to a bot was invoked
+    CommandInvoke(CommandInvocation),
+
     /// Bulk delete messages
     BulkMessageDelete {
         channel: String,
@@ -298,6 +316,35 @@ pub enum EventV1 {
         user: String,
     },
 
+    /// New thread
+    ThreadCreate(Channel),
+
+    /// Update existing thread
+    ThreadUpdate {
+        id: String,
+        data: PartialChannel,
+        #[serde(default)]
+        clear: Vec<FieldsChannel>,
+    },
+
+    /// Thread archived or unarchived
+    ThreadArchive {
+        id: String,
+        archived: bool,
+    },
+
+    /// User follows a thread
+    ThreadMemberJoin {
+        id: String,
+        user: String,
+    },
+
+    /// User unfollows a thread
+    ThreadMemberLeave {
+        id: String,
+        user: String,
+    },
+
     /// User started typing in a channel
     ChannelStartTyping {
         id: String,
@@ -317,6 +364,16 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// Recipient read up to a message in a direct message or group
+    ChannelReceipt {
+        id: String,
+        user: String,
+        message_id: String,
+    },
+
+    /// Channel export progressed
+    ChannelExportUpdate(ChannelExport),
+
     /// New webhook
     WebhookCreate(Webhook),
 
@@ -178,6 +178,7 @@ pub mod util;
 pub use models::*;
 
 pub mod events;
+pub mod search;
 #[cfg(feature = "tasks")]
 pub mod tasks;
 
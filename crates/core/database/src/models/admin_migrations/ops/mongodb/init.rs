@@ -113,6 +113,26 @@ pub async fn create_database(db: &MongoDb) {
         .await
         .expect("Failed to create mfa_tickets collection.");
 
+    db.create_collection("scheduled_messages")
+        .await
+        .expect("Failed to create scheduled_messages collection.");
+
+    db.create_collection("channel_exports")
+        .await
+        .expect("Failed to create channel_exports collection.");
+
+    db.create_collection("bot_commands")
+        .await
+        .expect("Failed to create bot_commands collection.");
+
+    db.create_collection("event_subscriptions")
+        .await
+        .expect("Failed to create event_subscriptions collection.");
+
+    db.create_collection("server_templates")
+        .await
+        .expect("Failed to create server_templates collection.");
+
     db.run_command(doc! {
         "createIndexes": "users",
         "indexes": [
@@ -144,6 +164,21 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create username index.");
 
+    db.run_command(doc! {
+        "createIndexes": "channels",
+        "indexes": [
+            {
+                "key": {
+                    "parent": 1_i32
+                },
+                "name": "parent",
+                "sparse": true
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create channels index.");
+
     db.run_command(doc! {
         "createIndexes": "messages",
         "indexes": [
@@ -173,6 +208,20 @@ pub async fn create_database(db: &MongoDb) {
                 },
                 "name": "channel_pinned_compound"
             },
+            {
+                "key": {
+                    "poll.expires_at": 1_i32
+                },
+                "name": "poll_expires_at",
+                "sparse": true
+            },
+            {
+                "key": {
+                    "expires_at": 1_i32
+                },
+                "name": "expires_at",
+                "sparse": true
+            },
         ]
     })
     .await
@@ -214,6 +263,13 @@ pub async fn create_database(db: &MongoDb) {
                     "_id.user": 1_i32,
                 },
                 "name": "user_id"
+            },
+            {
+                "key": {
+                    "role_expiries.expires_at": 1_i32,
+                },
+                "name": "role_expiries",
+                "sparse": true
             }
         ]
     })
@@ -330,6 +386,106 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create audit_logs index");
 
+    db.run_command(doc! {
+        "createIndexes": "scheduled_messages",
+        "indexes": [
+            {
+                "key": {
+                    "send_at": 1_i32
+                },
+                "name": "send_at"
+            },
+            {
+                "key": {
+                    "channel": 1_i32,
+                    "author": 1_i32
+                },
+                "name": "channel_author_compound"
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create scheduled_messages index.");
+
+    db.run_command(doc! {
+        "createIndexes": "channel_exports",
+        "indexes": [
+            {
+                "key": {
+                    "status": 1_i32
+                },
+                "name": "status"
+            },
+            {
+                "key": {
+                    "channel": 1_i32,
+                    "requester": 1_i32
+                },
+                "name": "channel_requester_compound"
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create channel_exports index.");
+
+    db.run_command(doc! {
+        "createIndexes": "bot_commands",
+        "indexes": [
+            {
+                "key": {
+                    "bot": 1_i32,
+                    "server": 1_i32
+                },
+                "name": "bot_server_compound"
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create bot_commands index.");
+
+    db.run_command(doc! {
+        "createIndexes": "event_subscriptions",
+        "indexes": [
+            {
+                "key": {
+                    "bot": 1_i32
+                },
+                "name": "bot"
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create event_subscriptions index.");
+
+    db.run_command(doc! {
+        "createIndexes": "server_templates",
+        "indexes": [
+            {
+                "key": {
+                    "server": 1_i32
+                },
+                "name": "server"
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create server_templates index.");
+
+    db.run_command(doc! {
+        "createIndexes": "channel_webhooks",
+        "indexes": [
+            {
+                "key": {
+                    "source_channel_id": 1_i32
+                },
+                "name": "source_channel_id",
+                "sparse": true
+            }
+        ]
+    })
+    .await
+    .expect("Failed to create channel_webhooks index.");
+
     db.run_command(doc! {
         "createIndexes": "accounts",
         "indexes": [
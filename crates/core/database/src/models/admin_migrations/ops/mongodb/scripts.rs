@@ -26,7 +26,7 @@ struct MigrationInfo {
     revision: i32,
 }
 
-pub const LATEST_REVISION: i32 = 52; // MUST BE +1 to last migration
+pub const LATEST_REVISION: i32 = 62; // MUST BE +1 to last migration
 
 pub async fn migrate_database(db: &MongoDb) {
     let migrations = db.col::<Document>("migrations");
@@ -1529,6 +1529,241 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
             .expect("Failed to create audit_logs index");
     };
 
+    if revision <= 52 {
+        info!("Running migration [revision 52 / 17-10-2026]: Add index for message threads");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "channels",
+                "indexes": [
+                    {
+                        "key": {
+                            "parent": 1_i32,
+                        },
+                        "name": "parent",
+                        "sparse": true,
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create channels index");
+    }
+
+    if revision <= 53 {
+        info!("Running migration [revision 53 / 17-10-2026]: Add index for poll expiry");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "messages",
+                "indexes": [
+                    {
+                        "key": {
+                            "poll.expires_at": 1_i32,
+                        },
+                        "name": "poll_expires_at",
+                        "sparse": true,
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create messages index");
+    }
+
+    if revision <= 54 {
+        info!("Running migration [revision 54 / 17-10-2026]: Add scheduled messages collection");
+
+        db.db()
+            .create_collection("scheduled_messages")
+            .await
+            .expect("Failed to create scheduled_messages collection");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "scheduled_messages",
+                "indexes": [
+                    {
+                        "key": {
+                            "send_at": 1_i32,
+                        },
+                        "name": "send_at",
+                    },
+                    {
+                        "key": {
+                            "channel": 1_i32,
+                            "author": 1_i32,
+                        },
+                        "name": "channel_author_compound",
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create scheduled_messages index");
+    }
+
+    if revision <= 55 {
+        info!("Running migration [revision 55 / 17-10-2026]: Add index for message expiry");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "messages",
+                "indexes": [
+                    {
+                        "key": {
+                            "expires_at": 1_i32,
+                        },
+                        "name": "expires_at",
+                        "sparse": true,
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create messages index");
+    }
+
+    if revision <= 56 {
+        info!("Running migration [revision 56 / 17-10-2026]: Add index for announcement channel followers");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "channel_webhooks",
+                "indexes": [
+                    {
+                        "key": {
+                            "source_channel_id": 1_i32,
+                        },
+                        "name": "source_channel_id",
+                        "sparse": true,
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create channel_webhooks index");
+    }
+
+    if revision <= 57 {
+        info!("Running migration [revision 57 / 17-10-2026]: Add channel exports collection");
+
+        db.db()
+            .create_collection("channel_exports")
+            .await
+            .expect("Failed to create channel_exports collection");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "channel_exports",
+                "indexes": [
+                    {
+                        "key": {
+                            "status": 1_i32,
+                        },
+                        "name": "status",
+                    },
+                    {
+                        "key": {
+                            "channel": 1_i32,
+                            "requester": 1_i32,
+                        },
+                        "name": "channel_requester_compound",
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create channel_exports index");
+    }
+
+    if revision <= 58 {
+        info!("Running migration [revision 58 / 17-10-2026]: Add bot commands collection");
+
+        db.db()
+            .create_collection("bot_commands")
+            .await
+            .expect("Failed to create bot_commands collection");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "bot_commands",
+                "indexes": [
+                    {
+                        "key": {
+                            "bot": 1_i32,
+                            "server": 1_i32,
+                        },
+                        "name": "bot_server_compound",
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create bot_commands index");
+    }
+
+    if revision <= 59 {
+        info!("Running migration [revision 59 / 17-10-2026]: Add event subscriptions collection");
+
+        db.db()
+            .create_collection("event_subscriptions")
+            .await
+            .expect("Failed to create event_subscriptions collection");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "event_subscriptions",
+                "indexes": [
+                    {
+                        "key": {
+                            "bot": 1_i32,
+                        },
+                        "name": "bot",
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create event_subscriptions index");
+    }
+
+    if revision <= 60 {
+        info!("Running migration [revision 60 / 17-10-2026]: Add server templates collection");
+
+        db.db()
+            .create_collection("server_templates")
+            .await
+            .expect("Failed to create server_templates collection");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "server_templates",
+                "indexes": [
+                    {
+                        "key": {
+                            "server": 1_i32,
+                        },
+                        "name": "server",
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create server_templates index");
+    }
+
+    if revision <= 61 {
+        info!("Running migration [revision 61 / 17-10-2026]: Add index for member role expiries");
+
+        db.db()
+            .run_command(doc! {
+                "createIndexes": "server_members",
+                "indexes": [
+                    {
+                        "key": {
+                            "role_expiries.expires_at": 1_i32,
+                        },
+                        "name": "role_expiries",
+                        "sparse": true,
+                    },
+                ]
+            })
+            .await
+            .expect("Failed to create role_expiries index");
+    }
+
     // Reminder to update LATEST_REVISION when adding new migrations.
     LATEST_REVISION.max(revision)
 }
@@ -4,7 +4,7 @@ use iso8601_timestamp::Timestamp;
 use revolt_config::config;
 use ulid::Ulid;
 
-use crate::{Database, PartialChannel, PartialMember, PartialRole, PartialServer, User, PartialEmoji};
+use crate::{Category, Database, PartialChannel, PartialMember, PartialRole, PartialServer, User, PartialEmoji};
 use revolt_models::v0;
 use revolt_permissions::OverrideField;
 use revolt_result::Result;
@@ -75,6 +75,11 @@ auto_derived!(
             role: String,
             permissions: OverrideField,
         },
+        ChannelUserPermissionsEdit {
+            channel: String,
+            user: String,
+            permissions: Option<OverrideField>,
+        },
         ChannelDelete {
             channel: String,
             name: String,
@@ -139,6 +144,24 @@ auto_derived!(
             emoji: String,
             name: String,
         },
+        CategoryCreate {
+            category: String,
+            title: String,
+        },
+        CategoryEdit {
+            category: String,
+            before: Category,
+            after: Category,
+        },
+        CategoryRolePermissionsEdit {
+            category: String,
+            role: String,
+            permissions: OverrideField,
+        },
+        CategoryDelete {
+            category: String,
+            title: String,
+        },
     }
 
     /// Audit Log Query
@@ -249,6 +272,9 @@ impl AuditLogEntry {
                 AuditLogEntryAction::MessageBulkDelete { .. } => {}
                 AuditLogEntryAction::ChannelEdit { .. } => {}
                 AuditLogEntryAction::ChannelRolePermissionsEdit { .. } => {}
+                AuditLogEntryAction::ChannelUserPermissionsEdit { user, .. } => {
+                    user_ids.insert(user.clone());
+                }
                 AuditLogEntryAction::ChannelDelete { .. } => {}
                 AuditLogEntryAction::InviteCreate { .. } => {}
                 AuditLogEntryAction::InviteDelete { .. } => {}
@@ -257,6 +283,10 @@ impl AuditLogEntry {
                 AuditLogEntryAction::EmojiCreate { .. } => {}
                 AuditLogEntryAction::EmojiUpdate { .. } => {}
                 AuditLogEntryAction::EmojiDelete { .. } => {}
+                AuditLogEntryAction::CategoryCreate { .. } => {}
+                AuditLogEntryAction::CategoryEdit { .. } => {}
+                AuditLogEntryAction::CategoryRolePermissionsEdit { .. } => {}
+                AuditLogEntryAction::CategoryDelete { .. } => {}
             };
         }
 
@@ -0,0 +1,148 @@
+use revolt_models::v0::{self, CommandOption, CommandOptionType, CommandOptionValue};
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{Channel, Database};
+
+/// Maximum number of commands a bot may register in a single scope
+pub const MAX_BOT_COMMANDS: usize = 100;
+
+auto_derived_partial!(
+    /// Command which can be invoked on a bot
+    pub struct BotCommand {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the bot this command belongs to
+        pub bot: String,
+        /// Id of the server this command is limited to
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub server: Option<String>,
+        /// Command name
+        pub name: String,
+        /// Command description
+        pub description: String,
+        /// Options which can be passed to this command
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub options: Vec<CommandOption>,
+    },
+    "PartialBotCommand"
+);
+
+impl BotCommand {
+    /// Register a new command for a bot
+    pub async fn create(
+        db: &Database,
+        bot_id: &str,
+        data: v0::DataCreateCommand,
+    ) -> Result<BotCommand> {
+        // Names must be unique within the scope the command is available in
+        let existing: Vec<BotCommand> = db
+            .fetch_bot_commands(bot_id)
+            .await?
+            .into_iter()
+            .filter(|command| command.server == data.server)
+            .collect();
+
+        if existing.len() >= MAX_BOT_COMMANDS {
+            return Err(create_error!(TooManyBotCommands {
+                max: MAX_BOT_COMMANDS
+            }));
+        }
+
+        if existing.iter().any(|command| command.name == data.name) {
+            return Err(create_error!(CommandAlreadyExists));
+        }
+
+        let command = BotCommand {
+            id: Ulid::new().to_string(),
+            bot: bot_id.to_string(),
+            server: data.server,
+            name: data.name,
+            description: data.description,
+            options: data.options,
+        };
+
+        db.insert_bot_command(&command).await?;
+        Ok(command)
+    }
+
+    /// Update command data
+    pub async fn update(&mut self, db: &Database, partial: PartialBotCommand) -> Result<()> {
+        self.apply_options(partial.clone());
+        db.update_bot_command(&self.id, &partial).await
+    }
+
+    /// Delete this command
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_bot_command(&self.id).await
+    }
+
+    /// Check options given for an invocation match this command's options
+    pub async fn validate_options(
+        &self,
+        db: &Database,
+        channel: &Channel,
+        options: &[v0::CommandOptionData],
+    ) -> Result<()> {
+        for (index, given) in options.iter().enumerate() {
+            if options[..index]
+                .iter()
+                .any(|other| other.name == given.name)
+            {
+                return Err(create_error!(InvalidProperty));
+            }
+
+            let option = self
+                .options
+                .iter()
+                .find(|option| option.name == given.name)
+                .ok_or_else(|| create_error!(InvalidProperty))?;
+
+            if !given.value.is_type(&option.option_type) {
+                return Err(create_error!(InvalidProperty));
+            }
+
+            if let Some(choices) = &option.choices {
+                if !choices.iter().any(|choice| choice.value == given.value) {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+
+            // Referenced objects must exist and, for channels and roles, be in the same server
+            if let CommandOptionValue::String(id) = &given.value {
+                match option.option_type {
+                    CommandOptionType::User => {
+                        db.fetch_user(id).await?;
+                    }
+                    CommandOptionType::Channel => {
+                        let target = db.fetch_channel(id).await?;
+                        if target.server() != channel.server() || target.server().is_none() {
+                            return Err(create_error!(InvalidProperty));
+                        }
+                    }
+                    CommandOptionType::Role => {
+                        let server = channel
+                            .server()
+                            .ok_or_else(|| create_error!(InvalidProperty))?;
+
+                        if !db.fetch_server(server).await?.roles.contains_key(id) {
+                            return Err(create_error!(InvalidProperty));
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if self
+            .options
+            .iter()
+            .any(|option| option.required && !options.iter().any(|o| o.name == option.name))
+        {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use revolt_result::Result;
+
+use crate::{BotCommand, PartialBotCommand};
+
+#[cfg(feature = "mongodb")]
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractBotCommands: Sync + Send {
+    /// Insert a new command into the database
+    async fn insert_bot_command(&self, command: &BotCommand) -> Result<()>;
+
+    /// Fetch a command by its id
+    async fn fetch_bot_command(&self, id: &str) -> Result<BotCommand>;
+
+    /// Fetch all commands belonging to a bot
+    async fn fetch_bot_commands(&self, bot_id: &str) -> Result<Vec<BotCommand>>;
+
+    /// Fetch commands of the given bots which are available in a server
+    ///
+    /// This includes their global commands and those limited to the server.
+    async fn fetch_server_commands(
+        &self,
+        bot_ids: &[String],
+        server_id: Option<&str>,
+    ) -> Result<Vec<BotCommand>>;
+
+    /// Update a given command with new information
+    async fn update_bot_command(&self, id: &str, partial: &PartialBotCommand) -> Result<()>;
+
+    /// Delete a command by its id
+    async fn delete_bot_command(&self, id: &str) -> Result<()>;
+
+    /// Delete all commands belonging to a bot
+    async fn delete_bot_commands(&self, bot_id: &str) -> Result<()>;
+
+    /// Delete commands a bot registered for a specific server
+    async fn delete_server_bot_commands(&self, bot_id: &str, server_id: &str) -> Result<()>;
+}
@@ -0,0 +1,87 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{BotCommand, PartialBotCommand};
+
+use super::AbstractBotCommands;
+
+static COL: &str = "bot_commands";
+
+#[async_trait]
+impl AbstractBotCommands for MongoDb {
+    /// Insert a new command into the database
+    async fn insert_bot_command(&self, command: &BotCommand) -> Result<()> {
+        query!(self, insert_one, COL, &command).map(|_| ())
+    }
+
+    /// Fetch a command by its id
+    async fn fetch_bot_command(&self, id: &str) -> Result<BotCommand> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all commands belonging to a bot
+    async fn fetch_bot_commands(&self, bot_id: &str) -> Result<Vec<BotCommand>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "bot": bot_id
+            }
+        )
+    }
+
+    /// Fetch commands of the given bots which are available in a server
+    async fn fetch_server_commands(
+        &self,
+        bot_ids: &[String],
+        server_id: Option<&str>,
+    ) -> Result<Vec<BotCommand>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "bot": {
+                    "$in": bot_ids
+                },
+                "server": {
+                    "$in": [null, server_id]
+                }
+            }
+        )
+    }
+
+    /// Update a given command with new information
+    async fn update_bot_command(&self, id: &str, partial: &PartialBotCommand) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Delete a command by its id
+    async fn delete_bot_command(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Delete all commands belonging to a bot
+    async fn delete_bot_commands(&self, bot_id: &str) -> Result<()> {
+        self.col::<BotCommand>(COL)
+            .delete_many(doc! {
+                "bot": bot_id
+            })
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+
+    /// Delete commands a bot registered for a specific server
+    async fn delete_server_bot_commands(&self, bot_id: &str, server_id: &str) -> Result<()> {
+        self.col::<BotCommand>(COL)
+            .delete_many(doc! {
+                "bot": bot_id,
+                "server": server_id
+            })
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
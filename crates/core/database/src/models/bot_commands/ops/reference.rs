@@ -0,0 +1,93 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{BotCommand, PartialBotCommand};
+
+use super::AbstractBotCommands;
+
+#[async_trait]
+impl AbstractBotCommands for ReferenceDb {
+    /// Insert a new command into the database
+    async fn insert_bot_command(&self, command: &BotCommand) -> Result<()> {
+        let mut bot_commands = self.bot_commands.lock().await;
+        if bot_commands.contains_key(&command.id) {
+            Err(create_database_error!("insert", "bot_command"))
+        } else {
+            bot_commands.insert(command.id.to_string(), command.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a command by its id
+    async fn fetch_bot_command(&self, id: &str) -> Result<BotCommand> {
+        let bot_commands = self.bot_commands.lock().await;
+        bot_commands
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all commands belonging to a bot
+    async fn fetch_bot_commands(&self, bot_id: &str) -> Result<Vec<BotCommand>> {
+        let bot_commands = self.bot_commands.lock().await;
+        Ok(bot_commands
+            .values()
+            .filter(|command| command.bot == bot_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch commands of the given bots which are available in a server
+    async fn fetch_server_commands(
+        &self,
+        bot_ids: &[String],
+        server_id: Option<&str>,
+    ) -> Result<Vec<BotCommand>> {
+        let bot_commands = self.bot_commands.lock().await;
+        Ok(bot_commands
+            .values()
+            .filter(|command| {
+                bot_ids.contains(&command.bot)
+                    && (command.server.is_none() || command.server.as_deref() == server_id)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update a given command with new information
+    async fn update_bot_command(&self, id: &str, partial: &PartialBotCommand) -> Result<()> {
+        let mut bot_commands = self.bot_commands.lock().await;
+        if let Some(command) = bot_commands.get_mut(id) {
+            command.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete a command by its id
+    async fn delete_bot_command(&self, id: &str) -> Result<()> {
+        let mut bot_commands = self.bot_commands.lock().await;
+        if bot_commands.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete all commands belonging to a bot
+    async fn delete_bot_commands(&self, bot_id: &str) -> Result<()> {
+        let mut bot_commands = self.bot_commands.lock().await;
+        bot_commands.retain(|_, command| command.bot != bot_id);
+        Ok(())
+    }
+
+    /// Delete commands a bot registered for a specific server
+    async fn delete_server_bot_commands(&self, bot_id: &str, server_id: &str) -> Result<()> {
+        let mut bot_commands = self.bot_commands.lock().await;
+        bot_commands.retain(|_, command| {
+            command.bot != bot_id || command.server.as_deref() != Some(server_id)
+        });
+        Ok(())
+    }
+}
@@ -1,7 +1,9 @@
 use revolt_result::Result;
 use ulid::Ulid;
 
-use crate::{events::client::EventV1, BotInformation, Database, PartialUser, User};
+use crate::{
+    events::client::EventV1, BotInformation, Database, EventSubscription, PartialUser, User,
+};
 
 auto_derived_partial!(
     /// Bot
@@ -25,9 +27,12 @@ auto_derived_partial!(
         /// Whether this bot should be publicly discoverable
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub discoverable: bool,
-        /// Reserved; URL for handling interactions
+        /// URL which component interactions are sent to, in addition to the event
         #[serde(skip_serializing_if = "String::is_empty", default)]
         pub interactions_url: String,
+        /// Secret used to sign requests sent to the interactions URL
+        #[serde(skip_serializing_if = "String::is_empty", default)]
+        pub interactions_secret: String,
         /// URL for terms of service
         #[serde(skip_serializing_if = "String::is_empty", default)]
         pub terms_of_service_url: String,
@@ -47,6 +52,7 @@ auto_derived!(
     pub enum FieldsBot {
         Token,
         InteractionsURL,
+        InteractionsSecret,
     }
 );
 
@@ -61,6 +67,7 @@ impl Default for Bot {
             analytics: Default::default(),
             discoverable: Default::default(),
             interactions_url: Default::default(),
+            interactions_secret: Default::default(),
             terms_of_service_url: Default::default(),
             privacy_policy_url: Default::default(),
             flags: Default::default(),
@@ -107,6 +114,7 @@ impl Bot {
             id,
             owner: owner.id.to_string(),
             token: nanoid::nanoid!(64),
+            interactions_secret: nanoid::nanoid!(64),
             ..Default::default()
         };
 
@@ -125,6 +133,7 @@ impl Bot {
             FieldsBot::InteractionsURL => {
                 self.interactions_url = String::new();
             }
+            FieldsBot::InteractionsSecret => self.interactions_secret = nanoid::nanoid!(64),
         }
     }
 
@@ -139,6 +148,14 @@ impl Bot {
             partial.token = Some(nanoid::nanoid!(64));
         }
 
+        // Bots created before interactions were signed with their own secret
+        // get one as soon as they need it
+        if remove.contains(&FieldsBot::InteractionsSecret)
+            || (partial.interactions_url.is_some() && self.interactions_secret.is_empty())
+        {
+            partial.interactions_secret = Some(nanoid::nanoid!(64));
+        }
+
         for field in &remove {
             self.remove_field(field);
         }
@@ -156,6 +173,9 @@ impl Bot {
     /// Delete this bot
     pub async fn delete(&self, db: &Database) -> Result<()> {
         db.fetch_user(&self.id).await?.mark_deleted(db).await?;
+        db.delete_bot_commands(&self.id).await?;
+        db.delete_event_subscriptions(&self.id).await?;
+        EventSubscription::announce_changes(&self.id).await;
         db.delete_bot(&self.id).await
     }
 }
@@ -194,7 +214,11 @@ mod tests {
                         public: Some(true),
                         ..Default::default()
                     },
-                    vec![FieldsBot::Token, FieldsBot::InteractionsURL],
+                    vec![
+                        FieldsBot::Token,
+                        FieldsBot::InteractionsURL,
+                        FieldsBot::InteractionsSecret,
+                    ],
                 )
                 .await
                 .unwrap();
@@ -208,6 +232,8 @@ mod tests {
             assert!(!bot.interactions_url.is_empty());
             assert!(fetched_bot1.interactions_url.is_empty());
             assert_ne!(bot.token, fetched_bot1.token);
+            assert!(!fetched_bot1.interactions_secret.is_empty());
+            assert_ne!(bot.interactions_secret, fetched_bot1.interactions_secret);
             assert_eq!(updated_bot, fetched_bot1);
             assert_eq!(fetched_bot1, fetched_bot2);
             assert_eq!(fetched_bot1, fetched_bots[0]);
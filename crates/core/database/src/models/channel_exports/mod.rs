@@ -0,0 +1,7 @@
+mod model;
+mod ops;
+mod transcript;
+
+pub use model::*;
+pub use ops::*;
+pub use transcript::*;
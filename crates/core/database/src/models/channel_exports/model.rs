@@ -0,0 +1,134 @@
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{events::client::EventV1, Database, File};
+
+auto_derived_partial!(
+    /// Export of a channel's message history
+    pub struct ChannelExport {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel being exported
+        pub channel: String,
+        /// Id of the user who requested this export
+        pub requester: String,
+        /// Progress of this export
+        pub status: ChannelExportStatus,
+        /// Number of messages included in the export
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message_count: Option<usize>,
+        /// Transcript as a JSON document
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub json: Option<File>,
+        /// Transcript as a self-contained HTML page
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub html: Option<File>,
+    },
+    "PartialChannelExport"
+);
+
+auto_derived!(
+    /// Progress of a channel export
+    pub enum ChannelExportStatus {
+        /// Waiting to be processed
+        Pending,
+        /// Messages are being exported
+        Processing,
+        /// Transcripts are ready to download
+        Complete,
+        /// Export could not be completed
+        Failed,
+    }
+);
+
+impl ChannelExport {
+    /// Request a new export of a channel
+    ///
+    /// If the user already has an export of this channel in progress, that export is returned instead.
+    pub async fn create(db: &Database, channel: &str, requester: &str) -> Result<ChannelExport> {
+        if let Some(export) = db
+            .fetch_channel_exports(channel, requester)
+            .await?
+            .into_iter()
+            .find(|export| {
+                matches!(
+                    export.status,
+                    ChannelExportStatus::Pending | ChannelExportStatus::Processing
+                )
+            })
+        {
+            return Ok(export);
+        }
+
+        let export = ChannelExport {
+            id: Ulid::new().to_string(),
+            channel: channel.to_string(),
+            requester: requester.to_string(),
+            status: ChannelExportStatus::Pending,
+            message_count: None,
+            json: None,
+            html: None,
+        };
+
+        db.insert_channel_export(&export).await?;
+        Ok(export)
+    }
+
+    /// Update channel export data and notify the requester
+    pub async fn update(&mut self, db: &Database, partial: PartialChannelExport) -> Result<()> {
+        self.apply_options(partial.clone());
+        db.update_channel_export(&self.id, &partial).await?;
+
+        EventV1::ChannelExportUpdate(self.clone().into())
+            .private(self.requester.clone())
+            .await;
+
+        Ok(())
+    }
+
+    /// Mark this export as being worked on
+    pub async fn start(&mut self, db: &Database) -> Result<()> {
+        self.update(
+            db,
+            PartialChannelExport {
+                status: Some(ChannelExportStatus::Processing),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Attach the finished transcripts to this export
+    pub async fn complete(
+        &mut self,
+        db: &Database,
+        message_count: usize,
+        json: File,
+        html: File,
+    ) -> Result<()> {
+        self.update(
+            db,
+            PartialChannelExport {
+                status: Some(ChannelExportStatus::Complete),
+                message_count: Some(message_count),
+                json: Some(json),
+                html: Some(html),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Mark this export as failed
+    pub async fn fail(&mut self, db: &Database) -> Result<()> {
+        self.update(
+            db,
+            PartialChannelExport {
+                status: Some(ChannelExportStatus::Failed),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+}
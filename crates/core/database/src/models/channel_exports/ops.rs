@@ -0,0 +1,32 @@
+use revolt_result::Result;
+
+use crate::{ChannelExport, PartialChannelExport};
+
+#[cfg(feature = "mongodb")]
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelExports: Sync + Send {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()>;
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport>;
+
+    /// Fetch exports of a channel requested by a given user
+    async fn fetch_channel_exports(
+        &self,
+        channel: &str,
+        requester: &str,
+    ) -> Result<Vec<ChannelExport>>;
+
+    /// Fetch channel exports waiting to be processed
+    async fn fetch_pending_channel_exports(&self) -> Result<Vec<ChannelExport>>;
+
+    /// Move exports left processing by an interrupted worker back to pending
+    async fn requeue_processing_channel_exports(&self) -> Result<()>;
+
+    /// Update a given channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()>;
+}
@@ -0,0 +1,74 @@
+use bson::Document;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{ChannelExport, PartialChannelExport};
+
+use super::AbstractChannelExports;
+
+static COL: &str = "channel_exports";
+
+#[async_trait]
+impl AbstractChannelExports for MongoDb {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()> {
+        query!(self, insert_one, COL, &export).map(|_| ())
+    }
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch exports of a channel requested by a given user
+    async fn fetch_channel_exports(
+        &self,
+        channel: &str,
+        requester: &str,
+    ) -> Result<Vec<ChannelExport>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "channel": channel,
+                "requester": requester
+            }
+        )
+    }
+
+    /// Fetch channel exports waiting to be processed
+    async fn fetch_pending_channel_exports(&self) -> Result<Vec<ChannelExport>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "status": "Pending"
+            }
+        )
+    }
+
+    /// Move exports left processing by an interrupted worker back to pending
+    async fn requeue_processing_channel_exports(&self) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "status": "Processing"
+                },
+                doc! {
+                    "$set": {
+                        "status": "Pending"
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
+
+    /// Update a given channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+}
@@ -0,0 +1,76 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{ChannelExport, ChannelExportStatus, PartialChannelExport};
+
+use super::AbstractChannelExports;
+
+#[async_trait]
+impl AbstractChannelExports for ReferenceDb {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        if channel_exports.contains_key(&export.id) {
+            Err(create_database_error!("insert", "channel_export"))
+        } else {
+            channel_exports.insert(export.id.to_string(), export.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport> {
+        let channel_exports = self.channel_exports.lock().await;
+        channel_exports
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch exports of a channel requested by a given user
+    async fn fetch_channel_exports(
+        &self,
+        channel: &str,
+        requester: &str,
+    ) -> Result<Vec<ChannelExport>> {
+        let channel_exports = self.channel_exports.lock().await;
+        Ok(channel_exports
+            .values()
+            .filter(|export| export.channel == channel && export.requester == requester)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch channel exports waiting to be processed
+    async fn fetch_pending_channel_exports(&self) -> Result<Vec<ChannelExport>> {
+        let channel_exports = self.channel_exports.lock().await;
+        Ok(channel_exports
+            .values()
+            .filter(|export| export.status == ChannelExportStatus::Pending)
+            .cloned()
+            .collect())
+    }
+
+    /// Move exports left processing by an interrupted worker back to pending
+    async fn requeue_processing_channel_exports(&self) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        for export in channel_exports.values_mut() {
+            if export.status == ChannelExportStatus::Processing {
+                export.status = ChannelExportStatus::Pending;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Update a given channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        if let Some(export) = channel_exports.get_mut(id) {
+            export.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
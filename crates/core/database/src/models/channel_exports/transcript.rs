@@ -0,0 +1,469 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use iso8601_timestamp::Timestamp;
+use revolt_config::config;
+use revolt_models::v0;
+use revolt_result::{Result, ToRevoltError};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use ulid::Ulid;
+
+use crate::{Channel, Database};
+
+/// Version of the JSON transcript format
+///
+/// Increment whenever a breaking change is made to the document structure.
+pub const TRANSCRIPT_VERSION: u32 = 1;
+
+/// Number of messages to read from the database at a time
+const CHUNK_SIZE: usize = 100;
+
+const HTML_STYLE: &str = "body{margin:0;padding:24px;background:#191919;color:#e9e9e9;font-family:sans-serif;font-size:15px}\
+h1{font-size:20px;margin:0 0 4px}\
+.meta{color:#8a8a8a;margin-bottom:24px}\
+.message{padding:8px 0;border-top:1px solid #2a2a2a}\
+.author{font-weight:600}\
+time,.edited{color:#8a8a8a;font-size:12px;margin-left:6px}\
+.content{white-space:pre-wrap;word-wrap:break-word;margin-top:4px}\
+.system{color:#8a8a8a;font-style:italic}\
+.reply{color:#8a8a8a;font-size:13px;border-left:2px solid #3a3a3a;padding-left:6px}\
+.embed{border-left:4px solid #3a3a3a;background:#242424;padding:6px 10px;margin-top:6px;border-radius:4px}\
+.attachments,.reactions{list-style:none;padding:0;margin:6px 0 0}\
+.reactions li{display:inline-block;background:#242424;border-radius:4px;padding:2px 6px;margin-right:4px}\
+a{color:#7ab7ff}";
+
+/// Rendered transcripts of a channel
+pub struct Transcript {
+    /// Number of messages in the transcript
+    pub message_count: usize,
+    /// Versioned JSON document
+    pub json: TranscriptFile,
+    /// Self-contained HTML page
+    pub html: TranscriptFile,
+}
+
+/// Temporary file a transcript is written to as it is rendered
+pub struct TranscriptFile(tokio::fs::File);
+
+impl TranscriptFile {
+    fn new() -> Result<Self> {
+        tempfile::tempfile()
+            .map(|file| TranscriptFile(tokio::fs::File::from_std(file)))
+            .to_internal_error()
+    }
+
+    async fn write(&mut self, buf: &[u8]) -> Result<()> {
+        self.0.write_all(buf).await.to_internal_error()
+    }
+
+    /// Read the whole document back
+    pub async fn into_bytes(mut self) -> Result<Vec<u8>> {
+        self.0.flush().await.to_internal_error()?;
+        self.0.rewind().await.to_internal_error()?;
+
+        let mut buf = Vec::new();
+        self.0.read_to_end(&mut buf).await.to_internal_error()?;
+        Ok(buf)
+    }
+}
+
+/// Escape text for use in HTML
+fn escape(text: &str) -> String {
+    let mut output = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str("&#39;"),
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+/// Get a human readable name for a channel
+fn channel_name(channel: &Channel) -> &str {
+    match channel {
+        Channel::SavedMessages { .. } => "Saved Notes",
+        Channel::DirectMessage { .. } => "Direct Message",
+        Channel::Group { name, .. }
+        | Channel::TextChannel { name, .. }
+        | Channel::ForumChannel { name, .. }
+        | Channel::Thread { name, .. } => name,
+    }
+}
+
+/// Whether a link can be placed in a transcript, only web links are allowed
+fn is_web_url(url: &str) -> bool {
+    let url = url.trim_start().to_ascii_lowercase();
+    url.starts_with("https://") || url.starts_with("http://")
+}
+
+/// Get the time a message was sent at from its id
+fn sent_at(id: &str) -> Timestamp {
+    Ulid::from_string(id)
+        .map(|id| Timestamp::from(id.datetime()))
+        .unwrap_or(Timestamp::UNIX_EPOCH)
+}
+
+/// Incrementally built transcript
+///
+/// Output is buffered per batch of messages and then written out to temporary files.
+struct TranscriptWriter {
+    autumn: String,
+    json: Vec<u8>,
+    html: String,
+    json_file: TranscriptFile,
+    html_file: TranscriptFile,
+    message_count: usize,
+    users: HashMap<String, v0::User>,
+    members: HashMap<String, v0::Member>,
+}
+
+impl TranscriptWriter {
+    fn new(autumn: String, channel: &Channel) -> Result<Self> {
+        let exported_at = Timestamp::now_utc();
+        let channel_model: v0::Channel = channel.clone().into();
+
+        let mut json = format!(r#"{{"version":{TRANSCRIPT_VERSION},"exported_at":"#).into_bytes();
+        serde_json::to_writer(&mut json, &exported_at).to_internal_error()?;
+        json.extend_from_slice(br#","channel":"#);
+        serde_json::to_writer(&mut json, &channel_model).to_internal_error()?;
+        json.extend_from_slice(br#","messages":["#);
+
+        let name = escape(channel_name(channel));
+        let exported_at = exported_at.format();
+        let html = format!(
+            "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{name}</title><style>{HTML_STYLE}</style></head>\
+<body><h1>#{name}</h1><div class=\"meta\">Exported at <time datetime=\"{exported_at}\">{exported_at}</time></div>"
+        );
+
+        Ok(TranscriptWriter {
+            autumn,
+            json,
+            html,
+            json_file: TranscriptFile::new()?,
+            html_file: TranscriptFile::new()?,
+            message_count: 0,
+            users: HashMap::new(),
+            members: HashMap::new(),
+        })
+    }
+
+    /// Fetch any authors of the given messages which haven't been seen yet
+    async fn fetch_authors(
+        &mut self,
+        db: &Database,
+        server: Option<&str>,
+        messages: &[v0::Message],
+    ) -> Result<()> {
+        let ids: Vec<String> = messages
+            .iter()
+            .filter(|message| message.webhook.is_none())
+            .map(|message| message.author.clone())
+            .filter(|id| !self.users.contains_key(id))
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        for user in db.fetch_users(&ids).await? {
+            self.users
+                .insert(user.id.clone(), user.into_known_static(false).await);
+        }
+
+        if let Some(server) = server {
+            for member in db.fetch_members(server, &ids).await? {
+                self.members.insert(member.id.user.clone(), member.into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Name to show for the author of a message
+    fn author_name(&self, message: &v0::Message) -> String {
+        if let Some(name) = message
+            .masquerade
+            .as_ref()
+            .and_then(|masquerade| masquerade.name.clone())
+        {
+            return name;
+        }
+
+        if let Some(webhook) = &message.webhook {
+            return webhook.name.clone();
+        }
+
+        if let Some(nickname) = self
+            .members
+            .get(&message.author)
+            .and_then(|member| member.nickname.clone())
+        {
+            return nickname;
+        }
+
+        match self.users.get(&message.author) {
+            Some(user) => user
+                .display_name
+                .clone()
+                .unwrap_or_else(|| format!("{}#{}", user.username, user.discriminator)),
+            None => "Unknown User".to_string(),
+        }
+    }
+
+    /// Write out everything buffered so far
+    async fn flush(&mut self) -> Result<()> {
+        self.json_file.write(&self.json).await?;
+        self.html_file.write(self.html.as_bytes()).await?;
+        self.json.clear();
+        self.html.clear();
+        Ok(())
+    }
+
+    fn write_message(&mut self, message: &v0::Message) -> Result<()> {
+        if self.message_count > 0 {
+            self.json.push(b',');
+        }
+
+        serde_json::to_writer(&mut self.json, message).to_internal_error()?;
+        self.message_count += 1;
+
+        let sent_at = sent_at(&message.id).format();
+        let author = escape(&self.author_name(message));
+        let html = &mut self.html;
+        write!(
+            html,
+            "<article class=\"message\" id=\"{id}\"><header><span class=\"author\">{author}</span><time datetime=\"{sent_at}\">{sent_at}</time>",
+            id = message.id,
+        )
+        .to_internal_error()?;
+
+        if message.edited.is_some() {
+            html.push_str("<span class=\"edited\">(edited)</span>");
+        }
+
+        html.push_str("</header>");
+
+        for reply in message.replies.iter().flatten() {
+            write!(
+                html,
+                "<div class=\"reply\">Replying to <a href=\"#{reply}\">a message</a></div>"
+            )
+            .to_internal_error()?;
+        }
+
+        if let Some(content) = &message.content {
+            write!(html, "<div class=\"content\">{}</div>", escape(content)).to_internal_error()?;
+        }
+
+        if let Some(system) = &message.system {
+            let text = match system {
+                v0::SystemMessage::Text { content } => content.clone(),
+                _ => serde_json::to_value(system)
+                    .ok()
+                    .and_then(|value| value.get("type")?.as_str().map(str::to_string))
+                    .unwrap_or_default(),
+            };
+
+            write!(
+                html,
+                "<div class=\"content system\">{}</div>",
+                escape(&text)
+            )
+            .to_internal_error()?;
+        }
+
+        if let Some(attachments) = &message.attachments {
+            html.push_str("<ul class=\"attachments\">");
+            for file in attachments {
+                write!(
+                    html,
+                    "<li><a href=\"{autumn}/{tag}/{id}/{filename}\">{filename}</a> ({size} bytes)</li>",
+                    autumn = self.autumn,
+                    tag = file.tag,
+                    id = file.id,
+                    filename = escape(&file.filename),
+                    size = file.size,
+                )
+                .to_internal_error()?;
+            }
+            html.push_str("</ul>");
+        }
+
+        for embed in message.embeds.iter().flatten() {
+            let (url, title, description) = match embed {
+                v0::Embed::Website(website) => (
+                    website.url.as_deref(),
+                    website.title.as_deref(),
+                    website.description.as_deref(),
+                ),
+                v0::Embed::Text(text) => (
+                    text.url.as_deref(),
+                    text.title.as_deref(),
+                    text.description.as_deref(),
+                ),
+                v0::Embed::Image(image) => (Some(image.url.as_str()), None, None),
+                v0::Embed::Video(video) => (Some(video.url.as_str()), None, None),
+                v0::Embed::None => continue,
+            };
+
+            html.push_str("<div class=\"embed\">");
+            match (url.filter(|url| is_web_url(url)), title.or(url)) {
+                (Some(url), title) => write!(
+                    html,
+                    "<div><a href=\"{}\">{}</a></div>",
+                    escape(url),
+                    escape(title.unwrap_or(url))
+                )
+                .to_internal_error()?,
+                (None, Some(title)) => {
+                    write!(html, "<div>{}</div>", escape(title)).to_internal_error()?
+                }
+                (None, None) => {}
+            }
+
+            if let Some(description) = description {
+                write!(html, "<div class=\"content\">{}</div>", escape(description))
+                    .to_internal_error()?;
+            }
+            html.push_str("</div>");
+        }
+
+        if !message.reactions.is_empty() {
+            html.push_str("<ul class=\"reactions\">");
+            for (emoji, users) in &message.reactions {
+                let emoji = if Ulid::from_string(emoji).is_ok() {
+                    format!(":{emoji}:")
+                } else {
+                    emoji.clone()
+                };
+
+                write!(html, "<li>{} {}</li>", escape(&emoji), users.len()).to_internal_error()?;
+            }
+            html.push_str("</ul>");
+        }
+
+        html.push_str("</article>");
+        Ok(())
+    }
+
+    async fn finish(mut self) -> Result<Transcript> {
+        let mut users: Vec<&v0::User> = self.users.values().collect();
+        users.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let mut members: Vec<&v0::Member> = self.members.values().collect();
+        members.sort_by(|a, b| a.id.user.cmp(&b.id.user));
+
+        self.json.extend_from_slice(br#"],"users":"#);
+        serde_json::to_writer(&mut self.json, &users).to_internal_error()?;
+        self.json.extend_from_slice(br#","members":"#);
+        serde_json::to_writer(&mut self.json, &members).to_internal_error()?;
+        self.json.push(b'}');
+
+        self.html.push_str("</body></html>");
+        self.flush().await?;
+
+        Ok(Transcript {
+            message_count: self.message_count,
+            json: self.json_file,
+            html: self.html_file,
+        })
+    }
+}
+
+impl Transcript {
+    /// Render transcripts of every message in a channel, oldest first
+    pub async fn render(db: &Database, channel: &Channel) -> Result<Transcript> {
+        let config = config().await;
+        let mut writer = TranscriptWriter::new(config.hosts.autumn, channel)?;
+        let mut messages = db.fetch_channel_messages_chunked(channel.id()).await?;
+
+        while let Some(chunk) = messages.next_n(CHUNK_SIZE).await? {
+            let chunk: Vec<v0::Message> = chunk
+                .into_iter()
+                .map(|message| message.into_model(None, None))
+                .collect();
+
+            writer.fetch_authors(db, channel.server(), &chunk).await?;
+
+            for message in &chunk {
+                writer.write_message(message)?;
+            }
+
+            writer.flush().await?;
+        }
+
+        writer.finish().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_web_url;
+    use crate::{Channel, Message, Transcript, User};
+
+    #[tokio::test]
+    async fn render() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "Exporter".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let channel = Channel::Group {
+                id: "channel".to_string(),
+                name: "Archive".to_string(),
+                owner: user.id.clone(),
+                description: None,
+                recipients: vec![user.id.clone()],
+                icon: None,
+                last_message_id: None,
+                permissions: None,
+                nsfw: false,
+                message_ttl: None,
+            };
+
+            for (id, content) in [("01", "first <b>"), ("02", "second")] {
+                db.insert_message(&Message {
+                    id: id.to_string(),
+                    channel: "channel".to_string(),
+                    author: user.id.clone(),
+                    content: Some(content.to_string()),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            }
+
+            let transcript = Transcript::render(&db, &channel).await.unwrap();
+            assert_eq!(transcript.message_count, 2);
+
+            let json: serde_json::Value =
+                serde_json::from_slice(&transcript.json.into_bytes().await.unwrap()).unwrap();
+            assert_eq!(json["version"], 1);
+            assert_eq!(json["messages"][0]["content"], "first <b>");
+            assert_eq!(json["messages"][1]["content"], "second");
+            assert_eq!(json["users"][0]["username"], "Exporter");
+
+            let html = String::from_utf8(transcript.html.into_bytes().await.unwrap()).unwrap();
+            assert!(html.contains("first &lt;b&gt;"));
+            assert!(html.find("first").unwrap() < html.find("second").unwrap());
+            assert!(html.contains("<title>Archive</title>"));
+        });
+    }
+
+    #[test]
+    fn only_web_links() {
+        assert!(is_web_url("https://stoat.chat"));
+        assert!(is_web_url("HTTP://stoat.chat"));
+        assert!(!is_web_url("javascript:alert(1)"));
+        assert!(!is_web_url(" JavaScript:alert(1)"));
+        assert!(!is_web_url("data:text/html,<script>alert(1)</script>"));
+    }
+}
@@ -43,4 +43,7 @@ pub trait AbstractChannelUnreads: Sync + Send {
 
     /// Fetch unread for a specific user in a channel.
     async fn fetch_unread(&self, user_id: &str, channel_id: &str) -> Result<Option<ChannelUnread>>;
+
+    /// Fetch unreads for every user in a channel.
+    async fn fetch_channel_unreads(&self, channel_id: &str) -> Result<Vec<ChannelUnread>>;
 }
@@ -174,4 +174,16 @@ impl AbstractChannelUnreads for MongoDb {
             }
         )
     }
+
+    /// Fetch unreads for every user in a channel.
+    async fn fetch_channel_unreads(&self, channel_id: &str) -> Result<Vec<ChannelUnread>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "_id.channel": channel_id
+            }
+        )
+    }
 }
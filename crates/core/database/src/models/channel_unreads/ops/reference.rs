@@ -140,4 +140,14 @@ impl AbstractChannelUnreads for ReferenceDb {
             })
             .cloned())
     }
+
+    /// Fetch unreads for every user in a channel.
+    async fn fetch_channel_unreads(&self, channel_id: &str) -> Result<Vec<ChannelUnread>> {
+        let unreads = self.channel_unreads.lock().await;
+        Ok(unreads
+            .values()
+            .filter(|unread| unread.id.channel == channel_id)
+            .cloned()
+            .collect())
+    }
 }
@@ -28,6 +28,10 @@ auto_derived_partial!(
 
         /// The private token for the webhook
         pub token: Option<String>,
+
+        /// Announcement channel this webhook follows
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub source_channel_id: Option<String>,
     },
     "PartialWebhook"
 );
@@ -50,6 +54,7 @@ impl Default for Webhook {
             channel_id: Default::default(),
             permissions: Default::default(),
             token: Default::default(),
+            source_channel_id: None,
         }
     }
 }
@@ -17,6 +17,9 @@ pub trait AbstractWebhooks: Sync + Send {
     /// Fetch webhooks for channel
     async fn fetch_webhooks_for_channel(&self, channel_id: &str) -> Result<Vec<Webhook>>;
 
+    /// Fetch webhooks following an announcement channel
+    async fn fetch_webhooks_following(&self, channel_id: &str) -> Result<Vec<Webhook>>;
+
     /// Update webhook with new information
     async fn update_webhook(
         &self,
@@ -40,6 +40,18 @@ impl AbstractWebhooks for MongoDb {
             .await)
     }
 
+    /// Fetch webhooks following an announcement channel
+    async fn fetch_webhooks_following(&self, channel_id: &str) -> Result<Vec<Webhook>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "source_channel_id": channel_id,
+            }
+        )
+    }
+
     /// Update webhook with new information
     async fn update_webhook(
         &self,
@@ -37,6 +37,16 @@ impl AbstractWebhooks for ReferenceDb {
             .collect())
     }
 
+    /// Fetch webhooks following an announcement channel
+    async fn fetch_webhooks_following(&self, channel_id: &str) -> Result<Vec<Webhook>> {
+        let webhooks = self.channel_webhooks.lock().await;
+        Ok(webhooks
+            .values()
+            .filter(|webhook| webhook.source_channel_id.as_deref() == Some(channel_id))
+            .cloned()
+            .collect())
+    }
+
     /// Update webhook with new information
     async fn update_webhook(
         &self,
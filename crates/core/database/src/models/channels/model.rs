@@ -10,7 +10,8 @@ use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
 use crate::{
-    events::client::EventV1, Database, File, PartialServer, Server, SystemMessage, User, AMQP,
+    events::client::EventV1, Database, FieldsMessage, File, Message, PartialMessage, PartialServer,
+    Server, SystemMessage, ThreadSummary, User, AMQP,
 };
 
 #[cfg(feature = "mongodb")]
@@ -40,6 +41,9 @@ auto_derived!(
             /// Id of the last message sent in this channel
             #[serde(skip_serializing_if = "Option::is_none")]
             last_message_id: Option<String>,
+            /// Time in seconds after which messages sent in this channel expire
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message_ttl: Option<u64>,
         },
         /// Group channel between 1 or more participants
         Group {
@@ -72,6 +76,10 @@ auto_derived!(
             /// Whether this group is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
+
+            /// Time in seconds after which messages sent in this channel expire
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message_ttl: Option<u64>,
         },
         /// Text channel belonging to a server
         TextChannel {
@@ -103,6 +111,12 @@ auto_derived!(
                 skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Permissions assigned to specific members of this channel
+            #[serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )]
+            user_permissions: HashMap<String, OverrideField>,
 
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
@@ -115,6 +129,99 @@ auto_derived!(
             /// The channel's slowmode delay in seconds
             #[serde(skip_serializing_if = "Option::is_none")]
             slowmode: Option<u64>,
+            /// Time in seconds after which messages sent in this channel expire
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message_ttl: Option<u64>,
+
+            /// Whether this is an announcement channel which other servers can follow
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            announcement: bool,
+        },
+        /// Forum channel belonging to a server, each post is a thread
+        ForumChannel {
+            /// Unique Id
+            #[serde(rename = "_id")]
+            id: String,
+            /// Id of the server this channel belongs to
+            server: String,
+
+            /// Display name of the channel
+            name: String,
+            /// Channel description
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<String>,
+
+            /// Custom icon attachment
+            #[serde(skip_serializing_if = "Option::is_none")]
+            icon: Option<File>,
+
+            /// Default permissions assigned to users in this channel
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_permissions: Option<OverrideField>,
+            /// Permissions assigned based on role to this channel
+            #[serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )]
+            role_permissions: HashMap<String, OverrideField>,
+            /// Permissions assigned to specific members of this channel
+            #[serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )]
+            user_permissions: HashMap<String, OverrideField>,
+
+            /// Whether this channel is marked as not safe for work
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            nsfw: bool,
+
+            /// Tags which can be applied to posts
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            tags: Vec<ForumTag>,
+            /// Order posts are listed in by default
+            #[serde(default)]
+            default_sort: ForumSortOrder,
+            /// Whether posts must have at least one tag applied
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            require_tag: bool,
+            /// Time in seconds after which messages sent in posts in this channel expire
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message_ttl: Option<u64>,
+        },
+        /// Thread spawned from a message in a server channel or a post in a forum
+        Thread {
+            /// Unique Id
+            #[serde(rename = "_id")]
+            id: String,
+            /// Id of the server this thread belongs to
+            server: String,
+            /// Id of the channel this thread was spawned in
+            parent: String,
+            /// Id of the message this thread was spawned from
+            ///
+            /// Forum posts are not spawned from a message
+            #[serde(skip_serializing_if = "Option::is_none")]
+            message: Option<String>,
+
+            /// Display name of the thread
+            name: String,
+            /// User id of the creator of the thread
+            owner: String,
+            /// Array of user ids following this thread
+            #[serde(default)]
+            members: Vec<String>,
+
+            /// Id of the last message sent in this thread
+            #[serde(skip_serializing_if = "Option::is_none")]
+            last_message_id: Option<String>,
+
+            /// Whether this thread has been archived
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            archived: bool,
+
+            /// Ids of the forum tags applied to this post
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            applied_tags: Vec<String>,
         },
     }
 
@@ -124,6 +231,27 @@ auto_derived!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub max_users: Option<usize>,
     }
+
+    /// Tag which can be applied to posts in a forum
+    pub struct ForumTag {
+        /// Unique Id
+        pub id: String,
+        /// Tag name
+        pub name: String,
+        /// Emoji shown alongside the tag
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub emoji: Option<String>,
+    }
+
+    /// Order in which forum posts are listed
+    #[derive(Default)]
+    pub enum ForumSortOrder {
+        /// Most recently active posts first
+        #[default]
+        LatestActivity,
+        /// Most recently created posts first
+        Creation,
+    }
 );
 
 auto_derived!(
@@ -146,6 +274,8 @@ auto_derived!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub role_permissions: Option<HashMap<String, OverrideField>>,
         #[serde(skip_serializing_if = "Option::is_none")]
+        pub user_permissions: Option<HashMap<String, OverrideField>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
         pub default_permissions: Option<OverrideField>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_message_id: Option<String>,
@@ -153,6 +283,20 @@ auto_derived!(
         pub voice: Option<VoiceInformation>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub slowmode: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message_ttl: Option<u64>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub announcement: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub archived: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tags: Option<Vec<ForumTag>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_sort: Option<ForumSortOrder>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub require_tag: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub applied_tags: Option<Vec<String>>,
     }
 
     /// Optional fields on channel object
@@ -162,6 +306,7 @@ auto_derived!(
         DefaultPermissions,
         Voice,
         Slowmode,
+        MessageTtl,
     }
 );
 
@@ -212,9 +357,12 @@ impl Channel {
                 last_message_id: None,
                 default_permissions: None,
                 role_permissions: HashMap::new(),
+                user_permissions: HashMap::new(),
                 nsfw: data.nsfw.unwrap_or(false),
                 voice: data.voice.map(|voice| voice.into()),
                 slowmode: None,
+                message_ttl: None,
+                announcement: false,
             },
             v0::LegacyServerChannelType::Voice => Channel::TextChannel {
                 id: id.clone(),
@@ -225,9 +373,27 @@ impl Channel {
                 last_message_id: None,
                 default_permissions: None,
                 role_permissions: HashMap::new(),
+                user_permissions: HashMap::new(),
                 nsfw: data.nsfw.unwrap_or(false),
                 voice: Some(data.voice.unwrap_or_default().into()),
                 slowmode: None,
+                message_ttl: None,
+                announcement: false,
+            },
+            v0::LegacyServerChannelType::Forum => Channel::ForumChannel {
+                id: id.clone(),
+                server: server.id.to_owned(),
+                name: data.name,
+                description: data.description,
+                icon: None,
+                default_permissions: None,
+                role_permissions: HashMap::new(),
+                user_permissions: HashMap::new(),
+                nsfw: data.nsfw.unwrap_or(false),
+                tags: vec![],
+                default_sort: ForumSortOrder::default(),
+                require_tag: false,
+                message_ttl: None,
             },
         };
 
@@ -291,6 +457,8 @@ impl Channel {
             permissions: None,
 
             nsfw: data.nsfw.unwrap_or(false),
+
+            message_ttl: None,
         };
 
         db.insert_channel(&channel).await?;
@@ -322,6 +490,7 @@ impl Channel {
                     active: true, // show by default
                     recipients: vec![user_a.id.clone(), user_b.id.clone()],
                     last_message_id: None,
+                    message_ttl: None,
                 }
             };
 
@@ -337,6 +506,250 @@ impl Channel {
         }
     }
 
+    /// Create a thread from a message sent in a server channel
+    pub async fn create_thread(
+        db: &Database,
+        parent: &Channel,
+        message: &mut Message,
+        data: v0::DataCreateThread,
+        owner_id: String,
+    ) -> Result<Channel> {
+        let Channel::TextChannel { id, server, .. } = parent else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        if &message.channel != id {
+            return Err(create_error!(NotFound));
+        }
+
+        if message.thread.is_some() {
+            return Err(create_error!(ThreadAlreadyExists));
+        }
+
+        let channel = Channel::Thread {
+            id: Ulid::new().to_string(),
+            server: server.clone(),
+            parent: id.clone(),
+            message: Some(message.id.clone()),
+            name: data.name,
+            owner: owner_id.clone(),
+            members: vec![owner_id],
+            last_message_id: None,
+            archived: false,
+            applied_tags: vec![],
+        };
+
+        db.insert_channel(&channel).await?;
+
+        message
+            .update(
+                db,
+                PartialMessage {
+                    thread: Some(ThreadSummary {
+                        id: channel.id().to_string(),
+                        reply_count: 0,
+                        last_message_id: None,
+                    }),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await?;
+
+        EventV1::ThreadCreate(channel.clone().into())
+            .p(server.clone())
+            .await;
+
+        Ok(channel)
+    }
+
+    /// Create a new post in a forum channel
+    pub async fn create_forum_post(
+        db: &Database,
+        forum: &Channel,
+        title: String,
+        applied_tags: Vec<String>,
+        owner_id: String,
+    ) -> Result<Channel> {
+        let Channel::ForumChannel {
+            id,
+            server,
+            tags,
+            require_tag,
+            ..
+        } = forum
+        else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        Channel::validate_forum_tags(tags, *require_tag, &applied_tags)?;
+
+        let channel = Channel::Thread {
+            id: Ulid::new().to_string(),
+            server: server.clone(),
+            parent: id.clone(),
+            message: None,
+            name: title,
+            owner: owner_id.clone(),
+            members: vec![owner_id],
+            last_message_id: None,
+            archived: false,
+            applied_tags,
+        };
+
+        db.insert_channel(&channel).await?;
+
+        EventV1::ThreadCreate(channel.clone().into())
+            .p(server.clone())
+            .await;
+
+        Ok(channel)
+    }
+
+    /// Check that the given tags may be applied to a post in a forum
+    pub fn validate_forum_tags(
+        tags: &[ForumTag],
+        require_tag: bool,
+        applied_tags: &[String],
+    ) -> Result<()> {
+        if require_tag && applied_tags.is_empty() {
+            return Err(create_error!(ForumTagRequired));
+        }
+
+        if applied_tags
+            .iter()
+            .any(|applied| !tags.iter().any(|tag| &tag.id == applied))
+        {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        Ok(())
+    }
+
+    /// Remove a deleted tag from every post in a forum
+    pub async fn remove_tag_from_posts(&self, db: &Database, tag_id: &str) -> Result<()> {
+        let Channel::ForumChannel { id, .. } = self else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        let posts = db.fetch_threads(&[id.clone()], true).await?;
+        db.remove_tag_from_posts(id, tag_id).await?;
+
+        for post in posts {
+            if let Channel::Thread {
+                id,
+                server,
+                mut applied_tags,
+                ..
+            } = post
+            {
+                if applied_tags.iter().any(|tag| tag == tag_id) {
+                    applied_tags.retain(|tag| tag != tag_id);
+
+                    EventV1::ThreadUpdate {
+                        id,
+                        data: PartialChannel {
+                            applied_tags: Some(applied_tags),
+                            ..Default::default()
+                        }
+                        .into(),
+                        clear: vec![],
+                    }
+                    .p(server)
+                    .await;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Add user to the members of a thread
+    pub async fn add_user_to_thread(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        match self {
+            Channel::Thread { id, members, .. } => {
+                if members.iter().any(|member| member == user_id) {
+                    return Err(create_error!(NoEffect));
+                }
+
+                db.add_user_to_thread(id, user_id).await?;
+                members.push(user_id.to_string());
+
+                EventV1::ThreadMemberJoin {
+                    id: id.clone(),
+                    user: user_id.to_string(),
+                }
+                .p(id.clone())
+                .await;
+
+                Ok(())
+            }
+            _ => Err(create_error!(InvalidOperation)),
+        }
+    }
+
+    /// Remove user from the members of a thread
+    pub async fn remove_user_from_thread(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        match self {
+            Channel::Thread { id, members, .. } => {
+                if !members.iter().any(|member| member == user_id) {
+                    return Err(create_error!(NoEffect));
+                }
+
+                db.remove_user_from_thread(id, user_id).await?;
+                members.retain(|member| member != user_id);
+
+                EventV1::ThreadMemberLeave {
+                    id: id.clone(),
+                    user: user_id.to_string(),
+                }
+                .p(id.clone())
+                .await;
+
+                Ok(())
+            }
+            _ => Err(create_error!(InvalidOperation)),
+        }
+    }
+
+    /// Archive or unarchive a thread
+    pub async fn set_thread_archived(&mut self, db: &Database, archived: bool) -> Result<()> {
+        match self {
+            Channel::Thread {
+                id,
+                server,
+                archived: current,
+                ..
+            } => {
+                if *current == archived {
+                    return Err(create_error!(NoEffect));
+                }
+
+                db.update_channel(
+                    id,
+                    &PartialChannel {
+                        archived: Some(archived),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await?;
+
+                *current = archived;
+
+                EventV1::ThreadArchive {
+                    id: id.clone(),
+                    archived,
+                }
+                .p(server.clone())
+                .await;
+
+                Ok(())
+            }
+            _ => Err(create_error!(InvalidOperation)),
+        }
+    }
+
     /// Add user to a group
     pub async fn add_user_to_group(
         &mut self,
@@ -433,14 +846,18 @@ impl Channel {
             Channel::DirectMessage { id, .. }
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
-            | Channel::TextChannel { id, .. } => id,
+            | Channel::TextChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::Thread { id, .. } => id,
         }
     }
 
     /// Clone this channel's server id
     pub fn server(&self) -> Option<&str> {
         match self {
-            Channel::TextChannel { server, .. } => Some(server),
+            Channel::TextChannel { server, .. }
+            | Channel::ForumChannel { server, .. }
+            | Channel::Thread { server, .. } => Some(server),
             _ => None,
         }
     }
@@ -458,6 +875,25 @@ impl Channel {
         }
     }
 
+    /// Gets the default time in seconds after which messages sent in this channel expire
+    pub fn message_ttl(&self) -> Option<u64> {
+        match self {
+            Self::DirectMessage { message_ttl, .. }
+            | Self::Group { message_ttl, .. }
+            | Self::TextChannel { message_ttl, .. }
+            | Self::ForumChannel { message_ttl, .. } => *message_ttl,
+            _ => None,
+        }
+    }
+
+    /// Resolves the default message expiry for this channel, threads inherit from their parent
+    pub async fn resolve_message_ttl(&self, db: &Database) -> Result<Option<u64>> {
+        match self {
+            Self::Thread { parent, .. } => Ok(db.fetch_channel(parent).await?.message_ttl()),
+            _ => Ok(self.message_ttl()),
+        }
+    }
+
     /// Set role permission on a channel
     pub async fn set_role_permission(
         &mut self,
@@ -471,6 +907,12 @@ impl Channel {
                 server,
                 role_permissions,
                 ..
+            }
+            | Channel::ForumChannel {
+                id,
+                server,
+                role_permissions,
+                ..
             } => {
                 db.set_channel_role_permission(id, role_id, permissions)
                     .await?;
@@ -495,6 +937,54 @@ impl Channel {
         }
     }
 
+    /// Set or clear permissions for a specific member on a channel
+    pub async fn set_user_permission(
+        &mut self,
+        db: &Database,
+        user_id: &str,
+        permissions: Option<OverrideField>,
+    ) -> Result<()> {
+        match self {
+            Channel::TextChannel {
+                id,
+                server,
+                user_permissions,
+                ..
+            }
+            | Channel::ForumChannel {
+                id,
+                server,
+                user_permissions,
+                ..
+            } => {
+                if let Some(permissions) = permissions {
+                    db.set_channel_user_permission(id, user_id, permissions)
+                        .await?;
+
+                    user_permissions.insert(user_id.to_string(), permissions);
+                } else {
+                    db.remove_channel_user_permission(id, user_id).await?;
+                    user_permissions.remove(user_id);
+                }
+
+                EventV1::ChannelUpdate {
+                    id: id.clone(),
+                    data: PartialChannel {
+                        user_permissions: Some(user_permissions.clone()),
+                        ..Default::default()
+                    }
+                    .into(),
+                    clear: vec![],
+                }
+                .p(server.clone())
+                .await;
+
+                Ok(())
+            }
+            _ => Err(create_error!(InvalidOperation)),
+        }
+    }
+
     /// Update channel data
     pub async fn update(
         &mut self,
@@ -511,16 +1001,28 @@ impl Channel {
         let id = self.id().to_string();
         db.update_channel(&id, &partial, remove.clone()).await?;
 
-        EventV1::ChannelUpdate {
-            id: id.clone(),
-            data: partial.into(),
-            clear: remove.into_iter().map(|v| v.into()).collect(),
+        if let Self::Thread { server, .. } = self {
+            EventV1::ThreadUpdate {
+                id,
+                data: partial.into(),
+                clear: remove.into_iter().map(|v| v.into()).collect(),
+            }
+            .p(server.clone())
+            .await;
+        } else {
+            EventV1::ChannelUpdate {
+                id: id.clone(),
+                data: partial.into(),
+                clear: remove.into_iter().map(|v| v.into()).collect(),
+            }
+            .p(match self {
+                Self::TextChannel { server, .. } | Self::ForumChannel { server, .. } => {
+                    server.clone()
+                }
+                _ => id,
+            })
+            .await;
         }
-        .p(match self {
-            Self::TextChannel { server, .. } => server.clone(),
-            _ => id,
-        })
-        .await;
 
         Ok(())
     }
@@ -529,13 +1031,17 @@ impl Channel {
     pub fn remove_field(&mut self, field: &FieldsChannel) {
         match field {
             FieldsChannel::Description => match self {
-                Self::Group { description, .. } | Self::TextChannel { description, .. } => {
+                Self::Group { description, .. }
+                | Self::TextChannel { description, .. }
+                | Self::ForumChannel { description, .. } => {
                     description.take();
                 }
                 _ => {}
             },
             FieldsChannel::Icon => match self {
-                Self::Group { icon, .. } | Self::TextChannel { icon, .. } => {
+                Self::Group { icon, .. }
+                | Self::TextChannel { icon, .. }
+                | Self::ForumChannel { icon, .. } => {
                     icon.take();
                 }
                 _ => {}
@@ -544,6 +1050,10 @@ impl Channel {
                 Self::TextChannel {
                     default_permissions,
                     ..
+                }
+                | Self::ForumChannel {
+                    default_permissions,
+                    ..
                 } => {
                     default_permissions.take();
                 }
@@ -560,7 +1070,16 @@ impl Channel {
                     slowmode.take();
                 }
                 _ => {}
-            }
+            },
+            FieldsChannel::MessageTtl => match self {
+                Self::DirectMessage { message_ttl, .. }
+                | Self::Group { message_ttl, .. }
+                | Self::TextChannel { message_ttl, .. }
+                | Self::ForumChannel { message_ttl, .. } => {
+                    message_ttl.take();
+                }
+                _ => {}
+            },
         }
     }
 
@@ -576,10 +1095,18 @@ impl Channel {
     pub fn apply_options(&mut self, partial: PartialChannel) {
         match self {
             Self::SavedMessages { .. } => {}
-            Self::DirectMessage { active, .. } => {
+            Self::DirectMessage {
+                active,
+                message_ttl,
+                ..
+            } => {
                 if let Some(v) = partial.active {
                     *active = v;
                 }
+
+                if let Some(v) = partial.message_ttl {
+                    message_ttl.replace(v);
+                }
             }
             Self::Group {
                 name,
@@ -588,6 +1115,7 @@ impl Channel {
                 icon,
                 nsfw,
                 permissions,
+                message_ttl,
                 ..
             } => {
                 if let Some(v) = partial.name {
@@ -613,6 +1141,10 @@ impl Channel {
                 if let Some(v) = partial.permissions {
                     permissions.replace(v);
                 }
+
+                if let Some(v) = partial.message_ttl {
+                    message_ttl.replace(v);
+                }
             }
             Self::TextChannel {
                 name,
@@ -621,7 +1153,10 @@ impl Channel {
                 nsfw,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 voice,
+                message_ttl,
+                announcement,
                 ..
             } => {
                 if let Some(v) = partial.name {
@@ -644,6 +1179,10 @@ impl Channel {
                     *role_permissions = v;
                 }
 
+                if let Some(v) = partial.user_permissions {
+                    *user_permissions = v;
+                }
+
                 if let Some(v) = partial.default_permissions {
                     default_permissions.replace(v);
                 }
@@ -651,6 +1190,90 @@ impl Channel {
                 if let Some(v) = partial.voice {
                     voice.replace(v);
                 }
+
+                if let Some(v) = partial.message_ttl {
+                    message_ttl.replace(v);
+                }
+
+                if let Some(v) = partial.announcement {
+                    *announcement = v;
+                }
+            }
+            Self::ForumChannel {
+                name,
+                description,
+                icon,
+                nsfw,
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                tags,
+                default_sort,
+                require_tag,
+                message_ttl,
+                ..
+            } => {
+                if let Some(v) = partial.name {
+                    *name = v;
+                }
+
+                if let Some(v) = partial.description {
+                    description.replace(v);
+                }
+
+                if let Some(v) = partial.icon {
+                    icon.replace(v);
+                }
+
+                if let Some(v) = partial.nsfw {
+                    *nsfw = v;
+                }
+
+                if let Some(v) = partial.role_permissions {
+                    *role_permissions = v;
+                }
+
+                if let Some(v) = partial.user_permissions {
+                    *user_permissions = v;
+                }
+
+                if let Some(v) = partial.default_permissions {
+                    default_permissions.replace(v);
+                }
+
+                if let Some(v) = partial.tags {
+                    *tags = v;
+                }
+
+                if let Some(v) = partial.default_sort {
+                    *default_sort = v;
+                }
+
+                if let Some(v) = partial.require_tag {
+                    *require_tag = v;
+                }
+
+                if let Some(v) = partial.message_ttl {
+                    message_ttl.replace(v);
+                }
+            }
+            Self::Thread {
+                name,
+                archived,
+                applied_tags,
+                ..
+            } => {
+                if let Some(v) = partial.name {
+                    *name = v;
+                }
+
+                if let Some(v) = partial.archived {
+                    *archived = v;
+                }
+
+                if let Some(v) = partial.applied_tags {
+                    *applied_tags = v;
+                }
             }
         }
     }
@@ -668,6 +1291,7 @@ impl Channel {
             Channel::DirectMessage {
                 active,
                 last_message_id,
+                message_ttl,
                 ..
             } => {
                 if partial.active.is_some() {
@@ -677,6 +1301,10 @@ impl Channel {
                 if partial.last_message_id.is_some() {
                     before.last_message_id = last_message_id.clone()
                 };
+
+                if partial.message_ttl.is_some() || remove.contains(&FieldsChannel::MessageTtl) {
+                    before.message_ttl = *message_ttl;
+                };
             }
             Channel::Group {
                 name,
@@ -686,6 +1314,7 @@ impl Channel {
                 last_message_id,
                 permissions,
                 nsfw,
+                message_ttl,
                 ..
             } => {
                 if partial.name.is_some() {
@@ -715,6 +1344,10 @@ impl Channel {
                 if partial.nsfw.is_some() {
                     before.nsfw = Some(*nsfw);
                 };
+
+                if partial.message_ttl.is_some() || remove.contains(&FieldsChannel::MessageTtl) {
+                    before.message_ttl = *message_ttl;
+                };
             }
             Channel::TextChannel {
                 name,
@@ -723,9 +1356,12 @@ impl Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 nsfw,
                 voice,
                 slowmode,
+                message_ttl,
+                announcement,
                 ..
             } => {
                 if partial.name.is_some() {
@@ -754,6 +1390,10 @@ impl Channel {
                     before.role_permissions = Some(role_permissions.clone());
                 };
 
+                if partial.user_permissions.is_some() {
+                    before.user_permissions = Some(user_permissions.clone());
+                };
+
                 if partial.nsfw.is_some() {
                     before.nsfw = Some(*nsfw);
                 };
@@ -765,6 +1405,97 @@ impl Channel {
                 if partial.slowmode.is_some() {
                     before.slowmode = *slowmode;
                 }
+
+                if partial.message_ttl.is_some() || remove.contains(&FieldsChannel::MessageTtl) {
+                    before.message_ttl = *message_ttl;
+                };
+
+                if partial.announcement.is_some() {
+                    before.announcement = Some(*announcement);
+                };
+            }
+            Channel::ForumChannel {
+                name,
+                description,
+                icon,
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                nsfw,
+                tags,
+                default_sort,
+                require_tag,
+                message_ttl,
+                ..
+            } => {
+                if partial.name.is_some() {
+                    before.name = Some(name.clone());
+                };
+
+                if partial.description.is_some() || remove.contains(&FieldsChannel::Description) {
+                    before.description = description.clone();
+                };
+
+                if partial.icon.is_some() || remove.contains(&FieldsChannel::Icon) {
+                    before.icon = icon.clone();
+                };
+
+                if partial.default_permissions.is_some()
+                    || remove.contains(&FieldsChannel::DefaultPermissions)
+                {
+                    before.default_permissions = *default_permissions;
+                };
+
+                if partial.role_permissions.is_some() {
+                    before.role_permissions = Some(role_permissions.clone());
+                };
+
+                if partial.user_permissions.is_some() {
+                    before.user_permissions = Some(user_permissions.clone());
+                };
+
+                if partial.nsfw.is_some() {
+                    before.nsfw = Some(*nsfw);
+                };
+
+                if partial.tags.is_some() {
+                    before.tags = Some(tags.clone());
+                };
+
+                if partial.default_sort.is_some() {
+                    before.default_sort = Some(default_sort.clone());
+                };
+
+                if partial.require_tag.is_some() {
+                    before.require_tag = Some(*require_tag);
+                };
+
+                if partial.message_ttl.is_some() || remove.contains(&FieldsChannel::MessageTtl) {
+                    before.message_ttl = *message_ttl;
+                };
+            }
+            Channel::Thread {
+                name,
+                last_message_id,
+                archived,
+                applied_tags,
+                ..
+            } => {
+                if partial.name.is_some() {
+                    before.name = Some(name.clone());
+                };
+
+                if partial.last_message_id.is_some() {
+                    before.last_message_id = last_message_id.clone()
+                };
+
+                if partial.archived.is_some() {
+                    before.archived = Some(*archived);
+                };
+
+                if partial.applied_tags.is_some() {
+                    before.applied_tags = Some(applied_tags.clone());
+                };
             }
         }
 
@@ -784,6 +1515,38 @@ impl Channel {
         crate::util::acker::ack_channel(user, self.id(), message, amqp).await
     }
 
+    /// Let other recipients know a user has read up to a message
+    ///
+    /// Only sent in direct messages and groups, and only if the user shares read receipts.
+    pub async fn send_receipt(&self, db: &Database, user: &User, message: &str) -> Result<()> {
+        if !matches!(self, Channel::DirectMessage { .. } | Channel::Group { .. })
+            || user.read_receipts != Some(true)
+        {
+            return Ok(());
+        }
+
+        // Only send a receipt if the user has read further than before
+        if let Some(last_id) = db
+            .fetch_unread(&user.id, self.id())
+            .await?
+            .and_then(|unread| unread.last_id)
+        {
+            if last_id.as_str() >= message {
+                return Ok(());
+            }
+        }
+
+        EventV1::ChannelReceipt {
+            id: self.id().to_string(),
+            user: user.id.clone(),
+            message_id: message.to_string(),
+        }
+        .p(self.id().to_string())
+        .await;
+
+        Ok(())
+    }
+
     /// Remove user from a group
     pub async fn remove_user_from_group(
         &self,
@@ -883,6 +1646,33 @@ impl Channel {
 
     /// Delete a channel
     pub async fn delete(&self, db: &Database) -> Result<()> {
+        match self {
+            Channel::TextChannel { id, .. } | Channel::ForumChannel { id, .. } => {
+                // Threads can't outlive the channel they were spawned in
+                for thread in db.fetch_threads(&[id.clone()], true).await? {
+                    let thread_id = thread.id().to_string();
+                    EventV1::ChannelDelete {
+                        id: thread_id.clone(),
+                    }
+                    .p(thread_id)
+                    .await;
+
+                    db.delete_channel(&thread).await?;
+                }
+            }
+            Channel::Thread {
+                message: Some(message),
+                ..
+            } => {
+                if let Ok(mut message) = db.fetch_message(message).await {
+                    message
+                        .update(db, Default::default(), vec![FieldsMessage::Thread])
+                        .await?;
+                }
+            }
+            _ => {}
+        }
+
         let id = self.id().to_string();
         EventV1::ChannelDelete { id: id.clone() }.p(id).await;
         // TODO: missing functionality:
@@ -901,6 +1691,7 @@ impl IntoDocumentPath for FieldsChannel {
             FieldsChannel::DefaultPermissions => "default_permissions",
             FieldsChannel::Voice => "voice",
             FieldsChannel::Slowmode => "slowmode",
+            FieldsChannel::MessageTtl => "message_ttl",
         })
     }
 }
@@ -909,7 +1700,7 @@ impl IntoDocumentPath for FieldsChannel {
 mod tests {
     use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 
-    use crate::{fixture, util::permissions::DatabasePermissionQuery};
+    use crate::{fixture, util::permissions::DatabasePermissionQuery, Channel};
 
     #[tokio::test]
     async fn permissions_group_channel() {
@@ -962,4 +1753,39 @@ mod tests {
                 .has_channel_permission(ChannelPermission::SendMessage));
         });
     }
+
+    #[tokio::test]
+    async fn permissions_thread_with_missing_parent() {
+        database_test!(|db| async move {
+            fixture!(db, "server_with_roles",
+                owner user 0
+                moderator user 1
+                channel channel 3);
+
+            let thread = Channel::Thread {
+                id: "thread".to_string(),
+                server: channel.server().unwrap().to_string(),
+                parent: "missing".to_string(),
+                message: None,
+                name: "Thread".to_string(),
+                owner: moderator.id.clone(),
+                members: vec![moderator.id.clone()],
+                last_message_id: None,
+                archived: false,
+                applied_tags: vec![],
+            };
+
+            let mut query = DatabasePermissionQuery::new(&db, &owner).channel(&thread);
+            assert_eq!(
+                u64::from(calculate_channel_permissions(&mut query).await),
+                0
+            );
+
+            let mut query = DatabasePermissionQuery::new(&db, &moderator).channel(&thread);
+            assert_eq!(
+                u64::from(calculate_channel_permissions(&mut query).await),
+                0
+            );
+        });
+    }
 }
@@ -33,9 +33,19 @@ pub trait AbstractChannels: Sync + Send {
     // Fetch direct message channel (DM or Saved Messages)
     async fn find_direct_message_channel(&self, user_a: &str, user_b: &str) -> Result<Channel>;
 
+    /// Fetch threads spawned in any of the given channels
+    async fn fetch_threads<'a>(
+        &self,
+        parent_ids: &'a [String],
+        include_archived: bool,
+    ) -> Result<Vec<Channel>>;
+
     /// Insert a user to a group
     async fn add_user_to_group(&self, channel_id: &str, user_id: &str) -> Result<()>;
 
+    /// Insert a user to the members of a thread
+    async fn add_user_to_thread(&self, channel_id: &str, user_id: &str) -> Result<()>;
+
     /// Insert channel role permissions
     async fn set_channel_role_permission(
         &self,
@@ -44,6 +54,17 @@ pub trait AbstractChannels: Sync + Send {
         permissions: OverrideField,
     ) -> Result<()>;
 
+    /// Insert channel member permissions
+    async fn set_channel_user_permission(
+        &self,
+        channel_id: &str,
+        user_id: &str,
+        permissions: OverrideField,
+    ) -> Result<()>;
+
+    /// Remove channel member permissions
+    async fn remove_channel_user_permission(&self, channel_id: &str, user_id: &str) -> Result<()>;
+
     // Update channel
     async fn update_channel(
         &self,
@@ -55,6 +76,12 @@ pub trait AbstractChannels: Sync + Send {
     // Remove a user from a group
     async fn remove_user_from_group(&self, channel_id: &str, user_id: &str) -> Result<()>;
 
+    // Remove a user from the members of a thread
+    async fn remove_user_from_thread(&self, channel_id: &str, user_id: &str) -> Result<()>;
+
+    // Remove a tag from every post in a forum
+    async fn remove_tag_from_posts(&self, forum_id: &str, tag_id: &str) -> Result<()>;
+
     // Remove a user from all specified groups
     async fn remove_user_from_groups(&self, channel_ids: Vec<String>, user_id: &str) -> Result<()>;
 
@@ -138,6 +138,31 @@ impl AbstractChannels for MongoDb {
         query!(self, find_one, COL, doc)?.ok_or_else(|| create_error!(NotFound))
     }
 
+    /// Fetch threads spawned in any of the given channels
+    async fn fetch_threads<'a>(
+        &self,
+        parent_ids: &'a [String],
+        include_archived: bool,
+    ) -> Result<Vec<Channel>> {
+        let mut filter = doc! {
+            "channel_type": "Thread",
+            "parent": {
+                "$in": parent_ids
+            }
+        };
+
+        if !include_archived {
+            filter.insert(
+                "archived",
+                doc! {
+                    "$ne": true
+                },
+            );
+        }
+
+        query!(self, find, COL, filter)
+    }
+
     /// Insert a user to a group
     async fn add_user_to_group(&self, channel: &str, user: &str) -> Result<()> {
         self.col::<Document>(COL)
@@ -156,6 +181,24 @@ impl AbstractChannels for MongoDb {
             .map_err(|_| create_database_error!("update_one", "channel"))
     }
 
+    /// Insert a user to the members of a thread
+    async fn add_user_to_thread(&self, channel: &str, user: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": channel
+                },
+                doc! {
+                    "$addToSet": {
+                        "members": user
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Insert channel role permissions
     async fn set_channel_role_permission(
         &self,
@@ -177,6 +220,43 @@ impl AbstractChannels for MongoDb {
             .map_err(|_| create_database_error!("update_one", "channel"))
     }
 
+    /// Insert channel member permissions
+    async fn set_channel_user_permission(
+        &self,
+        channel: &str,
+        user: &str,
+        permissions: OverrideField,
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! { "_id": channel },
+                doc! {
+                    "$set": {
+                        "user_permissions.".to_owned() + user: permissions
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Remove channel member permissions
+    async fn remove_channel_user_permission(&self, channel: &str, user: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! { "_id": channel },
+                doc! {
+                    "$unset": {
+                        "user_permissions.".to_owned() + user: 1_i32
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     // Update channel
     async fn update_channel(
         &self,
@@ -214,6 +294,43 @@ impl AbstractChannels for MongoDb {
             .map_err(|_| create_database_error!("update_one", "channels"))
     }
 
+    // Remove a user from the members of a thread
+    async fn remove_user_from_thread(&self, channel: &str, user: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": channel
+                },
+                doc! {
+                    "$pull": {
+                        "members": user
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    // Remove a tag from every post in a forum
+    async fn remove_tag_from_posts(&self, forum_id: &str, tag_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "channel_type": "Thread",
+                    "parent": forum_id
+                },
+                doc! {
+                    "$pull": {
+                        "applied_tags": tag_id
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
+
     // Remove a user from all specified groups
     async fn remove_user_from_groups(&self, channel_ids: Vec<String>, user_id: &str) -> Result<()> {
         self.col::<Document>(COL)
@@ -236,7 +353,9 @@ impl AbstractChannels for MongoDb {
     async fn delete_channel(&self, channel: &Channel) -> Result<()> {
         let id = channel.id().to_string();
         let server_id = match channel {
-            Channel::TextChannel { server, .. } => Some(server),
+            Channel::TextChannel { server, .. } | Channel::ForumChannel { server, .. } => {
+                Some(server)
+            }
             _ => None,
         };
 
@@ -91,6 +91,25 @@ impl AbstractChannels for ReferenceDb {
         }
         Err(create_error!(NotFound))
     }
+    /// Fetch threads spawned in any of the given channels
+    async fn fetch_threads<'a>(
+        &self,
+        parent_ids: &'a [String],
+        include_archived: bool,
+    ) -> Result<Vec<Channel>> {
+        let channels = self.channels.lock().await;
+        Ok(channels
+            .values()
+            .filter(|channel| match channel {
+                Channel::Thread {
+                    parent, archived, ..
+                } => parent_ids.contains(parent) && (include_archived || !archived),
+                _ => false,
+            })
+            .cloned()
+            .collect())
+    }
+
     /// Insert a user to a group
     async fn add_user_to_group(&self, channel_id: &str, user_id: &str) -> Result<()> {
         let mut channels = self.channels.lock().await;
@@ -102,6 +121,21 @@ impl AbstractChannels for ReferenceDb {
             Err(create_error!(InvalidOperation))
         }
     }
+    /// Insert a user to the members of a thread
+    async fn add_user_to_thread(&self, channel_id: &str, user_id: &str) -> Result<()> {
+        let mut channels = self.channels.lock().await;
+
+        if let Some(Channel::Thread { members, .. }) = channels.get_mut(channel_id) {
+            if !members.iter().any(|member| member == user_id) {
+                members.push(String::from(user_id));
+            }
+
+            Ok(())
+        } else {
+            Err(create_error!(InvalidOperation))
+        }
+    }
+
     /// Insert channel role permissions
     async fn set_channel_role_permission(
         &self,
@@ -115,6 +149,9 @@ impl AbstractChannels for ReferenceDb {
             match &mut channel {
                 Channel::TextChannel {
                     role_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    role_permissions, ..
                 } => {
                     if role_permissions.get(role_id).is_some() {
                         role_permissions.remove(role_id);
@@ -132,6 +169,51 @@ impl AbstractChannels for ReferenceDb {
         }
     }
 
+    /// Insert channel member permissions
+    async fn set_channel_user_permission(
+        &self,
+        channel_id: &str,
+        user_id: &str,
+        permissions: OverrideField,
+    ) -> Result<()> {
+        let mut channels = self.channels.lock().await;
+
+        match channels.get_mut(channel_id) {
+            Some(
+                Channel::TextChannel {
+                    user_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    user_permissions, ..
+                },
+            ) => {
+                user_permissions.insert(String::from(user_id), permissions);
+                Ok(())
+            }
+            _ => Err(create_error!(NotFound)),
+        }
+    }
+
+    /// Remove channel member permissions
+    async fn remove_channel_user_permission(&self, channel_id: &str, user_id: &str) -> Result<()> {
+        let mut channels = self.channels.lock().await;
+
+        match channels.get_mut(channel_id) {
+            Some(
+                Channel::TextChannel {
+                    user_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    user_permissions, ..
+                },
+            ) => {
+                user_permissions.remove(user_id);
+                Ok(())
+            }
+            _ => Err(create_error!(NotFound)),
+        }
+    }
+
     // Update channel
     async fn update_channel(
         &self,
@@ -163,6 +245,37 @@ impl AbstractChannels for ReferenceDb {
         Err(create_error!(NotFound))
     }
 
+    // Remove a user from the members of a thread
+    async fn remove_user_from_thread(&self, channel: &str, user: &str) -> Result<()> {
+        let mut channels = self.channels.lock().await;
+        if let Some(Channel::Thread { members, .. }) = channels.get_mut(channel) {
+            members.retain(|member| member != user);
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    // Remove a tag from every post in a forum
+    async fn remove_tag_from_posts(&self, forum_id: &str, tag_id: &str) -> Result<()> {
+        let mut channels = self.channels.lock().await;
+
+        for channel in channels.values_mut() {
+            if let Channel::Thread {
+                parent,
+                applied_tags,
+                ..
+            } = channel
+            {
+                if parent == forum_id {
+                    applied_tags.retain(|tag| tag != tag_id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // Remove a user from all specified groups
     async fn remove_user_from_groups(&self, channel_ids: Vec<String>, user_id: &str) -> Result<()> {
         let mut channels = self.channels.lock().await;
@@ -217,6 +330,9 @@ impl AbstractChannels for ReferenceDb {
             }
             | Channel::TextChannel {
                 last_message_id, ..
+            }
+            | Channel::Thread {
+                last_message_id, ..
             } => *last_message_id = message_id.map(|id| id.to_string()),
             _ => (),
         };
@@ -0,0 +1,105 @@
+use redis_kiss::{get_connection, AsyncCommands};
+use revolt_models::v0;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::Database;
+
+/// Maximum number of event subscriptions a bot may have
+pub const MAX_EVENT_SUBSCRIPTIONS: usize = 5;
+
+/// Topic which is notified whenever a bot's event subscriptions change
+pub const EVENT_SUBSCRIPTIONS_TOPIC: &str = "event_subscriptions";
+
+auto_derived_partial!(
+    /// HTTP endpoint which a bot's events are delivered to
+    pub struct EventSubscription {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the bot this subscription belongs to
+        pub bot: String,
+        /// HTTPS endpoint events are sent to
+        pub url: String,
+        /// Secret used to sign requests
+        pub secret: String,
+        /// Event types to deliver
+        pub events: Vec<String>,
+        /// Whether delivery has been stopped after repeated failures
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub disabled: bool,
+    },
+    "PartialEventSubscription"
+);
+
+impl EventSubscription {
+    /// Create a new event subscription for a bot
+    pub async fn create(
+        db: &Database,
+        bot_id: &str,
+        data: v0::DataCreateEventSubscription,
+    ) -> Result<EventSubscription> {
+        if db.fetch_event_subscriptions(bot_id).await?.len() >= MAX_EVENT_SUBSCRIPTIONS {
+            return Err(create_error!(TooManyEventSubscriptions {
+                max: MAX_EVENT_SUBSCRIPTIONS
+            }));
+        }
+
+        let subscription = EventSubscription {
+            id: Ulid::new().to_string(),
+            bot: bot_id.to_string(),
+            url: data.url,
+            secret: nanoid::nanoid!(64),
+            events: data.events,
+            disabled: false,
+        };
+
+        db.insert_event_subscription(&subscription).await?;
+        EventSubscription::announce_changes(bot_id).await;
+        Ok(subscription)
+    }
+
+    /// Update subscription data
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        mut partial: PartialEventSubscription,
+        regenerate_secret: bool,
+    ) -> Result<()> {
+        if regenerate_secret {
+            partial.secret = Some(nanoid::nanoid!(64));
+        }
+
+        self.apply_options(partial.clone());
+        db.update_event_subscription(&self.id, &partial).await?;
+        EventSubscription::announce_changes(&self.bot).await;
+        Ok(())
+    }
+
+    /// Delete this subscription
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_event_subscription(&self.id).await?;
+        EventSubscription::announce_changes(&self.bot).await;
+        Ok(())
+    }
+
+    /// Let event delivery know a bot's subscriptions have changed so it can reload them
+    pub async fn announce_changes(bot_id: &str) {
+        let Ok(mut redis) = get_connection().await else {
+            warn!("Failed to announce event subscription changes for {bot_id}");
+            return;
+        };
+
+        if let Err(error) = redis
+            .publish::<_, _, ()>(EVENT_SUBSCRIPTIONS_TOPIC, bot_id)
+            .await
+        {
+            warn!("Failed to announce event subscription changes for {bot_id}: {error:?}");
+        }
+    }
+
+    /// Check whether an event type should be delivered to this subscription
+    pub fn wants(&self, event_type: &str) -> bool {
+        !self.disabled && self.events.iter().any(|event| event == event_type)
+    }
+}
@@ -0,0 +1,35 @@
+use revolt_result::Result;
+
+use crate::{EventSubscription, PartialEventSubscription};
+
+#[cfg(feature = "mongodb")]
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractEventSubscriptions: Sync + Send {
+    /// Insert a new event subscription into the database
+    async fn insert_event_subscription(&self, subscription: &EventSubscription) -> Result<()>;
+
+    /// Fetch an event subscription by its id
+    async fn fetch_event_subscription(&self, id: &str) -> Result<EventSubscription>;
+
+    /// Fetch all event subscriptions belonging to a bot
+    async fn fetch_event_subscriptions(&self, bot_id: &str) -> Result<Vec<EventSubscription>>;
+
+    /// Fetch all event subscriptions which have not been disabled
+    async fn fetch_active_event_subscriptions(&self) -> Result<Vec<EventSubscription>>;
+
+    /// Update a given event subscription with new information
+    async fn update_event_subscription(
+        &self,
+        id: &str,
+        partial: &PartialEventSubscription,
+    ) -> Result<()>;
+
+    /// Delete an event subscription by its id
+    async fn delete_event_subscription(&self, id: &str) -> Result<()>;
+
+    /// Delete all event subscriptions belonging to a bot
+    async fn delete_event_subscriptions(&self, bot_id: &str) -> Result<()>;
+}
@@ -0,0 +1,72 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{EventSubscription, PartialEventSubscription};
+
+use super::AbstractEventSubscriptions;
+
+static COL: &str = "event_subscriptions";
+
+#[async_trait]
+impl AbstractEventSubscriptions for MongoDb {
+    /// Insert a new event subscription into the database
+    async fn insert_event_subscription(&self, subscription: &EventSubscription) -> Result<()> {
+        query!(self, insert_one, COL, &subscription).map(|_| ())
+    }
+
+    /// Fetch an event subscription by its id
+    async fn fetch_event_subscription(&self, id: &str) -> Result<EventSubscription> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all event subscriptions belonging to a bot
+    async fn fetch_event_subscriptions(&self, bot_id: &str) -> Result<Vec<EventSubscription>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "bot": bot_id
+            }
+        )
+    }
+
+    /// Fetch all event subscriptions which have not been disabled
+    async fn fetch_active_event_subscriptions(&self) -> Result<Vec<EventSubscription>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "disabled": {
+                    "$ne": true
+                }
+            }
+        )
+    }
+
+    /// Update a given event subscription with new information
+    async fn update_event_subscription(
+        &self,
+        id: &str,
+        partial: &PartialEventSubscription,
+    ) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Delete an event subscription by its id
+    async fn delete_event_subscription(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Delete all event subscriptions belonging to a bot
+    async fn delete_event_subscriptions(&self, bot_id: &str) -> Result<()> {
+        self.col::<EventSubscription>(COL)
+            .delete_many(doc! {
+                "bot": bot_id
+            })
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
@@ -0,0 +1,81 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{EventSubscription, PartialEventSubscription};
+
+use super::AbstractEventSubscriptions;
+
+#[async_trait]
+impl AbstractEventSubscriptions for ReferenceDb {
+    /// Insert a new event subscription into the database
+    async fn insert_event_subscription(&self, subscription: &EventSubscription) -> Result<()> {
+        let mut event_subscriptions = self.event_subscriptions.lock().await;
+        if event_subscriptions.contains_key(&subscription.id) {
+            Err(create_database_error!("insert", "event_subscription"))
+        } else {
+            event_subscriptions.insert(subscription.id.to_string(), subscription.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch an event subscription by its id
+    async fn fetch_event_subscription(&self, id: &str) -> Result<EventSubscription> {
+        let event_subscriptions = self.event_subscriptions.lock().await;
+        event_subscriptions
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all event subscriptions belonging to a bot
+    async fn fetch_event_subscriptions(&self, bot_id: &str) -> Result<Vec<EventSubscription>> {
+        let event_subscriptions = self.event_subscriptions.lock().await;
+        Ok(event_subscriptions
+            .values()
+            .filter(|subscription| subscription.bot == bot_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all event subscriptions which have not been disabled
+    async fn fetch_active_event_subscriptions(&self) -> Result<Vec<EventSubscription>> {
+        let event_subscriptions = self.event_subscriptions.lock().await;
+        Ok(event_subscriptions
+            .values()
+            .filter(|subscription| !subscription.disabled)
+            .cloned()
+            .collect())
+    }
+
+    /// Update a given event subscription with new information
+    async fn update_event_subscription(
+        &self,
+        id: &str,
+        partial: &PartialEventSubscription,
+    ) -> Result<()> {
+        let mut event_subscriptions = self.event_subscriptions.lock().await;
+        if let Some(subscription) = event_subscriptions.get_mut(id) {
+            subscription.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete an event subscription by its id
+    async fn delete_event_subscription(&self, id: &str) -> Result<()> {
+        let mut event_subscriptions = self.event_subscriptions.lock().await;
+        if event_subscriptions.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete all event subscriptions belonging to a bot
+    async fn delete_event_subscriptions(&self, bot_id: &str) -> Result<()> {
+        let mut event_subscriptions = self.event_subscriptions.lock().await;
+        event_subscriptions.retain(|_, subscription| subscription.bot != bot_id);
+        Ok(())
+    }
+}
@@ -51,7 +51,12 @@ auto_derived!(
         /// File is a video with specific dimensions
         Video { width: isize, height: isize },
         /// File is audio
-        Audio,
+        Audio {
+            /// Length of the audio in milliseconds
+            duration: Option<u64>,
+            /// Peak amplitude of evenly spaced segments of the audio, scaled to 0-255
+            waveform: Option<Vec<u8>>,
+        },
     }
 );
 
@@ -77,6 +82,8 @@ impl FileHash {
 
             deleted: None,
             reported: None,
+            description: None,
+            spoiler: None,
 
             // TODO: remove this data
             metadata: self.metadata.clone(),
@@ -33,6 +33,13 @@ auto_derived_partial!(
         #[serde(skip_serializing_if = "Option::is_none")]
         pub reported: Option<bool>,
 
+        /// Description of this file for accessibility
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Whether this file should be hidden until clicked
+        #[serde(skip_serializing_if = "crate::if_option_false")]
+        pub spoiler: Option<bool>,
+
         // !!! DEPRECATED:
         /// Parsed metadata of this file
         pub metadata: Metadata,
@@ -71,6 +78,7 @@ auto_derived!(
         ChannelIcon,
         ServerIcon,
         RoleIcon,
+        ChannelExport,
     }
 
     /// Information about what the file was used for
@@ -89,6 +97,27 @@ impl File {
         db.fetch_attachment_hash(self.hash.as_ref().unwrap()).await
     }
 
+    /// Set the description and spoiler flag of this file
+    pub async fn set_details(
+        &mut self,
+        db: &Database,
+        description: Option<String>,
+        spoiler: Option<bool>,
+    ) -> Result<()> {
+        if description.is_none() && spoiler.is_none() {
+            return Ok(());
+        }
+
+        let partial = PartialFile {
+            description,
+            spoiler,
+            ..Default::default()
+        };
+
+        self.apply_options(partial.clone());
+        db.update_attachment(&self.id, &partial).await
+    }
+
     /// Use a file for a message attachment
     pub async fn use_attachment(
         db: &Database,
@@ -1,6 +1,6 @@
 use revolt_result::Result;
 
-use crate::File;
+use crate::{File, PartialFile};
 
 use super::FileUsedFor;
 
@@ -34,6 +34,9 @@ pub trait AbstractAttachments: Sync + Send {
         uploader_id: String,
     ) -> Result<File>;
 
+    /// Update an attachment with new information.
+    async fn update_attachment(&self, id: &str, partial: &PartialFile) -> Result<()>;
+
     /// Mark an attachment as having been reported.
     async fn mark_attachment_as_reported(&self, id: &str) -> Result<()>;
 
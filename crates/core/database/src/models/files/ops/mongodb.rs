@@ -6,6 +6,7 @@ use revolt_result::Result;
 use crate::File;
 use crate::FileUsedFor;
 use crate::MongoDb;
+use crate::PartialFile;
 
 use super::AbstractAttachments;
 
@@ -117,6 +118,11 @@ impl AbstractAttachments for MongoDb {
         Ok(file)
     }
 
+    /// Update an attachment with new information.
+    async fn update_attachment(&self, id: &str, partial: &PartialFile) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
     /// Mark an attachment as having been reported.
     async fn mark_attachment_as_reported(&self, id: &str) -> Result<()> {
         self.col::<Document>(COL)
@@ -2,6 +2,7 @@ use revolt_result::Result;
 
 use crate::File;
 use crate::FileUsedFor;
+use crate::PartialFile;
 use crate::ReferenceDb;
 
 use super::AbstractAttachments;
@@ -91,6 +92,17 @@ impl AbstractAttachments for ReferenceDb {
         }
     }
 
+    /// Update an attachment with new information.
+    async fn update_attachment(&self, id: &str, partial: &PartialFile) -> Result<()> {
+        let mut files = self.files.lock().await;
+        if let Some(file) = files.get_mut(id) {
+            file.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
     /// Mark an attachment as having been reported.
     async fn mark_attachment_as_reported(&self, id: &str) -> Result<()> {
         let mut files = self.files.lock().await;
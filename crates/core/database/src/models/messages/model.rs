@@ -1,24 +1,33 @@
 use indexmap::{IndexMap, IndexSet};
 use iso8601_timestamp::Timestamp;
+use redis_kiss::{get_connection, redis, AsyncCommands};
 use revolt_config::{config, FeaturesLimits};
 use revolt_models::v0::{
-    self, BulkMessageResponse, DataMessageSend, Embed, MessageAuthor, MessageFlags, MessageSort,
-    MessageWebhook, PushNotification, ReplyIntent, SendableEmbed, Text,
+    self, ActionRow, BulkMessageResponse, ChannelSlowmode, DataMessageSend, Embed, MessageAuthor,
+    MessageFlags, MessageSort, MessageWebhook, PushNotification, ReplyIntent, SendableEmbed, Text,
+};
+use revolt_parser::{parse_search_query, SearchHas, SearchQuery};
+use revolt_permissions::{
+    calculate_channel_permissions, ChannelPermission, PermissionQuery, PermissionValue,
 };
-use revolt_permissions::{calculate_channel_permissions, ChannelPermission, PermissionValue};
 use revolt_result::{ErrorType, Result};
-use std::time::SystemTime;
-use std::{collections::HashSet, hash::RandomState};
+use std::time::{Duration, SystemTime};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::RandomState,
+};
 use ulid::Ulid;
 use validator::Validate;
 
 use crate::{
     events::client::EventV1,
+    search,
     util::{
         bulk_permissions::BulkDatabasePermissionQuery, idempotency::IdempotencyKey,
         permissions::DatabasePermissionQuery,
     },
-    Channel, Database, Emoji, File, User, AMQP,
+    Channel, Database, Emoji, File, FileUsedFor, FileUsedForType, Metadata, PartialFile, User,
+    AMQP,
 };
 
 #[cfg(feature = "tasks")]
@@ -70,12 +79,30 @@ auto_derived_partial!(
         /// Information about how this message should be interacted with
         #[serde(skip_serializing_if = "Interactions::is_default", default)]
         pub interactions: Interactions,
+        /// Buttons and select menus attached to this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub components: Option<Vec<ActionRow>>,
         /// Name and / or avatar overrides for this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub masquerade: Option<Masquerade>,
         /// Whether or not the message in pinned
         #[serde(skip_serializing_if = "crate::if_option_false")]
         pub pinned: Option<bool>,
+        /// Summary of the thread spawned from this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread: Option<ThreadSummary>,
+        /// Poll attached to this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<Poll>,
+        /// Message this message was forwarded from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub forward: Option<MessageForward>,
+        /// Time at which this message will be deleted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
+        /// Prior versions of this message, oldest first
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub revisions: Vec<MessageRevision>,
 
         /// Bitfield of message flags
         #[serde(skip_serializing_if = "Option::is_none")]
@@ -119,6 +146,12 @@ auto_derived!(
             by: String,
             finished_at: Option<Timestamp>,
         },
+        #[serde(rename = "poll_closed")]
+        PollClosed {
+            id: String,
+            question: String,
+            results: Vec<PollResult>,
+        },
     }
 
     /// Name and / or avatar override information
@@ -149,6 +182,113 @@ auto_derived!(
         pub restrict_reactions: bool,
     }
 
+    /// Summary of a thread spawned from a message
+    pub struct ThreadSummary {
+        /// Id of the thread channel
+        pub id: String,
+        /// Number of messages sent in the thread
+        pub reply_count: u64,
+        /// Id of the last message sent in the thread
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_message_id: Option<String>,
+    }
+
+    /// Snapshot of a message forwarded from another channel
+    pub struct MessageForward {
+        /// Id of the original message
+        pub id: String,
+        /// Id of the channel the original message was sent in
+        pub channel: String,
+        /// Id of the user or webhook that sent the original message
+        pub author: String,
+        /// Time at which the original message was sent
+        pub timestamp: Timestamp,
+        /// Content of the original message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Attachments on the original message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub attachments: Option<Vec<ForwardedAttachment>>,
+    }
+
+    /// Snapshot of an attachment on a forwarded message
+    ///
+    /// Each forward has its own copy of the file record
+    pub struct ForwardedAttachment {
+        /// Id of the file
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Tag / bucket the file was uploaded to
+        pub tag: String,
+        /// Original filename
+        pub filename: String,
+        /// Parsed metadata of the file
+        pub metadata: Metadata,
+        /// Raw content type of the file
+        pub content_type: String,
+        /// Size of the file (in bytes)
+        pub size: isize,
+        /// Description of the file for accessibility
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Whether the file should be hidden until clicked
+        #[serde(skip_serializing_if = "crate::if_option_false")]
+        pub spoiler: Option<bool>,
+    }
+
+    /// Poll attached to a message
+    pub struct Poll {
+        /// Question being asked
+        pub question: String,
+        /// Options which can be voted for
+        pub options: Vec<PollOption>,
+        /// Hashmap of option IDs to array of user IDs
+        #[serde(skip_serializing_if = "IndexMap::is_empty", default)]
+        pub votes: IndexMap<String, IndexSet<String>>,
+        /// Whether users may vote for more than one option
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub multi_select: bool,
+        /// Whether voters are hidden from everyone but moderators
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub anonymous: bool,
+        /// Time at which this poll closes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
+        /// Whether this poll has been closed
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub closed: bool,
+    }
+
+    /// Option on a poll
+    pub struct PollOption {
+        /// Option Id
+        pub id: String,
+        /// Option text
+        pub text: String,
+    }
+
+    /// Final tally for an option on a closed poll
+    pub struct PollResult {
+        /// Option Id
+        pub id: String,
+        /// Option text
+        pub text: String,
+        /// Number of votes cast for this option
+        pub votes: u64,
+    }
+
+    /// Prior version of a message before it was edited
+    pub struct MessageRevision {
+        /// Message content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Attached embeds
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub embeds: Option<Vec<Embed>>,
+        /// Time at which this version was replaced
+        pub timestamp: Timestamp,
+    }
+
     /// Appended Information
     pub struct AppendMessage {
         /// Additional embeds to include in this message
@@ -190,6 +330,24 @@ auto_derived!(
         pub query: Option<String>,
         /// Search for pinned
         pub pinned: Option<bool>,
+        /// Any of these parent channel IDs
+        pub channels: Option<Vec<String>>,
+        /// Any of these message author IDs
+        pub authors: Option<Vec<String>>,
+        /// Message mentions all of these user IDs
+        pub mentions: Option<Vec<String>>,
+        /// Search for messages with attachments
+        pub has_attachments: Option<bool>,
+        /// Search for messages with embeds
+        pub has_embeds: Option<bool>,
+        /// Search for messages containing links
+        pub has_links: Option<bool>,
+        /// Sent before this Unix timestamp (in milliseconds)
+        pub sent_before: Option<u64>,
+        /// Sent at or after this Unix timestamp (in milliseconds)
+        pub sent_after: Option<u64>,
+        /// Any of these message IDs
+        pub ids: Option<Vec<String>>,
     }
 
     /// Message Query
@@ -209,6 +367,7 @@ auto_derived!(
     /// Optional fields on message
     pub enum FieldsMessage {
         Pinned,
+        Thread,
     }
 );
 
@@ -255,15 +414,570 @@ impl Default for Message {
             replies: None,
             reactions: Default::default(),
             interactions: Default::default(),
+            components: None,
             masquerade: None,
             flags: None,
             pinned: None,
+            thread: None,
+            poll: None,
+            forward: None,
+            expires_at: None,
+            revisions: vec![],
         }
     }
 }
 
 #[allow(clippy::disallowed_methods)]
+impl MessageFilter {
+    /// Parse a structured search query and apply its operators to this filter
+    ///
+    /// Users given as `username#discriminator` are resolved to their ids
+    pub async fn apply_search(&mut self, db: &Database, query: &str) -> Result<()> {
+        async fn resolve_users(db: &Database, users: Vec<String>) -> Result<Vec<String>> {
+            let mut ids = Vec::with_capacity(users.len());
+            for user in users {
+                if let Some((username, discriminator)) = user.rsplit_once('#') {
+                    ids.push(
+                        db.fetch_user_by_username(username, discriminator)
+                            .await
+                            .map_err(|_| create_error!(UnknownUser))?
+                            .id,
+                    );
+                } else {
+                    ids.push(user);
+                }
+            }
+
+            Ok(ids)
+        }
+
+        let SearchQuery {
+            text,
+            from,
+            mentions,
+            has,
+            before,
+            after,
+            channels,
+        } = parse_search_query(query).map_err(|error| {
+            create_error!(FailedValidation {
+                error: error.to_string()
+            })
+        })?;
+
+        self.query = text;
+
+        if !from.is_empty() {
+            self.authors = Some(resolve_users(db, from).await?);
+        }
+
+        if !mentions.is_empty() {
+            self.mentions = Some(resolve_users(db, mentions).await?);
+        }
+
+        if !channels.is_empty() {
+            // Narrow down any channels the caller has already restricted the search to
+            self.channels = Some(match self.channels.take() {
+                Some(existing) => existing
+                    .into_iter()
+                    .filter(|channel| channels.contains(channel))
+                    .collect(),
+                None => channels,
+            });
+        }
+
+        for has in has {
+            match has {
+                SearchHas::Attachment => self.has_attachments = Some(true),
+                SearchHas::Embed => self.has_embeds = Some(true),
+                SearchHas::Link => self.has_links = Some(true),
+            }
+        }
+
+        self.sent_before = before;
+        self.sent_after = after;
+
+        Ok(())
+    }
+}
+
 impl Message {
+    /// Check the user is not in slowmode for the given channel, starting a new slowmode period if not
+    async fn check_slowmode(
+        channel: &Channel,
+        user: &User,
+        permissions: &PermissionValue,
+    ) -> Result<()> {
+        if !permissions.has_channel_permission(ChannelPermission::BypassSlowmode) {
+            if let Channel::TextChannel {
+                slowmode: Some(channel_slowmode),
+                id: channel_id,
+                ..
+            } = channel
+            {
+                if *channel_slowmode > 0 {
+                    if let Ok(conn) = get_connection().await {
+                        let mut conn = conn.into_inner();
+
+                        let slowmode_key = format!("slowmode:{}:{}", user.id, channel_id);
+
+                        // Atomic check-and-set: only set if absent and apply expiry in one command.
+                        let set_result: Option<String> = conn
+                            .set_options(
+                                &slowmode_key,
+                                "1", // The value doesn't matter, only the key's existence
+                                redis::SetOptions::default()
+                                    .conditional_set(redis::ExistenceCheck::NX)
+                                    .with_expiration(redis::SetExpiry::EX(
+                                        *channel_slowmode as usize,
+                                    )),
+                            )
+                            .await
+                            .unwrap_or(None);
+
+                        if set_result.is_some() {
+                            let idx_key = format!("slowmode_idx:{}", user.id);
+                            conn.sadd::<_, _, ()>(&idx_key, channel_id.as_str())
+                                .await
+                                .ok();
+                            conn.expire::<_, ()>(&idx_key, *channel_slowmode as usize)
+                                .await
+                                .ok();
+                        }
+
+                        // If `set_result` is None, the `NX` condition failed because the key already exists.
+                        // This means the user is currently in slowmode.
+                        if set_result.is_none() {
+                            // Fetch the remaining TTL to accurately populate the retry_after field
+                            let ttl: i64 = conn.ttl(&slowmode_key).await.unwrap_or(0);
+
+                            // Redis returns positive integers for valid TTLs
+                            if ttl > 0 {
+                                EventV1::UserSlowmodes {
+                                    slowmodes: vec![ChannelSlowmode {
+                                        channel_id: channel_id.to_string(),
+                                        duration: *channel_slowmode,
+                                        retry_after: ttl as u64,
+                                    }],
+                                }
+                                .private(user.id.clone())
+                                .await;
+                                return Err(create_error!(InSlowmode {
+                                    retry_after: ttl as u64
+                                }));
+                            }
+                        } else {
+                            EventV1::UserSlowmodes {
+                                slowmodes: vec![ChannelSlowmode {
+                                    channel_id: channel_id.to_string(),
+                                    duration: *channel_slowmode,
+                                    retry_after: *channel_slowmode,
+                                }],
+                            }
+                            .private(user.id.clone())
+                            .await;
+                        }
+                    }
+                    // If Redis connection fails, just skip the slowmode check
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Create message from API data on behalf of a user
+    ///
+    /// Checks the user is allowed to send this message in the given channel,
+    /// including permissions, slowmode and mention restrictions.
+    pub async fn create_from_user(
+        db: &Database,
+        amqp: Option<&AMQP>,
+        channel: Channel,
+        user: &User,
+        data: DataMessageSend,
+        idempotency: IdempotencyKey,
+    ) -> Result<v0::Message> {
+        // Ensure we have permissions to send a message
+        let mut query = DatabasePermissionQuery::new(db, user).channel(&channel);
+        let permissions = calculate_channel_permissions(&mut query).await;
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+        // Archived threads are read-only
+        if let Channel::Thread { archived: true, .. } = &channel {
+            return Err(create_error!(ThreadArchived));
+        }
+
+        // Verify permissions for masquerade
+        if let Some(masq) = &data.masquerade {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
+
+            if masq.colour.is_some() {
+                permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageRole)?;
+            }
+        }
+
+        // Check permissions for embeds
+        if data.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
+        }
+
+        // Check permissions for files
+        if data.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
+        }
+
+        Message::check_slowmode(&channel, user, &permissions).await?;
+
+        // Ensure interactions information is correct
+        if let Some(interactions) = &data.interactions {
+            let interactions: Interactions = interactions.clone().into();
+            interactions.validate(db, &permissions).await?;
+        }
+
+        // Disallow mentions for new users (TRUST-0: <12 hours age) in public servers
+        let allow_mentions = if let Some(server) = query.server_ref() {
+            if server.discoverable {
+                (Ulid::from_string(&user.id)
+                    .unwrap()
+                    .datetime()
+                    .elapsed()
+                    .expect("Time went backwards"))
+                    >= Duration::from_hours(12)
+            } else {
+                true
+            }
+        } else {
+            true
+        };
+
+        // Create the message
+        let author: v0::User = user.clone().into(db, Some(user)).await;
+
+        // Make sure we have server member (edge case if server owner)
+        query.are_we_a_member().await;
+
+        // Create model user / members
+        let model_user = user
+            .clone()
+            .into_known_static(revolt_presence::is_online(&user.id).await)
+            .await;
+
+        let model_member: Option<v0::Member> = query
+            .member_ref()
+            .as_ref()
+            .map(|member| member.clone().into_owned().into());
+
+        Ok(Message::create_from_api(
+            db,
+            amqp,
+            channel,
+            data,
+            MessageAuthor::User(&author),
+            Some(model_user.clone()),
+            model_member.clone(),
+            user.limits().await,
+            idempotency,
+            permissions.has_channel_permission(ChannelPermission::SendEmbeds),
+            allow_mentions,
+        )
+        .await?
+        .into_model(Some(model_user), model_member))
+    }
+
+    /// Forward this message into another channel on behalf of a user
+    ///
+    /// Checks the user can read this message and send messages in the target channel.
+    pub async fn forward(
+        &self,
+        db: &Database,
+        amqp: Option<&AMQP>,
+        source: &Channel,
+        target: &Channel,
+        user: &User,
+    ) -> Result<v0::Message> {
+        // Ensure we can read the original message
+        let mut query = DatabasePermissionQuery::new(db, user).channel(source);
+        calculate_channel_permissions(&mut query)
+            .await
+            .throw_if_lacking_channel_permission(ChannelPermission::ReadMessageHistory)?;
+
+        // Ensure we have permissions to send a message
+        let mut query = DatabasePermissionQuery::new(db, user).channel(target);
+        let permissions = calculate_channel_permissions(&mut query).await;
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+        if self.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
+        }
+
+        // Messages can only be sent in the posts of a forum
+        if matches!(target, Channel::ForumChannel { .. }) {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        // Archived threads are read-only
+        if let Channel::Thread { archived: true, .. } = target {
+            return Err(create_error!(ThreadArchived));
+        }
+
+        // System messages can't be forwarded
+        if self.system.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        Message::check_slowmode(target, user, &permissions).await?;
+
+        // Forwarding a forwarded message keeps the original attribution
+        let (mut forward, files) = if let Some(forward) = &self.forward {
+            let mut files = vec![];
+            for attachment in forward.attachments.iter().flatten() {
+                files.push(db.fetch_attachment(&attachment.tag, &attachment.id).await?);
+            }
+
+            (forward.clone(), files)
+        } else {
+            (
+                MessageForward {
+                    id: self.id.clone(),
+                    channel: self.channel.clone(),
+                    author: self.author.clone(),
+                    timestamp: Timestamp::from(
+                        Ulid::from_string(&self.id)
+                            .map_err(|_| create_error!(InvalidOperation))?
+                            .datetime(),
+                    ),
+                    content: self.content.clone(),
+                    attachments: None,
+                },
+                self.attachments.clone().unwrap_or_default(),
+            )
+        };
+
+        let id = Ulid::new().to_string();
+
+        // Each forward gets its own file records so they outlive the original message
+        if !files.is_empty() {
+            let mut attachments = Vec::with_capacity(files.len());
+            for file in files {
+                let copy = File {
+                    id: Ulid::new().to_string(),
+                    used_for: Some(FileUsedFor {
+                        id: id.clone(),
+                        object_type: FileUsedForType::Message,
+                    }),
+                    message_id: Some(id.clone()),
+                    ..file
+                };
+
+                db.insert_attachment(&copy).await?;
+                attachments.push(ForwardedAttachment {
+                    id: copy.id,
+                    tag: copy.tag,
+                    filename: copy.filename,
+                    metadata: copy.metadata,
+                    content_type: copy.content_type,
+                    size: copy.size,
+                    description: copy.description,
+                    spoiler: copy.spoiler,
+                });
+            }
+
+            forward.attachments = Some(attachments);
+        }
+
+        let mut message = Message {
+            id,
+            channel: target.id().to_string(),
+            author: user.id.clone(),
+            forward: Some(forward),
+            ..Default::default()
+        };
+
+        // Apply the target channel's default expiry,
+        // a forward must not outlive an expiring original
+        message.expires_at = self.expires_at;
+        if let Some(ttl) = target.resolve_message_ttl(db).await? {
+            let expires_at =
+                Timestamp::now_utc() + iso8601_timestamp::Duration::seconds(ttl as i64);
+
+            if message
+                .expires_at
+                .is_none_or(|existing| expires_at < existing)
+            {
+                message.expires_at = Some(expires_at);
+            }
+        }
+
+        // Make sure we have server member (edge case if server owner)
+        query.are_we_a_member().await;
+
+        // Create model user / members
+        let author: v0::User = user.clone().into(db, Some(user)).await;
+        let model_user = user
+            .clone()
+            .into_known_static(revolt_presence::is_online(&user.id).await)
+            .await;
+
+        let model_member: Option<v0::Member> = query
+            .member_ref()
+            .as_ref()
+            .map(|member| member.clone().into_owned().into());
+
+        message
+            .send(
+                db,
+                amqp,
+                MessageAuthor::User(&author),
+                Some(model_user.clone()),
+                model_member.clone(),
+                target,
+                false,
+            )
+            .await?;
+
+        Ok(message.into_model(Some(model_user), model_member))
+    }
+
+    /// Publish this message to every channel following its announcement channel
+    ///
+    /// Copies are sent through each follower's webhook, masquerading as the source server
+    pub async fn publish(
+        &mut self,
+        db: &Database,
+        amqp: Option<&AMQP>,
+        channel: &Channel,
+    ) -> Result<()> {
+        let Channel::TextChannel {
+            server,
+            announcement: true,
+            ..
+        } = channel
+        else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        // System messages can't be published
+        if self.system.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let mut flags = MessageFlagsValue(self.flags.unwrap_or_default());
+        if flags.has(MessageFlags::Published) {
+            return Err(create_error!(AlreadyPublished));
+        }
+
+        // Mark the message as published before sending any copies,
+        // so that only one of several concurrent requests goes through
+        if !db
+            .add_message_flag(&self.id, 1 << MessageFlags::Published as u32)
+            .await?
+        {
+            return Err(create_error!(AlreadyPublished));
+        }
+
+        flags.set(MessageFlags::Published, true);
+        self.flags = Some(flags.0);
+
+        EventV1::MessageUpdate {
+            id: self.id.clone(),
+            channel: self.channel.clone(),
+            data: PartialMessage {
+                flags: self.flags,
+                ..Default::default()
+            }
+            .into(),
+            clear: vec![],
+        }
+        .p(self.channel.clone())
+        .await;
+
+        let server = db.fetch_server(server).await?;
+        let config = config().await;
+        let masquerade = Masquerade {
+            name: Some(server.name),
+            avatar: server
+                .icon
+                .map(|icon| format!("{}/icons/{}", config.hosts.autumn, icon.id)),
+            colour: None,
+        };
+
+        for webhook in db.fetch_webhooks_following(channel.id()).await? {
+            let webhook_id = webhook.id.clone();
+            if let Err(error) = self
+                .publish_to(db, amqp, webhook.into(), masquerade.clone())
+                .await
+            {
+                warn!(
+                    "Failed to publish message {} through webhook {webhook_id}: {error:?}",
+                    self.id
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Send a copy of this message through a follower's webhook
+    ///
+    /// Each copy gets its own file records so deleting it leaves the original's files intact
+    async fn publish_to(
+        &self,
+        db: &Database,
+        amqp: Option<&AMQP>,
+        webhook: v0::Webhook,
+        masquerade: Masquerade,
+    ) -> Result<()> {
+        let target = db.fetch_channel(&webhook.channel_id).await?;
+        let id = Ulid::new().to_string();
+
+        let attachments = if let Some(files) = &self.attachments {
+            let mut copies = Vec::with_capacity(files.len());
+            for file in files {
+                let copy = File {
+                    id: Ulid::new().to_string(),
+                    used_for: Some(FileUsedFor {
+                        id: id.clone(),
+                        object_type: FileUsedForType::Message,
+                    }),
+                    message_id: Some(id.clone()),
+                    ..file.clone()
+                };
+
+                db.insert_attachment(&copy).await?;
+                copies.push(copy);
+            }
+
+            Some(copies)
+        } else {
+            None
+        };
+
+        let mut message = Message {
+            id,
+            channel: target.id().to_string(),
+            author: webhook.id.clone(),
+            webhook: Some(webhook.clone().into()),
+            content: self.content.clone(),
+            embeds: self.embeds.clone(),
+            attachments,
+            masquerade: Some(masquerade),
+            ..Default::default()
+        };
+
+        message
+            .send(
+                db,
+                amqp,
+                MessageAuthor::Webhook(&webhook),
+                None,
+                None,
+                &target,
+                false,
+            )
+            .await
+    }
+
     /// Create message from API data
     #[allow(clippy::too_many_arguments)]
     pub async fn create_from_api(
@@ -292,10 +1006,16 @@ impl Message {
             .await
             .map_err(|_| create_error!(InvalidOperation))?;
 
+        // Messages can only be sent in the posts of a forum
+        if matches!(channel, Channel::ForumChannel { .. }) {
+            return Err(create_error!(InvalidOperation));
+        }
+
         // Check the message is not empty
         if (data.content.as_ref().is_none_or(|v| v.is_empty()))
             && (data.attachments.as_ref().is_none_or(|v| v.is_empty()))
             && (data.embeds.as_ref().is_none_or(|v| v.is_empty()))
+            && data.poll.is_none()
         {
             return Err(create_error!(EmptyMessage));
         }
@@ -305,10 +1025,16 @@ impl Message {
         let mut mentions_everyone = false;
         let mut mentions_online = false;
         let mut suppress_notifications = false;
+        let mut voice_message = false;
 
         if let Some(raw_flags) = &data.flags {
-            if raw_flags > &7 {
-                // quick path to failure: bigger than all the bits combined
+            let settable = (1 << MessageFlags::SuppressNotifications as u32)
+                | (1 << MessageFlags::MentionsEveryone as u32)
+                | (1 << MessageFlags::MentionsOnline as u32)
+                | (1 << MessageFlags::VoiceMessage as u32);
+
+            if raw_flags & !settable != 0 {
+                // quick path to failure: sets a bit which cannot be set by the sender
                 return Err(create_error!(InvalidProperty));
             }
 
@@ -317,6 +1043,7 @@ impl Message {
             suppress_notifications = flags.has(MessageFlags::SuppressNotifications);
             mentions_everyone = allow_mentions && flags.has(MessageFlags::MentionsEveryone);
             mentions_online = allow_mentions && flags.has(MessageFlags::MentionsOnline);
+            voice_message = flags.has(MessageFlags::VoiceMessage);
 
             // Not a bot, and attempting to set mention flags
             if user.as_ref().is_some_and(|u| u.bot.as_ref().is_none())
@@ -331,7 +1058,9 @@ impl Message {
         }
 
         let server_id = match channel {
-            Channel::TextChannel { ref server, .. } => Some(server.clone()),
+            Channel::TextChannel { ref server, .. } | Channel::Thread { ref server, .. } => {
+                Some(server.clone())
+            }
             _ => None,
         };
 
@@ -350,6 +1079,13 @@ impl Message {
             }
         }
 
+        // Interactions are delivered to the bot which sent the message
+        if data.components.is_some()
+            && !matches!(&author, MessageAuthor::User(user) if user.bot.is_some())
+        {
+            return Err(create_error!(IsNotBot));
+        }
+
         let (author_id, webhook) = match &author {
             MessageAuthor::User(user) => (user.id.clone(), None),
             MessageAuthor::Webhook(webhook) => (webhook.id.clone(), Some((*webhook).clone())),
@@ -366,6 +1102,7 @@ impl Message {
                 .interactions
                 .map(|interactions| interactions.into())
                 .unwrap_or_default(),
+            components: data.components,
             author: author_id,
             webhook: webhook.map(|w| w.into()),
             flags: data.flags,
@@ -498,7 +1235,7 @@ impl Message {
                     user_mentions.retain(|m| recipients_hash.contains(m));
                     role_mentions.clear();
                 }
-                Channel::TextChannel { ref server, .. } => {
+                Channel::TextChannel { ref server, .. } | Channel::Thread { ref server, .. } => {
                     let mentions_vec = Vec::from_iter(user_mentions.iter().cloned());
 
                     let valid_members = db.fetch_members(server.as_str(), &mentions_vec[..]).await;
@@ -527,7 +1264,7 @@ impl Message {
                         return Err(create_error!(InternalError));
                     }
                 }
-                Channel::SavedMessages { .. } => {
+                Channel::SavedMessages { .. } | Channel::ForumChannel { .. } => {
                     user_mentions.clear();
                 }
             }
@@ -554,7 +1291,8 @@ impl Message {
         flag_value
             .set(MessageFlags::SuppressNotifications, suppress_notifications)
             .set(MessageFlags::MentionsEveryone, mentions_everyone)
-            .set(MessageFlags::MentionsOnline, mentions_online);
+            .set(MessageFlags::MentionsOnline, mentions_online)
+            .set(MessageFlags::VoiceMessage, voice_message);
 
         message.flags = Some(flag_value.0);
 
@@ -580,9 +1318,30 @@ impl Message {
             }));
         }
 
-        for attachment_id in data.attachments.as_deref().unwrap_or_default() {
-            attachments
-                .push(File::use_attachment(db, attachment_id, &message_id, author.id()).await?);
+        // Voice messages must consist of a single audio file
+        if voice_message {
+            let [attachment] = data.attachments.as_deref().unwrap_or_default() else {
+                return Err(create_error!(InvalidFlagValue));
+            };
+
+            let file = db.fetch_attachment("attachments", attachment.id()).await?;
+            if !matches!(file.metadata, Metadata::Audio { .. }) {
+                return Err(create_error!(InvalidFlagValue));
+            }
+        }
+
+        for attachment in data.attachments.as_deref().unwrap_or_default() {
+            let mut file =
+                File::use_attachment(db, attachment.id(), &message_id, author.id()).await?;
+
+            file.set_details(
+                db,
+                attachment.description().map(str::to_owned),
+                attachment.spoiler(),
+            )
+            .await?;
+
+            attachments.push(file);
         }
 
         if !attachments.is_empty() {
@@ -594,6 +1353,49 @@ impl Message {
             message.attach_sendable_embed(db, sendable_embed).await?;
         }
 
+        // Attach poll to message.
+        if let Some(poll) = data.poll {
+            if poll.options.len() > config.features.limits.global.poll_options {
+                return Err(create_error!(TooManyPollOptions {
+                    max: config.features.limits.global.poll_options,
+                }));
+            }
+
+            if poll.options.len() < 2 {
+                return Err(create_error!(InvalidProperty));
+            }
+
+            message.poll.replace(Poll {
+                question: poll.question,
+                options: poll
+                    .options
+                    .into_iter()
+                    .map(|option| PollOption {
+                        id: Ulid::new().to_string(),
+                        text: option.text,
+                    })
+                    .collect(),
+                votes: IndexMap::new(),
+                multi_select: poll.multi_select,
+                anonymous: poll.anonymous,
+                expires_at: poll.duration.map(|duration| {
+                    Timestamp::now_utc() + iso8601_timestamp::Duration::seconds(duration as i64)
+                }),
+                closed: false,
+            });
+        }
+
+        // Set expiry, falling back to the channel's default
+        let ttl = match data.expires_in {
+            Some(ttl) => Some(ttl),
+            None => channel.resolve_message_ttl(db).await?,
+        };
+
+        if let Some(ttl) = ttl {
+            message.expires_at =
+                Some(Timestamp::now_utc() + iso8601_timestamp::Duration::seconds(ttl as i64));
+        }
+
         // Set content
         message.content = data.content;
 
@@ -621,6 +1423,7 @@ impl Message {
         mentions_elsewhere: bool,
     ) -> Result<()> {
         db.insert_message(self).await?;
+        search::index_message(self).await;
 
         // Fan out events
         EventV1::Message(self.clone().into_model(user, member))
@@ -688,6 +1491,35 @@ impl Message {
         )
         .await?;
 
+        // Update the summary on the message this thread was spawned from
+        if let Channel::Thread {
+            parent,
+            message: Some(message),
+            ..
+        } = channel
+        {
+            db.add_thread_reply(message, &self.id).await?;
+
+            if let Ok(Message {
+                thread: Some(thread),
+                ..
+            }) = db.fetch_message(message).await
+            {
+                EventV1::MessageUpdate {
+                    id: message.to_string(),
+                    channel: parent.to_string(),
+                    data: PartialMessage {
+                        thread: Some(thread),
+                        ..Default::default()
+                    }
+                    .into(),
+                    clear: vec![],
+                }
+                .p(parent.to_string())
+                .await;
+            }
+        }
+
         let is_dm_or_group = matches!(
             channel,
             Channel::DirectMessage { .. } | Channel::Group { .. }
@@ -718,7 +1550,7 @@ impl Message {
                                 .filter(|uid| *uid != author.id())
                                 .cloned()
                                 .collect(),
-                            Channel::TextChannel { .. } => {
+                            Channel::TextChannel { .. } | Channel::Thread { .. } => {
                                 self.mentions.clone().unwrap_or_default()
                             }
                             _ => vec![],
@@ -776,6 +1608,68 @@ impl Message {
         ping || self.role_mentions.is_some()
     }
 
+    /// Record the current content and embeds of this message as a revision,
+    /// if the given edit changes either of them
+    ///
+    /// Should be called before applying an edit
+    pub async fn push_revision(&mut self, db: &Database, partial: &PartialMessage) -> Result<()> {
+        let content_changed = partial
+            .content
+            .as_ref()
+            .is_some_and(|content| Some(content) != self.content.as_ref());
+
+        let embeds_changed = partial
+            .embeds
+            .as_ref()
+            .is_some_and(|embeds| embeds.as_slice() != self.embeds.as_deref().unwrap_or_default());
+
+        if !content_changed && !embeds_changed {
+            return Ok(());
+        }
+
+        let revision = MessageRevision {
+            content: self.content.clone(),
+            embeds: self.embeds.clone(),
+            timestamp: Timestamp::now_utc(),
+        };
+
+        let limit = config().await.features.limits.global.message_revisions;
+        db.push_message_revision(&self.id, &revision, limit).await?;
+
+        self.revisions.push(revision);
+        if self.revisions.len() > limit {
+            let excess = self.revisions.len() - limit;
+            self.revisions.drain(..excess);
+        }
+
+        Ok(())
+    }
+
+    /// Change the description and spoiler flag of attachments on this message
+    ///
+    /// Returns the full list of attachments with changes applied,
+    /// the files themselves are only written once the message is updated
+    pub fn apply_attachment_details(
+        &self,
+        intents: Vec<v0::AttachmentIntent>,
+    ) -> Result<Vec<File>> {
+        let mut attachments = self.attachments.clone().unwrap_or_default();
+        for intent in intents {
+            let file = attachments
+                .iter_mut()
+                .find(|file| file.id == intent.id)
+                .ok_or_else(|| create_error!(UnknownAttachment))?;
+
+            file.apply_options(PartialFile {
+                description: intent.description,
+                spoiler: intent.spoiler,
+                ..Default::default()
+            });
+        }
+
+        Ok(attachments)
+    }
+
     /// Update message data
     pub async fn update(
         &mut self,
@@ -783,6 +1677,32 @@ impl Message {
         partial: PartialMessage,
         remove: Vec<FieldsMessage>,
     ) -> Result<()> {
+        // Attachment details are also stored on the files themselves
+        if let Some(attachments) = &partial.attachments {
+            for file in attachments {
+                let changed = self
+                    .attachments
+                    .iter()
+                    .flatten()
+                    .find(|existing| existing.id == file.id)
+                    .is_some_and(|existing| {
+                        existing.description != file.description || existing.spoiler != file.spoiler
+                    });
+
+                if changed {
+                    db.update_attachment(
+                        &file.id,
+                        &PartialFile {
+                            description: file.description.clone(),
+                            spoiler: file.spoiler,
+                            ..Default::default()
+                        },
+                    )
+                    .await?;
+                }
+            }
+        }
+
         self.apply_options(partial.clone());
 
         for field in &remove {
@@ -792,6 +1712,10 @@ impl Message {
         db.update_message(&self.id, &partial, remove.clone())
             .await?;
 
+        if partial.content.is_some() {
+            search::index_message(self).await;
+        }
+
         EventV1::MessageUpdate {
             id: self.id.clone(),
             channel: self.channel.clone(),
@@ -812,8 +1736,7 @@ impl Message {
         include_users: Option<bool>,
         server_id: Option<&str>,
     ) -> Result<BulkMessageResponse> {
-        let messages: Vec<v0::Message> = db
-            .fetch_messages(query)
+        let messages: Vec<v0::Message> = search::fetch_messages(db, query)
             .await?
             .into_iter()
             .map(|msg| msg.into_model(None, None))
@@ -854,6 +1777,7 @@ impl Message {
                                 users.push(by.clone());
                             }
                             v0::SystemMessage::CallStarted { by, .. } => users.push(by.clone()),
+                            v0::SystemMessage::PollClosed { .. } => {}
                         }
                     }
                     users
@@ -995,56 +1919,33 @@ impl Message {
         }
     }
 
-    /// Delete a message
-    pub async fn delete(&self, db: &Database) -> Result<()> {
-        let file_ids = self
-            .attachments
+    /// Ids of the files owned by this message, including those of a forwarded message
+    fn file_ids(&self) -> impl Iterator<Item = String> + '_ {
+        self.attachments
             .iter()
             .flatten()
             .map(|file| file.id.clone())
-            .collect::<Vec<_>>();
+            .chain(
+                self.forward
+                    .iter()
+                    .flat_map(|forward| forward.attachments.iter().flatten())
+                    .map(|attachment| attachment.id.clone()),
+            )
+    }
+
+    /// Delete a message
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        let file_ids = self.file_ids().collect::<Vec<_>>();
 
         if !file_ids.is_empty() {
             db.mark_attachments_as_deleted(&file_ids).await?;
         }
 
         db.delete_message(&self.id).await?;
+        search::remove_messages(std::slice::from_ref(&self.id)).await;
 
-        if let Ok(mut channel) = db.fetch_channel(&self.channel).await {
-            match &channel {
-                Channel::DirectMessage {
-                    last_message_id, ..
-                }
-                | Channel::Group {
-                    last_message_id, ..
-                }
-                | Channel::TextChannel {
-                    last_message_id, ..
-                } => {
-                    if last_message_id.is_some() && last_message_id.as_ref().unwrap() == &self.id {
-                        let new_last_message_id =
-                            db.fetch_last_message(channel.id()).await.unwrap();
-
-                        db.update_last_messsage_id(channel.id(), new_last_message_id.as_deref())
-                            .await?;
-
-                        if new_last_message_id.is_some() {
-                            EventV1::ChannelUpdate {
-                                id: channel.id().to_string(),
-                                data: revolt_models::v0::PartialChannel {
-                                    last_message_id: new_last_message_id,
-                                    ..Default::default()
-                                },
-                                clear: vec![],
-                            }
-                            .p(channel.id().to_string())
-                            .await;
-                        }
-                    }
-                }
-                _ => (),
-            }
-        }
+        Message::update_channel_after_delete(db, &self.channel, std::slice::from_ref(&self.id))
+            .await?;
 
         EventV1::MessageDelete {
             id: self.id.clone(),
@@ -1055,6 +1956,108 @@ impl Message {
         Ok(())
     }
 
+    /// Point a channel, and the summary of a thread spawned in it,
+    /// away from messages which have been deleted from it
+    async fn update_channel_after_delete(
+        db: &Database,
+        channel_id: &str,
+        ids: &[String],
+    ) -> Result<()> {
+        let Ok(channel) = db.fetch_channel(channel_id).await else {
+            return Ok(());
+        };
+
+        let last_message_id = match &channel {
+            Channel::DirectMessage {
+                last_message_id, ..
+            }
+            | Channel::Group {
+                last_message_id, ..
+            }
+            | Channel::TextChannel {
+                last_message_id, ..
+            }
+            | Channel::Thread {
+                last_message_id, ..
+            } => last_message_id.clone(),
+            _ => return Ok(()),
+        };
+
+        if last_message_id
+            .as_ref()
+            .is_some_and(|last_message_id| ids.contains(last_message_id))
+        {
+            let last_message_id = db.fetch_last_message(channel_id).await?;
+            db.update_last_messsage_id(channel_id, last_message_id.as_deref())
+                .await?;
+
+            if last_message_id.is_some() {
+                EventV1::ChannelUpdate {
+                    id: channel_id.to_string(),
+                    data: revolt_models::v0::PartialChannel {
+                        last_message_id,
+                        ..Default::default()
+                    },
+                    clear: vec![],
+                }
+                .p(channel_id.to_string())
+                .await;
+            }
+        }
+
+        // Update the summary on the message this thread was spawned from
+        if let Channel::Thread {
+            parent,
+            message: Some(message),
+            ..
+        } = &channel
+        {
+            if let Ok(Message {
+                thread: Some(thread),
+                ..
+            }) = db.fetch_message(message).await
+            {
+                let last_message_id = if thread
+                    .last_message_id
+                    .as_ref()
+                    .is_some_and(|last_message_id| ids.contains(last_message_id))
+                {
+                    db.fetch_last_message(channel_id).await?
+                } else {
+                    thread.last_message_id.clone()
+                };
+
+                let thread = ThreadSummary {
+                    reply_count: thread.reply_count.saturating_sub(ids.len() as u64),
+                    last_message_id,
+                    ..thread
+                };
+
+                db.remove_thread_replies(
+                    message,
+                    ids.len() as u64,
+                    thread.last_message_id.as_deref(),
+                )
+                .await?;
+
+                EventV1::MessageUpdate {
+                    id: message.to_string(),
+                    channel: parent.to_string(),
+                    data: PartialMessage {
+                        thread: Some(thread),
+                        ..Default::default()
+                    }
+                    .into(),
+                    clear: vec![],
+                }
+                .p(parent.to_string())
+                .await;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Bulk delete messages
     pub async fn bulk_delete(db: &Database, channel: &str, ids: Vec<String>) -> Result<()> {
         let valid_ids = db
@@ -1066,6 +2069,8 @@ impl Message {
             .collect::<Vec<String>>();
 
         db.delete_messages(channel, &valid_ids).await?;
+        search::remove_messages(&valid_ids).await;
+
         EventV1::BulkMessageDelete {
             channel: channel.to_string(),
             ids: valid_ids,
@@ -1075,6 +2080,55 @@ impl Message {
         Ok(())
     }
 
+    /// Delete all messages which have passed their expiry
+    ///
+    /// Returns the number of messages deleted
+    pub async fn delete_expired(db: &Database) -> Result<usize> {
+        let messages = db.fetch_expired_messages().await?;
+        let count = messages.len();
+
+        let file_ids = messages
+            .iter()
+            .flat_map(|message| message.file_ids())
+            .collect::<Vec<_>>();
+
+        if !file_ids.is_empty() {
+            db.mark_attachments_as_deleted(&file_ids).await?;
+        }
+
+        let mut channels: HashMap<String, Vec<String>> = HashMap::new();
+        for message in messages {
+            channels
+                .entry(message.channel)
+                .or_default()
+                .push(message.id);
+        }
+
+        for (channel, mut ids) in channels {
+            db.delete_messages(&channel, &ids).await?;
+            search::remove_messages(&ids).await;
+            Message::update_channel_after_delete(db, &channel, &ids).await?;
+
+            if ids.len() == 1 {
+                EventV1::MessageDelete {
+                    id: ids.remove(0),
+                    channel: channel.clone(),
+                }
+                .p(channel)
+                .await;
+            } else {
+                EventV1::BulkMessageDelete {
+                    channel: channel.clone(),
+                    ids,
+                }
+                .p(channel)
+                .await;
+            }
+        }
+
+        Ok(count)
+    }
+
     /// Bulk delete messages by an author since a given time
     pub async fn bulk_delete_by_author_since(
         db: &Database,
@@ -1088,6 +2142,8 @@ impl Message {
 
         for (channel_id, message_ids) in deleted_groups {
             if !message_ids.is_empty() {
+                search::remove_messages(&message_ids).await;
+
                 EventV1::BulkMessageDelete {
                     channel: channel_id.clone(),
                     ids: message_ids,
@@ -1147,13 +2203,171 @@ impl Message {
         db.clear_reaction(&self.id, emoji).await
     }
 
+    /// Cast a vote on the poll attached to this message
+    pub async fn add_poll_vote(&self, db: &Database, user: &str, option: &str) -> Result<()> {
+        let poll = self.poll.as_ref().ok_or_else(|| create_error!(NotFound))?;
+
+        if poll.is_closed() {
+            return Err(create_error!(PollClosed));
+        }
+
+        // Check the option exists
+        if !poll.options.iter().any(|entry| entry.id == option) {
+            return Err(create_error!(NotFound));
+        }
+
+        // Nothing to do if this vote was already cast
+        if poll
+            .votes
+            .get(option)
+            .is_some_and(|users| users.contains(user))
+        {
+            return Ok(());
+        }
+
+        // Retract any existing vote if only one option can be picked
+        if !poll.multi_select {
+            for (existing, users) in &poll.votes {
+                if users.contains(user) {
+                    self.remove_poll_vote(db, user, existing).await?;
+                }
+            }
+        }
+
+        // Send vote event
+        EventV1::MessagePollVote {
+            id: self.id.to_string(),
+            channel_id: self.channel.to_string(),
+            user_id: (!poll.anonymous).then(|| user.to_string()),
+            option_id: option.to_string(),
+            voted: true,
+        }
+        .p(self.channel.to_string())
+        .await;
+
+        // Add vote
+        db.add_poll_vote(&self.id, option, user).await
+    }
+
+    /// Retract a vote on the poll attached to this message
+    pub async fn remove_poll_vote(&self, db: &Database, user: &str, option: &str) -> Result<()> {
+        let poll = self.poll.as_ref().ok_or_else(|| create_error!(NotFound))?;
+
+        if poll.is_closed() {
+            return Err(create_error!(PollClosed));
+        }
+
+        // Check if it actually exists
+        if !poll
+            .votes
+            .get(option)
+            .is_some_and(|users| users.contains(user))
+        {
+            return Err(create_error!(NotFound));
+        }
+
+        // Send vote event
+        EventV1::MessagePollVote {
+            id: self.id.to_string(),
+            channel_id: self.channel.to_string(),
+            user_id: (!poll.anonymous).then(|| user.to_string()),
+            option_id: option.to_string(),
+            voted: false,
+        }
+        .p(self.channel.to_string())
+        .await;
+
+        // Remove vote
+        db.remove_poll_vote(&self.id, option, user).await
+    }
+
+    /// Close the poll attached to this message and post the final results
+    pub async fn close_poll(&mut self, db: &Database, amqp: Option<&AMQP>) -> Result<()> {
+        let poll = self.poll.as_mut().ok_or_else(|| create_error!(NotFound))?;
+        if poll.closed {
+            return Ok(());
+        }
+
+        db.close_poll(&self.id).await?;
+        poll.closed = true;
+
+        EventV1::MessageUpdate {
+            id: self.id.clone(),
+            channel: self.channel.clone(),
+            data: PartialMessage {
+                poll: Some(poll.clone()),
+                ..Default::default()
+            }
+            .into(),
+            clear: vec![],
+        }
+        .p(self.channel.clone())
+        .await;
+
+        let channel = db.fetch_channel(&self.channel).await?;
+        let author = db.fetch_user(&self.author).await.ok();
+
+        SystemMessage::PollClosed {
+            id: self.id.clone(),
+            question: poll.question.clone(),
+            results: poll.results(),
+        }
+        .into_message(channel.id().to_string())
+        .send(
+            db,
+            amqp,
+            MessageAuthor::System {
+                username: author
+                    .as_ref()
+                    .map(|user| user.username.as_str())
+                    .unwrap_or("Poll"),
+                avatar: author
+                    .as_ref()
+                    .and_then(|user| user.avatar.as_ref())
+                    .map(|file| file.id.as_ref()),
+            },
+            None,
+            None,
+            &channel,
+            false,
+        )
+        .await
+    }
+
     pub fn remove_field(&mut self, field: &FieldsMessage) {
         match field {
             FieldsMessage::Pinned => self.pinned = None,
+            FieldsMessage::Thread => self.thread = None,
         }
     }
 }
 
+impl Poll {
+    /// Check whether this poll is no longer accepting votes
+    pub fn is_closed(&self) -> bool {
+        self.closed
+            || self
+                .expires_at
+                .is_some_and(|expires_at| expires_at <= Timestamp::now_utc())
+    }
+
+    /// Tally the votes cast for each option
+    pub fn results(&self) -> Vec<PollResult> {
+        self.options
+            .iter()
+            .map(|option| PollResult {
+                id: option.id.clone(),
+                text: option.text.clone(),
+                votes: self
+                    .votes
+                    .get(&option.id)
+                    .map(|users| users.len() as u64)
+                    .unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
 impl SystemMessage {
     pub fn into_message(self, channel: String) -> Message {
         Message {
@@ -1207,3 +2421,97 @@ impl Interactions {
         !self.restrict_reactions && self.reactions.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::MessageSort;
+
+    use crate::{Message, MessageFilter, MessageQuery, MessageTimePeriod};
+
+    fn ids(messages: Vec<Message>) -> Vec<String> {
+        messages.into_iter().map(|message| message.id).collect()
+    }
+
+    #[tokio::test]
+    async fn query() {
+        database_test!(|db| async move {
+            for (id, channel) in [("a", "one"), ("b", "one"), ("c", "two"), ("d", "one")] {
+                db.insert_message(&Message {
+                    id: id.to_string(),
+                    channel: channel.to_string(),
+                    pinned: (id == "b").then_some(true),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            }
+
+            let absolute =
+                |before: Option<&str>, after: Option<&str>, sort| MessageTimePeriod::Absolute {
+                    before: before.map(str::to_string),
+                    after: after.map(str::to_string),
+                    sort,
+                };
+
+            let filter = MessageFilter {
+                channel: Some("one".to_string()),
+                ..Default::default()
+            };
+
+            let latest = db
+                .fetch_messages(MessageQuery {
+                    limit: Some(2),
+                    filter: filter.clone(),
+                    time_period: absolute(None, None, None),
+                })
+                .await
+                .unwrap();
+            assert_eq!(ids(latest), vec!["d", "b"]);
+
+            let oldest = db
+                .fetch_messages(MessageQuery {
+                    limit: None,
+                    filter: filter.clone(),
+                    time_period: absolute(None, Some("a"), Some(MessageSort::Oldest)),
+                })
+                .await
+                .unwrap();
+            assert_eq!(ids(oldest), vec!["b", "d"]);
+
+            let bounded = db
+                .fetch_messages(MessageQuery {
+                    limit: None,
+                    filter: MessageFilter::default(),
+                    time_period: absolute(Some("d"), Some("a"), Some(MessageSort::Latest)),
+                })
+                .await
+                .unwrap();
+            assert_eq!(ids(bounded), vec!["c", "b"]);
+
+            let nearby = db
+                .fetch_messages(MessageQuery {
+                    limit: Some(2),
+                    filter: filter.clone(),
+                    time_period: MessageTimePeriod::Relative {
+                        nearby: "b".to_string(),
+                    },
+                })
+                .await
+                .unwrap();
+            assert_eq!(ids(nearby), vec!["b", "d", "a"]);
+
+            let pinned = db
+                .fetch_messages(MessageQuery {
+                    limit: None,
+                    filter: MessageFilter {
+                        pinned: Some(true),
+                        ..filter
+                    },
+                    time_period: absolute(None, None, None),
+                })
+                .await
+                .unwrap();
+            assert_eq!(ids(pinned), vec!["b"]);
+        });
+    }
+}
@@ -2,7 +2,10 @@ use std::collections::HashMap;
 use std::time::SystemTime;
 use revolt_result::Result;
 
-use crate::{AppendMessage, FieldsMessage, Message, MessageQuery, PartialMessage};
+use crate::{
+    util::ChunkedDatabaseGenerator, AppendMessage, FieldsMessage, Message, MessageQuery,
+    MessageRevision, PartialMessage,
+};
 
 #[cfg(feature = "mongodb")]
 mod mongodb;
@@ -22,12 +25,34 @@ pub trait AbstractMessages: Sync + Send {
     /// Fetch multiple messages by given IDs
     async fn fetch_messages_by_id(&self, ids: &[String]) -> Result<Vec<Message>>;
 
+    /// Fetch every message in a channel, oldest first
+    async fn fetch_channel_messages_chunked(
+        &self,
+        channel: &str,
+    ) -> Result<ChunkedDatabaseGenerator<Message>>;
+
     /// Update a given message with new information
     async fn update_message(&self, id: &str, message: &PartialMessage, remove: Vec<FieldsMessage>) -> Result<()>;
 
     /// Append information to a given message
     async fn append_message(&self, id: &str, append: &AppendMessage) -> Result<()>;
 
+    /// Set a flag on a given message unless it is already set
+    ///
+    /// Returns whether the flag was set by this call
+    async fn add_message_flag(&self, id: &str, flag: u32) -> Result<bool>;
+
+    /// Record a new message in the thread spawned from a given message
+    async fn add_thread_reply(&self, id: &str, reply_id: &str) -> Result<()>;
+
+    /// Remove deleted messages from the summary of the thread spawned from a given message
+    async fn remove_thread_replies(
+        &self,
+        id: &str,
+        count: u64,
+        last_message_id: Option<&str>,
+    ) -> Result<()>;
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()>;
 
@@ -37,6 +62,29 @@ pub trait AbstractMessages: Sync + Send {
     /// Remove reaction from a message
     async fn clear_reaction(&self, id: &str, emoji: &str) -> Result<()>;
 
+    /// Add a vote to the poll on a message
+    async fn add_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()>;
+
+    /// Remove a vote from the poll on a message
+    async fn remove_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()>;
+
+    /// Mark the poll on a message as closed
+    async fn close_poll(&self, id: &str) -> Result<()>;
+
+    /// Fetch messages with open polls which have expired
+    async fn fetch_expired_polls(&self) -> Result<Vec<Message>>;
+
+    /// Fetch messages which have passed their expiry
+    async fn fetch_expired_messages(&self) -> Result<Vec<Message>>;
+
+    /// Record a prior revision of a message, keeping at most `limit` revisions
+    async fn push_message_revision(
+        &self,
+        id: &str,
+        revision: &MessageRevision,
+        limit: usize,
+    ) -> Result<()>;
+
     /// Delete a message from the database by its id
     async fn delete_message(&self, id: &str) -> Result<()>;
 
@@ -1,6 +1,7 @@
 use bson::{to_bson, Document};
 use futures::try_join;
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
 use mongodb::options::FindOptions;
 use revolt_models::v0::MessageSort;
 use revolt_result::Result;
@@ -9,8 +10,8 @@ use std::time::SystemTime;
 use ulid::Ulid;
 
 use crate::{
-    AppendMessage, DocumentId, FieldsMessage, IntoDocumentPath, Message, MessageQuery,
-    MessageTimePeriod, MongoDb, PartialMessage,
+    util::ChunkedDatabaseGenerator, AppendMessage, DocumentId, FieldsMessage, IntoDocumentPath,
+    Message, MessageQuery, MessageRevision, MessageTimePeriod, MongoDb, PartialMessage,
 };
 
 use super::AbstractMessages;
@@ -34,12 +35,78 @@ impl AbstractMessages for MongoDb {
         let mut filter = doc! {};
 
         // 1. Apply message filters
-        if let Some(channel) = query.filter.channel {
-            filter.insert("channel", channel);
+        match (query.filter.channel, query.filter.channels) {
+            (Some(channel), Some(channels)) if !channels.contains(&channel) => {
+                return Ok(vec![]);
+            }
+            (Some(channel), _) => {
+                filter.insert("channel", channel);
+            }
+            (None, Some(channels)) => {
+                filter.insert("channel", doc! { "$in": channels });
+            }
+            (None, None) => {}
+        }
+
+        match (query.filter.author, query.filter.authors) {
+            (Some(author), Some(authors)) if !authors.contains(&author) => {
+                return Ok(vec![]);
+            }
+            (Some(author), _) => {
+                filter.insert("author", author);
+            }
+            (None, Some(authors)) => {
+                filter.insert("author", doc! { "$in": authors });
+            }
+            (None, None) => {}
+        }
+
+        if let Some(mentions) = query.filter.mentions {
+            filter.insert("mentions", doc! { "$all": mentions });
+        }
+
+        if let Some(has_attachments) = query.filter.has_attachments {
+            filter.insert("attachments.0", doc! { "$exists": has_attachments });
+        }
+
+        if let Some(has_embeds) = query.filter.has_embeds {
+            filter.insert("embeds.0", doc! { "$exists": has_embeds });
+        }
+
+        if let Some(has_links) = query.filter.has_links {
+            let regex = doc! { "$regex": "https?://" };
+            filter.insert(
+                "content",
+                if has_links {
+                    regex
+                } else {
+                    doc! { "$not": regex }
+                },
+            );
+        }
+
+        let mut id_filters = vec![];
+        if let Some(ids) = query.filter.ids {
+            id_filters.push(doc! {
+                "_id": { "$in": ids }
+            });
         }
 
-        if let Some(author) = query.filter.author {
-            filter.insert("author", author);
+        // Message IDs are sortable by time, so dates become bounds on the ID
+        if let Some(sent_before) = query.filter.sent_before {
+            id_filters.push(doc! {
+                "_id": { "$lt": Ulid::from_parts(sent_before, 0).to_string() }
+            });
+        }
+
+        if let Some(sent_after) = query.filter.sent_after {
+            id_filters.push(doc! {
+                "_id": { "$gte": Ulid::from_parts(sent_after, 0).to_string() }
+            });
+        }
+
+        if !id_filters.is_empty() {
+            filter.insert("$and", id_filters);
         }
 
         let is_search_query = if let Some(query) = query.filter.query {
@@ -185,6 +252,32 @@ impl AbstractMessages for MongoDb {
         .map_err(|_| create_database_error!("find", COL))
     }
 
+    /// Fetch every message in a channel, oldest first
+    async fn fetch_channel_messages_chunked(
+        &self,
+        channel: &str,
+    ) -> Result<ChunkedDatabaseGenerator<Message>> {
+        let mut session = self
+            .start_session()
+            .await
+            .map_err(|_| create_database_error!("start_session", COL))?;
+
+        let cursor = self
+            .col(COL)
+            .find(doc! {
+                "channel": channel
+            })
+            .sort(doc! {
+                "_id": 1_i32
+            })
+            .session(&mut session)
+            .batch_size(100)
+            .await
+            .map_err(|_| create_database_error!("find", COL))?;
+
+        Ok(ChunkedDatabaseGenerator::new_mongo(session, cursor))
+    }
+
     /// Update a given message with new information
     async fn update_message(
         &self,
@@ -238,6 +331,105 @@ impl AbstractMessages for MongoDb {
             .map_err(|_| create_database_error!("update_one", COL))
     }
 
+    /// Set a flag on a given message unless it is already set
+    ///
+    /// Returns whether the flag was set by this call
+    async fn add_message_flag(&self, id: &str, flag: u32) -> Result<bool> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id,
+                    "$or": [
+                        { "flags": { "$exists": false } },
+                        { "flags": { "$bitsAllClear": flag as i64 } }
+                    ]
+                },
+                vec![doc! {
+                    "$set": {
+                        "flags": {
+                            "$add": [
+                                { "$ifNull": ["$flags", 0_i64] },
+                                flag as i64
+                            ]
+                        }
+                    }
+                }],
+            )
+            .await
+            .map(|result| result.modified_count == 1)
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Record a new message in the thread spawned from a given message
+    async fn add_thread_reply(&self, id: &str, reply_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id,
+                    "thread": {
+                        "$exists": true
+                    }
+                },
+                doc! {
+                    "$inc": {
+                        "thread.reply_count": 1_i64
+                    },
+                    "$set": {
+                        "thread.last_message_id": reply_id
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Remove deleted messages from the summary of the thread spawned from a given message
+    async fn remove_thread_replies(
+        &self,
+        id: &str,
+        count: u64,
+        last_message_id: Option<&str>,
+    ) -> Result<()> {
+        let mut pipeline = vec![doc! {
+            "$set": {
+                "thread.reply_count": {
+                    "$max": [
+                        0_i64,
+                        {
+                            "$subtract": ["$thread.reply_count", count as i64]
+                        }
+                    ]
+                }
+            }
+        }];
+
+        pipeline.push(match last_message_id {
+            Some(last_message_id) => doc! {
+                "$set": {
+                    "thread.last_message_id": last_message_id
+                }
+            },
+            None => doc! {
+                "$unset": "thread.last_message_id"
+            },
+        });
+
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id,
+                    "thread": {
+                        "$exists": true
+                    }
+                },
+                pipeline,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         self.col::<Document>(COL)
@@ -292,6 +484,117 @@ impl AbstractMessages for MongoDb {
             .map_err(|_| create_database_error!("update_one", COL))
     }
 
+    /// Add a vote to the poll on a message
+    async fn add_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$addToSet": {
+                        format!("poll.votes.{option}"): user
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Remove a vote from the poll on a message
+    async fn remove_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$pull": {
+                        format!("poll.votes.{option}"): user
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Mark the poll on a message as closed
+    async fn close_poll(&self, id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$set": {
+                        "poll.closed": true
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Fetch messages with open polls which have expired
+    async fn fetch_expired_polls(&self) -> Result<Vec<Message>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "poll.closed": {
+                    "$ne": true
+                },
+                "poll.expires_at": {
+                    "$lte": to_bson(&Timestamp::now_utc()).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Fetch messages which have passed their expiry
+    async fn fetch_expired_messages(&self) -> Result<Vec<Message>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "expires_at": {
+                    "$lte": to_bson(&Timestamp::now_utc()).unwrap()
+                }
+            }
+        )
+    }
+
+    /// Record a prior revision of a message, keeping at most `limit` revisions
+    async fn push_message_revision(
+        &self,
+        id: &str,
+        revision: &MessageRevision,
+        limit: usize,
+    ) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$push": {
+                        "revisions": {
+                            "$each": [to_bson(revision).unwrap()],
+                            "$slice": -(limit as i64)
+                        }
+                    }
+                },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Delete a message from the database by its id
     async fn delete_message(&self, id: &str) -> Result<()> {
         query!(self, delete_one_by_id, COL, id).map(|_| ())
@@ -428,6 +731,7 @@ impl IntoDocumentPath for FieldsMessage {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsMessage::Pinned => "pinned",
+            FieldsMessage::Thread => "thread",
         })
     }
 }
@@ -1,9 +1,11 @@
 use crate::{
-    AppendMessage, FieldsMessage, Message, MessageQuery,
-    PartialMessage, ReferenceDb,
+    util::ChunkedDatabaseGenerator, AppendMessage, FieldsMessage, Message, MessageQuery,
+    MessageRevision, MessageTimePeriod, PartialMessage, ReferenceDb,
 };
 use futures::future::try_join_all;
 use indexmap::IndexSet;
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::MessageSort;
 use revolt_result::Result;
 use std::collections::HashMap;
 use std::time::SystemTime;
@@ -11,6 +13,110 @@ use ulid::Ulid;
 
 use super::AbstractMessages;
 
+/// Approximation of a MongoDB `$text` search
+struct TextSearch {
+    /// Terms of which at least one must be present
+    terms: Vec<String>,
+    /// Phrases which must all be present
+    phrases: Vec<String>,
+    /// Terms and phrases which must not be present
+    excluded: Vec<String>,
+}
+
+impl TextSearch {
+    fn parse(query: &str) -> TextSearch {
+        let mut search = TextSearch {
+            terms: vec![],
+            phrases: vec![],
+            excluded: vec![],
+        };
+
+        let query = query.to_lowercase();
+        let mut rest = query.as_str();
+        while let Some(start) = rest.find(|c: char| !c.is_whitespace()) {
+            rest = &rest[start..];
+
+            let negated = rest.starts_with('-');
+            if negated {
+                rest = &rest[1..];
+            }
+
+            let token = if let Some(phrase) = rest.strip_prefix('"') {
+                let end = phrase.find('"').unwrap_or(phrase.len());
+                rest = phrase.get(end + 1..).unwrap_or_default();
+
+                let phrase = phrase[..end].trim().to_string();
+                if !negated && !phrase.is_empty() {
+                    search.phrases.push(phrase.clone());
+                }
+
+                phrase
+            } else {
+                let end = rest.find(char::is_whitespace).unwrap_or(rest.len());
+                let word = &rest[..end];
+                rest = &rest[end..];
+
+                for term in words(word) {
+                    if !negated {
+                        search.terms.push(term.to_string());
+                    }
+                }
+
+                word.to_string()
+            };
+
+            if negated && !token.is_empty() {
+                search.excluded.push(token);
+            }
+        }
+
+        for phrase in &search.phrases {
+            search.terms.extend(words(phrase).map(str::to_string));
+        }
+
+        search
+    }
+}
+
+/// Split text into words, as the text index does
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+}
+
+/// Score a message against a text search, or None if it doesn't match
+///
+/// Scores are term frequencies weighted by message length, approximating MongoDB's `textScore`
+fn text_score(search: &TextSearch, message: &Message) -> Option<f32> {
+    let content = message.content.as_ref()?.to_lowercase();
+    let content_words: Vec<&str> = words(&content).collect();
+
+    let contains = |needle: &str| {
+        let needle: Vec<&str> = words(needle).collect();
+        !needle.is_empty()
+            && content_words
+                .windows(needle.len())
+                .any(|window| window == needle.as_slice())
+    };
+
+    if !search.phrases.iter().all(|phrase| contains(phrase))
+        || search.excluded.iter().any(|excluded| contains(excluded))
+    {
+        return None;
+    }
+
+    let matches = content_words
+        .iter()
+        .filter(|word| search.terms.iter().any(|term| term == *word))
+        .count();
+
+    if matches == 0 {
+        return None;
+    }
+
+    Some(matches as f32 / content_words.len() as f32 + 0.5 * matches as f32)
+}
+
 #[async_trait]
 impl AbstractMessages for ReferenceDb {
     /// Insert a new message into the database
@@ -35,8 +141,11 @@ impl AbstractMessages for ReferenceDb {
 
     /// Fetch multiple messages by given query
     async fn fetch_messages(&self, query: MessageQuery) -> Result<Vec<Message>> {
+        let search = query.filter.query.as_deref().map(TextSearch::parse);
+
+        // 1. Apply message filters
         let messages = self.messages.lock().await;
-        let matched_messages = messages
+        let matched_messages: Vec<Message> = messages
             .values()
             .filter(|message| {
                 if let Some(channel) = &query.filter.channel {
@@ -51,18 +160,75 @@ impl AbstractMessages for ReferenceDb {
                     }
                 }
 
-                if let Some(query) = &query.filter.query {
-                    if let Some(content) = &message.content {
-                        if !content.to_lowercase().contains(query) {
-                            return false;
-                        }
-                    } else {
+                if let Some(channels) = &query.filter.channels {
+                    if !channels.contains(&message.channel) {
+                        return false;
+                    }
+                }
+
+                if let Some(authors) = &query.filter.authors {
+                    if !authors.contains(&message.author) {
+                        return false;
+                    }
+                }
+
+                if let Some(mentions) = &query.filter.mentions {
+                    let mentioned = message.mentions.as_deref().unwrap_or_default();
+                    if !mentions.iter().all(|user| mentioned.contains(user)) {
+                        return false;
+                    }
+                }
+
+                if let Some(has_attachments) = query.filter.has_attachments {
+                    if message.attachments.as_ref().is_some_and(|v| !v.is_empty())
+                        != has_attachments
+                    {
+                        return false;
+                    }
+                }
+
+                if let Some(has_embeds) = query.filter.has_embeds {
+                    if message.embeds.as_ref().is_some_and(|v| !v.is_empty()) != has_embeds {
+                        return false;
+                    }
+                }
+
+                if let Some(has_links) = query.filter.has_links {
+                    let linked = message.content.as_ref().is_some_and(|content| {
+                        content.contains("http://") || content.contains("https://")
+                    });
+
+                    if linked != has_links {
+                        return false;
+                    }
+                }
+
+                if let Some(ids) = &query.filter.ids {
+                    if !ids.contains(&message.id) {
+                        return false;
+                    }
+                }
+
+                if let Some(sent_before) = query.filter.sent_before {
+                    if message.id >= Ulid::from_parts(sent_before, 0).to_string() {
+                        return false;
+                    }
+                }
+
+                if let Some(sent_after) = query.filter.sent_after {
+                    if message.id < Ulid::from_parts(sent_after, 0).to_string() {
+                        return false;
+                    }
+                }
+
+                if let Some(text) = &search {
+                    if text_score(text, message).is_none() {
                         return false;
                     }
                 }
 
                 if let Some(pinned) = query.filter.pinned {
-                    if message.pinned.unwrap_or_default() == pinned {
+                    if message.pinned.unwrap_or_default() != pinned {
                         return false;
                     }
                 }
@@ -72,120 +238,65 @@ impl AbstractMessages for ReferenceDb {
             .cloned()
             .collect();
 
-        // FIXME: sorting, etc (will be required for tests)
-
-        Ok(matched_messages)
-
-        /*
         // 2. Find query limit
-        let limit = query.limit.unwrap_or(50);
+        let limit = query.limit.unwrap_or(50) as usize;
 
         // 3. Apply message time period
         match query.time_period {
             MessageTimePeriod::Relative { nearby } => {
-                // 3.1. Prepare filters
-                let mut older_message_filter = filter.clone();
-                let mut newer_message_filter = filter;
-
-                older_message_filter.insert(
-                    "_id",
-                    doc! {
-                        "$lt": &nearby
-                    },
-                );
-
-                newer_message_filter.insert(
-                    "_id",
-                    doc! {
-                        "$gte": &nearby
-                    },
-                );
-
-                // 3.2. Execute in both directions
-                let (a, b) = try_join!(
-                    self.find_with_options::<_, Message>(
-                        COL,
-                        newer_message_filter,
-                        FindOptions::builder()
-                            .limit(limit / 2 + 1)
-                            .sort(doc! {
-                                "_id": 1_i32
-                            })
-                            .build(),
-                    ),
-                    self.find_with_options::<_, Message>(
-                        COL,
-                        older_message_filter,
-                        FindOptions::builder()
-                            .limit(limit / 2)
-                            .sort(doc! {
-                                "_id": -1_i32
-                            })
-                            .build(),
-                    )
-                )
-                .map_err(|_| create_database_error!("find", COL))?;
-
-                Ok([a, b].concat())
+                let (mut newer, mut older): (Vec<Message>, Vec<Message>) = matched_messages
+                    .into_iter()
+                    .partition(|message| message.id >= nearby);
+
+                newer.sort_by(|a, b| a.id.cmp(&b.id));
+                newer.truncate(limit / 2 + 1);
+
+                older.sort_by(|a, b| b.id.cmp(&a.id));
+                older.truncate(limit / 2 + 1);
+
+                Ok([newer, older].concat())
             }
             MessageTimePeriod::Absolute {
                 before,
                 after,
                 sort,
             } => {
-                // 3.1. Apply message ID filter
-                if let Some(doc) = match (before, after) {
-                    (Some(before), Some(after)) => Some(doc! {
-                        "$lt": before,
-                        "$gt": after
-                    }),
-                    (Some(before), _) => Some(doc! {
-                        "$lt": before
-                    }),
-                    (_, Some(after)) => Some(doc! {
-                        "$gt": after
-                    }),
-                    _ => None,
-                } {
-                    filter.insert("_id", doc);
+                let mut messages: Vec<Message> = matched_messages
+                    .into_iter()
+                    .filter(|message| before.as_ref().is_none_or(|before| &message.id < before))
+                    .filter(|message| after.as_ref().is_none_or(|after| &message.id > after))
+                    .collect();
+
+                match sort.unwrap_or(MessageSort::Latest) {
+                    // Sort by relevance, fallback to latest
+                    MessageSort::Relevance => {
+                        if let Some(text) = &search {
+                            let mut scored: Vec<(f32, Message)> = messages
+                                .into_iter()
+                                .map(|message| {
+                                    (text_score(text, &message).unwrap_or_default(), message)
+                                })
+                                .collect();
+
+                            scored.sort_by(|(a_score, a), (b_score, b)| {
+                                b_score.total_cmp(a_score).then_with(|| b.id.cmp(&a.id))
+                            });
+
+                            messages = scored.into_iter().map(|(_, message)| message).collect();
+                        } else {
+                            messages.sort_by(|a, b| b.id.cmp(&a.id));
+                        }
+                    }
+                    // Sort by latest first
+                    MessageSort::Latest => messages.sort_by(|a, b| b.id.cmp(&a.id)),
+                    // Sort by oldest first
+                    MessageSort::Oldest => messages.sort_by(|a, b| a.id.cmp(&b.id)),
                 }
 
-                // 3.2. Execute with given message sort
-                self.find_with_options(
-                    COL,
-                    filter,
-                    FindOptions::builder()
-                        .limit(limit)
-                        .sort(match sort.unwrap_or(MessageSort::Latest) {
-                            // Sort by relevance, fallback to latest
-                            MessageSort::Relevance => {
-                                if is_search_query {
-                                    doc! {
-                                        "score": {
-                                            "$meta": "textScore"
-                                        }
-                                    }
-                                } else {
-                                    doc! {
-                                        "_id": -1_i32
-                                    }
-                                }
-                            }
-                            // Sort by latest first
-                            MessageSort::Latest => doc! {
-                                "_id": -1_i32
-                            },
-                            // Sort by oldest first
-                            MessageSort::Oldest => doc! {
-                                "_id": 1_i32
-                            },
-                        })
-                        .build(),
-                )
-                .await
-                .map_err(|_| create_database_error!("find", COL))
+                messages.truncate(limit);
+                Ok(messages)
             }
-        }*/
+        }
     }
 
     /// Fetch multiple messages by given IDs
@@ -193,6 +304,22 @@ impl AbstractMessages for ReferenceDb {
         try_join_all(ids.iter().map(|id| self.fetch_message(id))).await
     }
 
+    /// Fetch every message in a channel, oldest first
+    async fn fetch_channel_messages_chunked(
+        &self,
+        channel: &str,
+    ) -> Result<ChunkedDatabaseGenerator<Message>> {
+        let messages = self.messages.lock().await;
+        let mut matched_messages: Vec<Message> = messages
+            .values()
+            .filter(|message| message.channel == channel)
+            .cloned()
+            .collect();
+
+        matched_messages.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(ChunkedDatabaseGenerator::new_reference(matched_messages))
+    }
+
     /// Update a given message with new information
     async fn update_message(
         &self,
@@ -234,6 +361,59 @@ impl AbstractMessages for ReferenceDb {
         }
     }
 
+    /// Set a flag on a given message unless it is already set
+    ///
+    /// Returns whether the flag was set by this call
+    async fn add_message_flag(&self, id: &str, flag: u32) -> Result<bool> {
+        let mut messages = self.messages.lock().await;
+        if let Some(message) = messages.get_mut(id) {
+            let flags = message.flags.unwrap_or_default();
+            if flags & flag == flag {
+                Ok(false)
+            } else {
+                message.flags = Some(flags | flag);
+                Ok(true)
+            }
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Record a new message in the thread spawned from a given message
+    async fn add_thread_reply(&self, id: &str, reply_id: &str) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(thread) = messages
+            .get_mut(id)
+            .and_then(|message| message.thread.as_mut())
+        {
+            thread.reply_count += 1;
+            thread.last_message_id = Some(reply_id.to_string());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove deleted messages from the summary of the thread spawned from a given message
+    async fn remove_thread_replies(
+        &self,
+        id: &str,
+        count: u64,
+        last_message_id: Option<&str>,
+    ) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(thread) = messages
+            .get_mut(id)
+            .and_then(|message| message.thread.as_mut())
+        {
+            thread.reply_count = thread.reply_count.saturating_sub(count);
+            thread.last_message_id = last_message_id.map(str::to_string);
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         let mut messages = self.messages.lock().await;
@@ -277,6 +457,108 @@ impl AbstractMessages for ReferenceDb {
         }
     }
 
+    /// Add a vote to the poll on a message
+    async fn add_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(poll) = messages
+            .get_mut(id)
+            .and_then(|message| message.poll.as_mut())
+        {
+            poll.votes
+                .entry(option.to_string())
+                .or_default()
+                .insert(user.to_string());
+
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove a vote from the poll on a message
+    async fn remove_poll_vote(&self, id: &str, option: &str, user: &str) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(poll) = messages
+            .get_mut(id)
+            .and_then(|message| message.poll.as_mut())
+        {
+            if let Some(users) = poll.votes.get_mut(option) {
+                users.swap_remove(user);
+            }
+
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Mark the poll on a message as closed
+    async fn close_poll(&self, id: &str) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(poll) = messages
+            .get_mut(id)
+            .and_then(|message| message.poll.as_mut())
+        {
+            poll.closed = true;
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Fetch messages with open polls which have expired
+    async fn fetch_expired_polls(&self) -> Result<Vec<Message>> {
+        let messages = self.messages.lock().await;
+        let now = Timestamp::now_utc();
+
+        Ok(messages
+            .values()
+            .filter(|message| {
+                message.poll.as_ref().is_some_and(|poll| {
+                    !poll.closed && poll.expires_at.is_some_and(|expires_at| expires_at <= now)
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch messages which have passed their expiry
+    async fn fetch_expired_messages(&self) -> Result<Vec<Message>> {
+        let messages = self.messages.lock().await;
+        let now = Timestamp::now_utc();
+
+        Ok(messages
+            .values()
+            .filter(|message| {
+                message
+                    .expires_at
+                    .is_some_and(|expires_at| expires_at <= now)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Record a prior revision of a message, keeping at most `limit` revisions
+    async fn push_message_revision(
+        &self,
+        id: &str,
+        revision: &MessageRevision,
+        limit: usize,
+    ) -> Result<()> {
+        let mut messages = self.messages.lock().await;
+        if let Some(message) = messages.get_mut(id) {
+            message.revisions.push(revision.clone());
+            if message.revisions.len() > limit {
+                let excess = message.revisions.len() - limit;
+                message.revisions.drain(..excess);
+            }
+
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
     /// Delete a message from the database by its id
     async fn delete_message(&self, id: &str) -> Result<()> {
         let mut messages = self.messages.lock().await;
@@ -292,7 +574,7 @@ impl AbstractMessages for ReferenceDb {
         self.messages
             .lock()
             .await
-            .retain(|id, message| message.channel != channel && !ids.contains(id));
+            .retain(|id, message| message.channel != channel || !ids.contains(id));
 
         Ok(())
     }
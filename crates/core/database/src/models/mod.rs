@@ -1,11 +1,14 @@
 mod admin_migrations;
 mod audit_logs;
+mod bot_commands;
 mod bots;
+mod channel_exports;
 mod channel_invites;
 mod channel_unreads;
 mod channel_webhooks;
 mod channels;
 mod emojis;
+mod event_subscriptions;
 mod file_hashes;
 mod files;
 mod messages;
@@ -13,8 +16,10 @@ mod policy_changes;
 mod ratelimit_events;
 mod safety_reports;
 mod safety_snapshots;
+mod scheduled_messages;
 mod server_bans;
 mod server_members;
+mod server_templates;
 mod servers;
 mod user_settings;
 mod users;
@@ -25,12 +30,15 @@ mod mfa_tickets;
 
 pub use admin_migrations::*;
 pub use audit_logs::*;
+pub use bot_commands::*;
 pub use bots::*;
+pub use channel_exports::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
 pub use channel_webhooks::*;
 pub use channels::*;
 pub use emojis::*;
+pub use event_subscriptions::*;
 pub use file_hashes::*;
 pub use files::*;
 pub use messages::*;
@@ -38,8 +46,10 @@ pub use policy_changes::*;
 pub use ratelimit_events::*;
 pub use safety_reports::*;
 pub use safety_snapshots::*;
+pub use scheduled_messages::*;
 pub use server_bans::*;
 pub use server_members::*;
+pub use server_templates::*;
 pub use servers::*;
 pub use user_settings::*;
 pub use users::*;
@@ -58,12 +68,15 @@ pub trait AbstractDatabase:
     + Send
     + admin_migrations::AbstractMigrations
     + audit_logs::AbstractAuditLogs
+    + bot_commands::AbstractBotCommands
     + bots::AbstractBots
     + channels::AbstractChannels
+    + channel_exports::AbstractChannelExports
     + channel_invites::AbstractChannelInvites
     + channel_unreads::AbstractChannelUnreads
     + channel_webhooks::AbstractWebhooks
     + emojis::AbstractEmojis
+    + event_subscriptions::AbstractEventSubscriptions
     + file_hashes::AbstractAttachmentHashes
     + files::AbstractAttachments
     + messages::AbstractMessages
@@ -71,8 +84,10 @@ pub trait AbstractDatabase:
     + ratelimit_events::AbstractRatelimitEvents
     + safety_reports::AbstractReport
     + safety_snapshots::AbstractSnapshot
+    + scheduled_messages::AbstractScheduledMessages
     + server_bans::AbstractServerBans
     + server_members::AbstractServerMembers
+    + server_templates::AbstractServerTemplates
     + servers::AbstractServers
     + user_settings::AbstractUserSettings
     + users::AbstractUsers
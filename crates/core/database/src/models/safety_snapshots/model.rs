@@ -28,7 +28,7 @@ auto_derived!(
             #[serde(rename = "_leading_context", default)]
             leading_context: Vec<Message>,
 
-            /// Message
+            /// Message, including any prior revisions from before it was edited
             #[serde(flatten)]
             message: Message,
         },
@@ -39,6 +39,9 @@ auto_derived!(
 
 impl SnapshotContent {
     /// Generate snapshot from a given message
+    ///
+    /// The message is captured along with its edit history so that
+    /// content which was edited away after the fact is preserved.
     pub async fn generate_from_message(
         db: &Database,
         message: Message,
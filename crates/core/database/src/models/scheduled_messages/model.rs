@@ -0,0 +1,189 @@
+use iso8601_timestamp::{Duration, Timestamp};
+use revolt_models::v0::{self, DataMessageSend};
+use revolt_result::{ErrorType, Result};
+use ulid::Ulid;
+
+use crate::{util::idempotency::IdempotencyKey, Database, Message, AMQP};
+
+auto_derived_partial!(
+    /// Message scheduled to be sent at a later time
+    pub struct ScheduledMessage {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel this message will be sent in
+        pub channel: String,
+        /// Id of the user who scheduled this message
+        pub author: String,
+        /// Message to send
+        pub data: DataMessageSend,
+        /// Time at which this message should be sent
+        pub send_at: Timestamp,
+        /// Reason this message could not be sent
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub failure: Option<String>,
+        /// Number of times sending this message has failed temporarily
+        #[serde(default)]
+        pub attempts: u32,
+        /// Time before which sending this message won't be retried
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub retry_at: Option<Timestamp>,
+    },
+    "PartialScheduledMessage"
+);
+
+auto_derived!(
+    /// Optional fields on scheduled message object
+    pub enum FieldsScheduledMessage {
+        Failure,
+        RetryAt,
+    }
+);
+
+/// Number of times to try sending a message before giving up on temporary errors
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Delay before the first retry, doubled with each further attempt
+const RETRY_DELAY_SECS: i64 = 30;
+
+/// Whether an error may go away by itself if sending is tried again later
+fn is_transient(error: &ErrorType) -> bool {
+    matches!(
+        error,
+        ErrorType::InSlowmode { .. }
+            | ErrorType::DatabaseError { .. }
+            | ErrorType::InternalError
+            | ErrorType::ProxyError
+            | ErrorType::OperationFailed
+    )
+}
+
+/// Stable identifier of an error, as it is named in API error responses
+fn error_code(error: &ErrorType) -> String {
+    serde_json::to_value(error)
+        .ok()
+        .and_then(|value| value.get("type")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| "InternalError".to_string())
+}
+
+impl ScheduledMessage {
+    /// Schedule a new message
+    pub async fn create(
+        db: &Database,
+        channel: String,
+        author: String,
+        data: DataMessageSend,
+        send_at: Timestamp,
+    ) -> Result<ScheduledMessage> {
+        if send_at <= Timestamp::now_utc() {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        let scheduled_message = ScheduledMessage {
+            id: Ulid::new().to_string(),
+            channel,
+            author,
+            data,
+            send_at,
+            failure: None,
+            attempts: 0,
+            retry_at: None,
+        };
+
+        db.insert_scheduled_message(&scheduled_message).await?;
+        Ok(scheduled_message)
+    }
+
+    /// Update scheduled message data
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialScheduledMessage,
+        remove: Vec<FieldsScheduledMessage>,
+    ) -> Result<()> {
+        if partial
+            .send_at
+            .is_some_and(|send_at| send_at <= Timestamp::now_utc())
+        {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        for field in &remove {
+            self.remove_field(field);
+        }
+
+        self.apply_options(partial.clone());
+        db.update_scheduled_message(&self.id, &partial, remove)
+            .await
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsScheduledMessage) {
+        match field {
+            FieldsScheduledMessage::Failure => self.failure = None,
+            FieldsScheduledMessage::RetryAt => self.retry_at = None,
+        }
+    }
+
+    /// Cancel this scheduled message
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_scheduled_message(&self.id).await
+    }
+
+    /// Send this message on behalf of its author
+    ///
+    /// Permissions are checked again as the author may have lost access since scheduling.
+    /// Temporary errors are retried with backoff, otherwise the message is kept and marked
+    /// as failed so the author can see why and reschedule it, failed messages are not
+    /// retried until they are edited.
+    pub async fn send(mut self, db: &Database, amqp: Option<&AMQP>) -> Result<v0::Message> {
+        let result = async {
+            let user = db.fetch_user(&self.author).await?;
+            let channel = db.fetch_channel(&self.channel).await?;
+
+            Message::create_from_user(
+                db,
+                amqp,
+                channel,
+                &user,
+                self.data.clone(),
+                IdempotencyKey::unchecked_from_string(self.id.clone()),
+            )
+            .await
+        }
+        .await;
+
+        match result {
+            Ok(message) => {
+                self.delete(db).await?;
+                Ok(message)
+            }
+            Err(error) => {
+                let partial = if is_transient(&error.error_type) && self.attempts + 1 < MAX_ATTEMPTS
+                {
+                    let mut delay = RETRY_DELAY_SECS << self.attempts;
+                    if let ErrorType::InSlowmode { retry_after } = &error.error_type {
+                        delay = delay.max(*retry_after as i64);
+                    }
+
+                    PartialScheduledMessage {
+                        attempts: Some(self.attempts + 1),
+                        retry_at: Timestamp::now_utc().checked_add(Duration::seconds(delay)),
+                        ..Default::default()
+                    }
+                } else {
+                    PartialScheduledMessage {
+                        failure: Some(error_code(&error.error_type)),
+                        ..Default::default()
+                    }
+                };
+
+                self.apply_options(partial.clone());
+                db.update_scheduled_message(&self.id, &partial, vec![])
+                    .await?;
+
+                Err(error)
+            }
+        }
+    }
+}
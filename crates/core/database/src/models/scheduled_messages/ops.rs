@@ -0,0 +1,37 @@
+use revolt_result::Result;
+
+use crate::{FieldsScheduledMessage, PartialScheduledMessage, ScheduledMessage};
+
+#[cfg(feature = "mongodb")]
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractScheduledMessages: Sync + Send {
+    /// Insert a new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()>;
+
+    /// Fetch a scheduled message by its id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage>;
+
+    /// Fetch scheduled messages in a channel, optionally only those by a given author
+    async fn fetch_scheduled_messages(
+        &self,
+        channel: &str,
+        author: Option<&str>,
+    ) -> Result<Vec<ScheduledMessage>>;
+
+    /// Fetch scheduled messages which are due to be sent and have not failed
+    async fn fetch_due_scheduled_messages(&self) -> Result<Vec<ScheduledMessage>>;
+
+    /// Update a given scheduled message with new information
+    async fn update_scheduled_message(
+        &self,
+        id: &str,
+        partial: &PartialScheduledMessage,
+        remove: Vec<FieldsScheduledMessage>,
+    ) -> Result<()>;
+
+    /// Delete a scheduled message by its id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()>;
+}
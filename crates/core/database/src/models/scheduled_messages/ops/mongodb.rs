@@ -0,0 +1,94 @@
+use bson::to_bson;
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{FieldsScheduledMessage, PartialScheduledMessage, ScheduledMessage};
+use crate::{IntoDocumentPath, MongoDb};
+
+use super::AbstractScheduledMessages;
+
+static COL: &str = "scheduled_messages";
+
+#[async_trait]
+impl AbstractScheduledMessages for MongoDb {
+    /// Insert a new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()> {
+        query!(self, insert_one, COL, &message).map(|_| ())
+    }
+
+    /// Fetch a scheduled message by its id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled messages in a channel, optionally only those by a given author
+    async fn fetch_scheduled_messages(
+        &self,
+        channel: &str,
+        author: Option<&str>,
+    ) -> Result<Vec<ScheduledMessage>> {
+        let mut filter = doc! {
+            "channel": channel
+        };
+
+        if let Some(author) = author {
+            filter.insert("author", author);
+        }
+
+        query!(self, find, COL, filter)
+    }
+
+    /// Fetch scheduled messages which are due to be sent and have not failed
+    async fn fetch_due_scheduled_messages(&self) -> Result<Vec<ScheduledMessage>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "send_at": {
+                    "$lte": to_bson(&Timestamp::now_utc()).unwrap()
+                },
+                "failure": {
+                    "$exists": false
+                },
+                "$or": [
+                    { "retry_at": { "$exists": false } },
+                    { "retry_at": { "$lte": to_bson(&Timestamp::now_utc()).unwrap() } }
+                ]
+            }
+        )
+    }
+
+    /// Update a given scheduled message with new information
+    async fn update_scheduled_message(
+        &self,
+        id: &str,
+        partial: &PartialScheduledMessage,
+        remove: Vec<FieldsScheduledMessage>,
+    ) -> Result<()> {
+        query!(
+            self,
+            update_one_by_id,
+            COL,
+            id,
+            partial,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None
+        )
+        .map(|_| ())
+    }
+
+    /// Delete a scheduled message by its id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
+
+impl IntoDocumentPath for FieldsScheduledMessage {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsScheduledMessage::Failure => "failure",
+            FieldsScheduledMessage::RetryAt => "retry_at",
+        })
+    }
+}
@@ -0,0 +1,95 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{FieldsScheduledMessage, PartialScheduledMessage, ScheduledMessage};
+
+use super::AbstractScheduledMessages;
+
+#[async_trait]
+impl AbstractScheduledMessages for ReferenceDb {
+    /// Insert a new scheduled message into the database
+    async fn insert_scheduled_message(&self, message: &ScheduledMessage) -> Result<()> {
+        let mut scheduled_messages = self.scheduled_messages.lock().await;
+        if scheduled_messages.contains_key(&message.id) {
+            Err(create_database_error!("insert", "scheduled_message"))
+        } else {
+            scheduled_messages.insert(message.id.to_string(), message.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a scheduled message by its id
+    async fn fetch_scheduled_message(&self, id: &str) -> Result<ScheduledMessage> {
+        let scheduled_messages = self.scheduled_messages.lock().await;
+        scheduled_messages
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch scheduled messages in a channel, optionally only those by a given author
+    async fn fetch_scheduled_messages(
+        &self,
+        channel: &str,
+        author: Option<&str>,
+    ) -> Result<Vec<ScheduledMessage>> {
+        let scheduled_messages = self.scheduled_messages.lock().await;
+        Ok(scheduled_messages
+            .values()
+            .filter(|message| {
+                message.channel == channel && author.is_none_or(|author| message.author == author)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch scheduled messages which are due to be sent
+    async fn fetch_due_scheduled_messages(&self) -> Result<Vec<ScheduledMessage>> {
+        let scheduled_messages = self.scheduled_messages.lock().await;
+        let now = Timestamp::now_utc();
+
+        Ok(scheduled_messages
+            .values()
+            .filter(|message| {
+                message.send_at <= now
+                    && message.failure.is_none()
+                    && message
+                        .retry_at
+                        .as_ref()
+                        .is_none_or(|retry_at| *retry_at <= now)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update a given scheduled message with new information
+    async fn update_scheduled_message(
+        &self,
+        id: &str,
+        partial: &PartialScheduledMessage,
+        remove: Vec<FieldsScheduledMessage>,
+    ) -> Result<()> {
+        let mut scheduled_messages = self.scheduled_messages.lock().await;
+        if let Some(message) = scheduled_messages.get_mut(id) {
+            for field in &remove {
+                message.remove_field(field);
+            }
+
+            message.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete a scheduled message by its id
+    async fn delete_scheduled_message(&self, id: &str) -> Result<()> {
+        let mut scheduled_messages = self.scheduled_messages.lock().await;
+        if scheduled_messages.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
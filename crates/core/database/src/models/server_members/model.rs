@@ -38,6 +38,9 @@ auto_derived_partial!(
         /// Member's roles
         #[serde(skip_serializing_if = "Vec::is_empty", default)]
         pub roles: Vec<String>,
+        /// Expiry times for temporarily assigned roles
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub role_expiries: Vec<RoleExpiry>,
         /// Timestamp this member is timed out until
         #[serde(skip_serializing_if = "Option::is_none")]
         pub timeout: Option<Timestamp>,
@@ -65,12 +68,21 @@ auto_derived!(
         pub user: String,
     }
 
+    /// Expiry of a temporarily assigned role
+    pub struct RoleExpiry {
+        /// Role Id
+        pub role: String,
+        /// Time at which the role is removed from the member
+        pub expires_at: Timestamp,
+    }
+
     /// Optional fields on server member object
     pub enum FieldsMember {
         Nickname,
         Pronouns,
         Avatar,
         Roles,
+        RoleExpiries,
         Timeout,
         CanReceive,
         CanPublish,
@@ -95,6 +107,7 @@ impl Default for Member {
             pronouns: None,
             avatar: None,
             roles: vec![],
+            role_expiries: vec![],
             timeout: None,
             can_publish: true,
             can_receive: true,
@@ -238,6 +251,7 @@ impl Member {
             FieldsMember::Nickname => self.nickname = None,
             FieldsMember::Pronouns => self.pronouns = None,
             FieldsMember::Roles => self.roles.clear(),
+            FieldsMember::RoleExpiries => self.role_expiries.clear(),
             FieldsMember::Timeout => self.timeout = None,
             FieldsMember::CanReceive => self.can_receive = true,
             FieldsMember::CanPublish => self.can_publish = true,
@@ -257,6 +271,7 @@ impl Member {
                 (FieldsMember::Timeout) timeout,
                 (FieldsMember::Pronouns) pronouns,
                 ((default) FieldsMember::Roles) roles,
+                ((default) FieldsMember::RoleExpiries) role_expiries,
                 ((default) FieldsMember::CanPublish) can_publish,
                 ((default) FieldsMember::CanReceive) can_receive,
             )
@@ -265,6 +280,60 @@ impl Member {
         before
     }
 
+    /// Remove any role assignments which have passed their expiry
+    ///
+    /// Each role is only removed if its assignment is still expired at the time
+    /// of the update, so roles which have been granted again in the meantime are kept.
+    ///
+    /// Returns the changed fields before and after the update if any roles were removed.
+    pub async fn remove_expired_roles(
+        &mut self,
+        db: &Database,
+    ) -> Result<Option<(PartialMember, PartialMember)>> {
+        let now = Timestamp::now_utc();
+        let mut removed = vec![];
+        for expiry in &self.role_expiries {
+            if expiry.expires_at <= now && db.remove_expired_role(&self.id, &expiry.role).await? {
+                removed.push(expiry.clone());
+            }
+        }
+
+        if removed.is_empty() {
+            return Ok(None);
+        }
+
+        // Pick up any other changes made since this member was fetched
+        *self = db.fetch_member(&self.id.server, &self.id.user).await?;
+
+        let partial = PartialMember {
+            roles: Some(self.roles.clone()),
+            role_expiries: Some(self.role_expiries.clone()),
+            ..Default::default()
+        };
+
+        let before = PartialMember {
+            roles: Some(
+                self.roles
+                    .iter()
+                    .cloned()
+                    .chain(removed.iter().map(|expiry| expiry.role.clone()))
+                    .collect(),
+            ),
+            role_expiries: Some(self.role_expiries.iter().cloned().chain(removed).collect()),
+            ..Default::default()
+        };
+
+        EventV1::ServerMemberUpdate {
+            id: self.id.clone().into(),
+            data: partial.clone().into(),
+            clear: vec![],
+        }
+        .p(self.id.server.clone())
+        .await;
+
+        Ok(Some((before, partial)))
+    }
+
     /// Get this user's current ranking
     pub fn get_ranking(&self, server: &Server) -> i64 {
         let mut value = i64::MAX;
@@ -298,6 +367,10 @@ impl Member {
     ) -> Result<()> {
         db.soft_delete_member(&self.id).await?;
 
+        // Bots lose any commands they registered for this server
+        db.delete_server_bot_commands(&self.id.user, &self.id.server)
+            .await?;
+
         EventV1::ServerMemberLeave {
             id: self.id.server.to_string(),
             user: self.id.user.to_string(),
@@ -344,7 +417,7 @@ mod tests {
     use iso8601_timestamp::{Duration, Timestamp};
     use revolt_models::v0::DataCreateServer;
 
-    use crate::{Member, PartialMember, RemovalIntention, Server, User};
+    use crate::{Member, PartialMember, RemovalIntention, RoleExpiry, Server, User};
 
     #[tokio::test]
     async fn muted_member_rejoin() {
@@ -367,6 +440,7 @@ mod tests {
                     name: "Server".to_string(),
                     description: None,
                     nsfw: None,
+                    template: None,
                 },
                 &owner,
                 false,
@@ -408,4 +482,74 @@ mod tests {
             assert!(kickable_member.in_timeout())
         });
     }
+
+    #[tokio::test]
+    async fn expired_roles_removed() {
+        database_test!(|db| async move {
+            let owner = User::create(&db, "Server Owner".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let user = User::create(&db, "Member".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let server = Server::create(
+                &db,
+                DataCreateServer {
+                    name: "Server".to_string(),
+                    ..Default::default()
+                },
+                &owner,
+                false,
+            )
+            .await
+            .unwrap()
+            .0;
+
+            let mut member = Member::create(&db, &server, &user, None).await.unwrap().0;
+
+            member
+                .update(
+                    &db,
+                    PartialMember {
+                        roles: Some(vec![
+                            "expired".to_string(),
+                            "pending".to_string(),
+                            "permanent".to_string(),
+                        ]),
+                        role_expiries: Some(vec![
+                            RoleExpiry {
+                                role: "expired".to_string(),
+                                expires_at: Timestamp::now_utc() - Duration::minutes(5),
+                            },
+                            RoleExpiry {
+                                role: "pending".to_string(),
+                                expires_at: Timestamp::now_utc() + Duration::minutes(5),
+                            },
+                        ]),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            let expired = db.fetch_members_with_expired_roles().await.unwrap();
+            assert_eq!(expired.len(), 1);
+
+            let mut member = expired.into_iter().next().unwrap();
+            assert!(member.remove_expired_roles(&db).await.unwrap().is_some());
+            assert!(member.remove_expired_roles(&db).await.unwrap().is_none());
+
+            let member = db.fetch_member(&server.id, &user.id).await.unwrap();
+            assert_eq!(member.roles, vec!["pending", "permanent"]);
+            assert_eq!(member.role_expiries.len(), 1);
+            assert!(db
+                .fetch_members_with_expired_roles()
+                .await
+                .unwrap()
+                .is_empty());
+        });
+    }
 }
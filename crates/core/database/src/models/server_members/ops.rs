@@ -105,6 +105,9 @@ pub trait AbstractServerMembers: Sync + Send {
     /// Fetch multiple members by their ids
     async fn fetch_members(&self, server_id: &str, ids: &[String]) -> Result<Vec<Member>>;
 
+    /// Fetch members who have at least one role assignment past its expiry
+    async fn fetch_members_with_expired_roles(&self) -> Result<Vec<Member>>;
+
     /// Fetch member count of a server
     async fn fetch_member_count(&self, server_id: &str) -> Result<usize>;
 
@@ -119,6 +122,11 @@ pub trait AbstractServerMembers: Sync + Send {
         remove: Vec<FieldsMember>,
     ) -> Result<()>;
 
+    /// Remove a role from a member if its assignment has expired
+    ///
+    /// Returns whether the role was removed.
+    async fn remove_expired_role(&self, id: &MemberCompositeKey, role: &str) -> Result<bool>;
+
     /// Marks a user as no longer a member of a server, while retaining the database value.
     /// This is used to keep information such as timeouts in place, but will remove information such as join date and applied roles.
     async fn soft_delete_member(&self, id: &MemberCompositeKey) -> Result<()>;
@@ -220,6 +220,24 @@ impl AbstractServerMembers for MongoDb {
             .await)
     }
 
+    /// Fetch members who have at least one role assignment past its expiry
+    async fn fetch_members_with_expired_roles(&self) -> Result<Vec<Member>> {
+        let now = Timestamp::now_utc();
+        let date = bson::to_bson(&now).expect("Failed to serialize timestamp");
+
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "pending_deletion_at": {"$exists": false},
+                "role_expiries.expires_at": {
+                    "$lte": date
+                }
+            }
+        )
+    }
+
     /// Fetch member count of a server
     async fn fetch_member_count(&self, server_id: &str) -> Result<usize> {
         self.col::<Member>(COL)
@@ -266,6 +284,39 @@ impl AbstractServerMembers for MongoDb {
         .map(|_| ())
     }
 
+    /// Remove a role from a member if its assignment has expired
+    async fn remove_expired_role(&self, id: &MemberCompositeKey, role: &str) -> Result<bool> {
+        let now = Timestamp::now_utc();
+        let date = bson::to_bson(&now).expect("Failed to serialize timestamp");
+
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id.server": &id.server,
+                    "_id.user": &id.user,
+                    "role_expiries": {
+                        "$elemMatch": {
+                            "role": role,
+                            "expires_at": {
+                                "$lte": date
+                            }
+                        }
+                    }
+                },
+                doc! {
+                    "$pull": {
+                        "roles": role,
+                        "role_expiries": {
+                            "role": role
+                        }
+                    }
+                },
+            )
+            .await
+            .map(|result| result.modified_count == 1)
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
     /// Marks a member for deletion.
     /// This will remove the record if the user has no pending actions (eg. timeout),
     /// otherwise will slate the record for deletion by revolt_crond once the actions expire.
@@ -285,7 +336,8 @@ impl AbstractServerMembers for MongoDb {
                                 "joined_at": "",
                                 "avatar": "",
                                 "nickname": "",
-                                "roles": ""
+                                "roles": "",
+                                "role_expiries": ""
                             }
                         },
                     )
@@ -347,6 +399,7 @@ impl IntoDocumentPath for FieldsMember {
             FieldsMember::Nickname => Some("nickname"),
             FieldsMember::Pronouns => Some("pronouns"),
             FieldsMember::Roles => Some("roles"),
+            FieldsMember::RoleExpiries => Some("role_expiries"),
             FieldsMember::Timeout => Some("timeout"),
             FieldsMember::CanPublish => Some("can_publish"),
             FieldsMember::CanReceive => Some("can_receive"),
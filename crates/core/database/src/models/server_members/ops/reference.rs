@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::ReferenceDb;
@@ -130,6 +131,23 @@ impl AbstractServerMembers for ReferenceDb {
             .collect())
     }
 
+    /// Fetch members who have at least one role assignment past its expiry
+    async fn fetch_members_with_expired_roles(&self) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        let now = Timestamp::now_utc();
+
+        Ok(server_members
+            .values()
+            .filter(|member| {
+                member
+                    .role_expiries
+                    .iter()
+                    .any(|expiry| expiry.expires_at <= now)
+            })
+            .cloned()
+            .collect())
+    }
+
     /// Fetch member count of a server
     async fn fetch_member_count(&self, server_id: &str) -> Result<usize> {
         let server_members = self.server_members.lock().await;
@@ -169,6 +187,26 @@ impl AbstractServerMembers for ReferenceDb {
         }
     }
 
+    /// Remove a role from a member if its assignment has expired
+    async fn remove_expired_role(&self, id: &MemberCompositeKey, role: &str) -> Result<bool> {
+        let mut server_members = self.server_members.lock().await;
+        let now = Timestamp::now_utc();
+
+        if let Some(member) = server_members.get_mut(id) {
+            if member
+                .role_expiries
+                .iter()
+                .any(|expiry| expiry.role == role && expiry.expires_at <= now)
+            {
+                member.roles.retain(|id| id != role);
+                member.role_expiries.retain(|expiry| expiry.role != role);
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     /// Soft delete a member
     async fn soft_delete_member(&self, id: &MemberCompositeKey) -> Result<()> {
         let mut server_members = self.server_members.lock().await;
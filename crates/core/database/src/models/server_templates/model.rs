@@ -0,0 +1,284 @@
+use std::collections::HashMap;
+
+use revolt_models::v0;
+use revolt_permissions::OverrideField;
+use revolt_result::Result;
+
+use crate::{
+    Category, Channel, Database, ForumSortOrder, ForumTag, Server, SystemMessageChannels, User,
+    VoiceInformation,
+};
+
+/// Maximum number of templates a single server may have
+pub const MAX_SERVER_TEMPLATES: usize = 10;
+
+static ALPHABET: [char; 54] = [
+    '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
+    'K', 'M', 'N', 'P', 'Q', 'R', 'S', 'T', 'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f',
+    'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 'r', 's', 't', 'v', 'w', 'x', 'y', 'z',
+];
+
+auto_derived_partial!(
+    /// Snapshot of a server's structure which new servers can be created from
+    pub struct ServerTemplate {
+        /// Template code
+        #[serde(rename = "_id")]
+        pub code: String,
+        /// Id of the server this template was taken from
+        pub server: String,
+        /// Id of the user who created this template
+        pub creator: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Version of this template, incremented each time it is synced
+        pub version: i32,
+        /// Captured server structure
+        pub snapshot: ServerSnapshot,
+    },
+    "PartialServerTemplate"
+);
+
+auto_derived!(
+    /// Captured server structure
+    ///
+    /// Ids are those of the source server and are replaced when a server is created.
+    pub struct ServerSnapshot {
+        /// Description of the server
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+        /// Roles in the server
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub roles: Vec<TemplateRole>,
+        /// Channels in the server
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub channels: Vec<TemplateChannel>,
+        /// Categories in the server
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_messages: Option<SystemMessageChannels>,
+
+        /// Whether the server is flagged as not safe for work
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub nsfw: bool,
+        /// Whether analytics are enabled
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub analytics: bool,
+    }
+
+    /// Role captured in a template
+    pub struct TemplateRole {
+        /// Id of the role in the source server
+        pub id: String,
+        /// Role name
+        pub name: String,
+        /// Permissions available to this role
+        pub permissions: OverrideField,
+        /// Colour used for this role
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub colour: Option<String>,
+        /// Whether this role should be shown separately on the member sidebar
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub hoist: bool,
+        /// Ranking of this role
+        pub rank: i64,
+    }
+
+    /// Channel captured in a template
+    pub struct TemplateChannel {
+        /// Id of the channel in the source server
+        pub id: String,
+        /// Channel type
+        pub channel_type: v0::LegacyServerChannelType,
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Whether this channel is marked as not safe for work
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub nsfw: bool,
+        /// Voice information for when this channel is also a voice channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub voice: Option<VoiceInformation>,
+
+        /// Default permissions assigned to users in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+        pub role_permissions: HashMap<String, OverrideField>,
+
+        /// The channel's slowmode delay in seconds
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub slowmode: Option<u64>,
+        /// Time in seconds after which messages sent in this channel expire
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message_ttl: Option<u64>,
+
+        /// Tags which can be applied to posts in a forum
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub tags: Vec<ForumTag>,
+        /// Order forum posts are listed in by default
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_sort: Option<ForumSortOrder>,
+        /// Whether forum posts must have at least one tag applied
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub require_tag: bool,
+    }
+);
+
+impl ServerSnapshot {
+    /// Capture the current structure of a server
+    pub async fn from_server(db: &Database, server: &Server) -> Result<ServerSnapshot> {
+        let mut roles: Vec<TemplateRole> = server
+            .roles
+            .values()
+            .map(|role| TemplateRole {
+                id: role.id.clone(),
+                name: role.name.clone(),
+                permissions: role.permissions,
+                colour: role.colour.clone(),
+                hoist: role.hoist,
+                rank: role.rank,
+            })
+            .collect();
+
+        roles.sort_by_key(|role| role.rank);
+
+        // Keep the server's channel order, threads are not captured
+        let channels = db.fetch_channels(&server.channels).await?;
+        let channels = server
+            .channels
+            .iter()
+            .filter_map(|id| channels.iter().find(|channel| channel.id() == id))
+            .filter_map(|channel| match channel {
+                Channel::TextChannel {
+                    id,
+                    name,
+                    description,
+                    nsfw,
+                    voice,
+                    default_permissions,
+                    role_permissions,
+                    slowmode,
+                    message_ttl,
+                    ..
+                } => Some(TemplateChannel {
+                    id: id.clone(),
+                    channel_type: if voice.is_some() {
+                        v0::LegacyServerChannelType::Voice
+                    } else {
+                        v0::LegacyServerChannelType::Text
+                    },
+                    name: name.clone(),
+                    description: description.clone(),
+                    nsfw: *nsfw,
+                    voice: voice.clone(),
+                    default_permissions: *default_permissions,
+                    role_permissions: role_permissions.clone(),
+                    slowmode: *slowmode,
+                    message_ttl: *message_ttl,
+                    tags: vec![],
+                    default_sort: None,
+                    require_tag: false,
+                }),
+                Channel::ForumChannel {
+                    id,
+                    name,
+                    description,
+                    nsfw,
+                    default_permissions,
+                    role_permissions,
+                    tags,
+                    default_sort,
+                    require_tag,
+                    message_ttl,
+                    ..
+                } => Some(TemplateChannel {
+                    id: id.clone(),
+                    channel_type: v0::LegacyServerChannelType::Forum,
+                    name: name.clone(),
+                    description: description.clone(),
+                    nsfw: *nsfw,
+                    voice: None,
+                    default_permissions: *default_permissions,
+                    role_permissions: role_permissions.clone(),
+                    slowmode: None,
+                    message_ttl: *message_ttl,
+                    tags: tags.clone(),
+                    default_sort: Some(default_sort.clone()),
+                    require_tag: *require_tag,
+                }),
+                _ => None,
+            })
+            .collect();
+
+        Ok(ServerSnapshot {
+            description: server.description.clone(),
+            default_permissions: server.default_permissions,
+            roles,
+            channels,
+            categories: server.categories.clone().unwrap_or_default(),
+            system_messages: server.system_messages.clone(),
+            nsfw: server.nsfw,
+            analytics: server.analytics,
+        })
+    }
+}
+
+#[allow(clippy::disallowed_methods)]
+impl ServerTemplate {
+    /// Create a new template from a server
+    pub async fn create(
+        db: &Database,
+        server: &Server,
+        creator: &User,
+        data: v0::DataCreateServerTemplate,
+    ) -> Result<ServerTemplate> {
+        if db.fetch_server_templates(&server.id).await?.len() >= MAX_SERVER_TEMPLATES {
+            return Err(create_error!(TooManyServerTemplates {
+                max: MAX_SERVER_TEMPLATES
+            }));
+        }
+
+        let template = ServerTemplate {
+            code: nanoid::nanoid!(8, &ALPHABET),
+            server: server.id.clone(),
+            creator: creator.id.clone(),
+            name: data.name,
+            description: data.description,
+            version: 1,
+            snapshot: ServerSnapshot::from_server(db, server).await?,
+        };
+
+        db.insert_server_template(&template).await?;
+        Ok(template)
+    }
+
+    /// Capture the source server's current structure and bump the version
+    pub async fn sync(&mut self, db: &Database, server: &Server) -> Result<()> {
+        let partial = PartialServerTemplate {
+            version: Some(self.version + 1),
+            snapshot: Some(ServerSnapshot::from_server(db, server).await?),
+            ..Default::default()
+        };
+
+        db.update_server_template(&self.code, &partial).await?;
+        self.apply_options(partial);
+        Ok(())
+    }
+
+    /// Delete this template
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_server_template(&self.code).await
+    }
+}
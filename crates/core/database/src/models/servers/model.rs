@@ -10,7 +10,10 @@ use revolt_permissions::{OverrideField, DEFAULT_PERMISSION_SERVER};
 use revolt_result::Result;
 use ulid::Ulid;
 
-use crate::{events::client::EventV1, Channel, Database, File, User};
+use crate::{
+    events::client::EventV1, Channel, Database, File, ForumTag, PartialChannel, ServerSnapshot,
+    User,
+};
 
 auto_derived_partial!(
     /// Server
@@ -107,6 +110,16 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+
+        /// Default permissions assigned to users in channels in this category
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to channels in this category
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
     }
 
     /// System message channel assignments
@@ -150,6 +163,12 @@ impl Server {
         owner: &User,
         create_default_channels: bool,
     ) -> Result<(Server, Vec<Channel>)> {
+        let template = if let Some(code) = &data.template {
+            Some(db.fetch_server_template(code).await?)
+        } else {
+            None
+        };
+
         let mut server = Server {
             id: ulid::Ulid::new().to_string(),
             owner: owner.id.to_string(),
@@ -169,7 +188,14 @@ impl Server {
             system_messages: None,
         };
 
-        let channels: Vec<Channel> = if create_default_channels {
+        let channels: Vec<Channel> = if let Some(template) = template {
+            if server.description.is_none() {
+                server.description = template.snapshot.description.clone();
+            }
+
+            server.nsfw = data.nsfw.unwrap_or(template.snapshot.nsfw);
+            server.apply_snapshot(db, template.snapshot).await?
+        } else if create_default_channels {
             vec![
                 Channel::create_server_channel(
                     db,
@@ -192,6 +218,123 @@ impl Server {
         Ok((server, channels))
     }
 
+    /// Recreate the structure captured in a template snapshot on a new server
+    ///
+    /// Roles, channels and categories are given new ids and all references between them are remapped.
+    async fn apply_snapshot(
+        &mut self,
+        db: &Database,
+        snapshot: ServerSnapshot,
+    ) -> Result<Vec<Channel>> {
+        self.default_permissions = snapshot.default_permissions;
+        self.analytics = snapshot.analytics;
+
+        let mut role_ids = HashMap::new();
+        for role in snapshot.roles {
+            let id = Ulid::new().to_string();
+            role_ids.insert(role.id, id.clone());
+            self.roles.insert(
+                id.clone(),
+                Role {
+                    id,
+                    name: role.name,
+                    permissions: role.permissions,
+                    colour: role.colour,
+                    hoist: role.hoist,
+                    rank: role.rank,
+                    icon: None,
+                },
+            );
+        }
+
+        let remap_roles = |overrides: HashMap<String, OverrideField>| {
+            overrides
+                .into_iter()
+                .filter_map(|(id, value)| role_ids.get(&id).map(|id| (id.clone(), value)))
+                .collect::<HashMap<String, OverrideField>>()
+        };
+
+        let mut channel_ids = HashMap::new();
+        let mut channels = vec![];
+        for template in snapshot.channels {
+            let mut channel = Channel::create_server_channel(
+                db,
+                self,
+                DataCreateServerChannel {
+                    channel_type: template.channel_type,
+                    name: template.name,
+                    description: template.description,
+                    nsfw: Some(template.nsfw),
+                    voice: template.voice.map(|voice| voice.into()),
+                },
+                false,
+            )
+            .await?;
+
+            channel_ids.insert(template.id, channel.id().to_string());
+            self.channels.push(channel.id().to_string());
+
+            // The server does not exist yet, so skip emitting update events
+            let partial = PartialChannel {
+                default_permissions: template.default_permissions,
+                role_permissions: Some(remap_roles(template.role_permissions)),
+                slowmode: template.slowmode,
+                message_ttl: template.message_ttl,
+                tags: (!template.tags.is_empty()).then(|| {
+                    template
+                        .tags
+                        .into_iter()
+                        .map(|tag| ForumTag {
+                            id: Ulid::new().to_string(),
+                            ..tag
+                        })
+                        .collect()
+                }),
+                default_sort: template.default_sort,
+                require_tag: template.require_tag.then_some(true),
+                ..Default::default()
+            };
+
+            db.update_channel(channel.id(), &partial, vec![]).await?;
+            channel.apply_options(partial);
+            channels.push(channel);
+        }
+
+        let remap_channel = |id: Option<String>| id.and_then(|id| channel_ids.get(&id).cloned());
+
+        if !snapshot.categories.is_empty() {
+            self.categories = Some(
+                snapshot
+                    .categories
+                    .into_iter()
+                    .map(|category| Category {
+                        id: Ulid::new().to_string(),
+                        title: category.title,
+                        channels: category
+                            .channels
+                            .into_iter()
+                            .filter_map(|id| channel_ids.get(&id).cloned())
+                            .collect(),
+                        default_permissions: category.default_permissions,
+                        role_permissions: remap_roles(category.role_permissions),
+                    })
+                    .collect(),
+            );
+        }
+
+        self.system_messages =
+            snapshot
+                .system_messages
+                .map(|system_messages| SystemMessageChannels {
+                    user_joined: remap_channel(system_messages.user_joined),
+                    user_left: remap_channel(system_messages.user_left),
+                    user_kicked: remap_channel(system_messages.user_kicked),
+                    user_banned: remap_channel(system_messages.user_banned),
+                });
+
+        Ok(channels)
+    }
+
     /// Update server data
     pub async fn update(
         &mut self,
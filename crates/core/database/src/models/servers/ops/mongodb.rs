@@ -129,7 +129,10 @@ impl AbstractServers for MongoDb {
                 },
                 doc! {
                     "$pull": {
-                        "roles": &role_id
+                        "roles": &role_id,
+                        "role_expiries": {
+                            "role": &role_id
+                        }
                     }
                 },
             )
@@ -266,6 +269,13 @@ impl MongoDb {
             .await
             .map_err(|_| create_database_error!("delete_many", "audit_logs"))?;
 
+        self.col::<Document>("server_templates")
+            .delete_many(doc! {
+                "server": &server_id
+            })
+            .await
+            .map_err(|_| create_database_error!("delete_many", "server_templates"))?;
+
         Ok(())
     }
 }
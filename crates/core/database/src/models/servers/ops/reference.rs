@@ -75,6 +75,11 @@ impl AbstractServers for ReferenceDb {
     async fn delete_server(&self, id: &str) -> Result<()> {
         let mut servers = self.servers.lock().await;
         if servers.remove(id).is_some() {
+            self.server_templates
+                .lock()
+                .await
+                .retain(|_, template| template.server != id);
+
             Ok(())
         } else {
             Err(create_error!(NotFound))
@@ -122,15 +127,26 @@ impl AbstractServers for ReferenceDb {
     ///
     /// Also updates channels and members.
     async fn delete_role(&self, server_id: &str, role_id: &str) -> Result<()> {
-        let mut servers = self.servers.lock().await;
-        if let Some(server) = servers.get_mut(server_id) {
-            if server.roles.remove(role_id).is_some() {
-                Ok(())
-            } else {
-                Err(create_error!(NotFound))
+        {
+            let mut servers = self.servers.lock().await;
+            let server = servers
+                .get_mut(server_id)
+                .ok_or_else(|| create_error!(NotFound))?;
+
+            if server.roles.remove(role_id).is_none() {
+                return Err(create_error!(NotFound));
             }
-        } else {
-            Err(create_error!(NotFound))
         }
+
+        let mut server_members = self.server_members.lock().await;
+        for member in server_members
+            .values_mut()
+            .filter(|member| member.id.server == server_id)
+        {
+            member.roles.retain(|id| id != role_id);
+            member.role_expiries.retain(|expiry| expiry.role != role_id);
+        }
+
+        Ok(())
     }
 }
@@ -66,6 +66,9 @@ auto_derived_partial!(
         pub suspended_until: Option<Timestamp>,
         /// Last acknowledged policy change
         pub last_acknowledged_policy_change: Timestamp,
+        /// Whether this user shares read receipts in direct messages and groups
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub read_receipts: Option<bool>,
     },
     "PartialUser"
 );
@@ -197,6 +200,7 @@ impl Default for User {
             bot: Default::default(),
             suspended_until: Default::default(),
             last_acknowledged_policy_change: Timestamp::UNIX_EPOCH,
+            read_receipts: None,
         }
     }
 }
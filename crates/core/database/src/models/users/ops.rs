@@ -32,6 +32,9 @@ pub trait AbstractUsers: Sync + Send {
     /// Fetch ids of servers that both users share
     async fn fetch_mutual_server_ids(&self, user_a: &str, user_b: &str) -> Result<Vec<String>>;
 
+    /// Fetch ids of bots which are members of a server
+    async fn fetch_server_bot_ids(&self, server_id: &str) -> Result<Vec<String>>;
+
     /// Update a user by their id given some data
     async fn update_user(
         &self,
@@ -192,6 +192,66 @@ impl AbstractUsers for MongoDb {
             .await)
     }
 
+    /// Fetch ids of bots which are members of a server
+    async fn fetch_server_bot_ids(&self, server_id: &str) -> Result<Vec<String>> {
+        Ok(self
+            .col::<DocumentId>("server_members")
+            .aggregate(vec![
+                doc! {
+                    "$match": {
+                        "_id.server": server_id,
+                        "pending_deletion_at": {
+                            "$exists": false
+                        }
+                    }
+                },
+                doc! {
+                    "$lookup": {
+                        "from": COL,
+                        "as": "bots",
+                        "let": {
+                            "user": "$_id.user"
+                        },
+                        "pipeline": [
+                            {
+                                "$match": {
+                                    "$expr": {
+                                        "$eq": [ "$_id", "$$user" ]
+                                    },
+                                    "bot": {
+                                        "$exists": true
+                                    }
+                                }
+                            },
+                            {
+                                "$project": {
+                                    "_id": 1
+                                }
+                            }
+                        ]
+                    }
+                },
+                doc! {
+                    "$match": {
+                        "bots": {
+                            "$size": 1_i32
+                        }
+                    }
+                },
+                doc! {
+                    "$project": {
+                        "_id": "$_id.user"
+                    }
+                },
+            ])
+            .await
+            .map_err(|_| create_database_error!("aggregate", "server_members"))?
+            .filter_map(|s| async { s.ok() })
+            .filter_map(|doc| async move { doc.get_str("_id").map(|id| id.to_string()).ok() })
+            .collect()
+            .await)
+    }
+
     /// Update a user by their id given some data
     async fn update_user(
         &self,
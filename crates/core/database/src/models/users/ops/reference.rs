@@ -80,6 +80,23 @@ impl AbstractUsers for ReferenceDb {
         todo!()
     }
 
+    /// Fetch ids of bots which are members of a server
+    async fn fetch_server_bot_ids(&self, server_id: &str) -> Result<Vec<String>> {
+        let server_members = self.server_members.lock().await;
+        let users = self.users.lock().await;
+        Ok(server_members
+            .keys()
+            .filter(|id| id.server == server_id)
+            .filter(|id| {
+                users
+                    .get(&id.user)
+                    .map(|user| user.bot.is_some())
+                    .unwrap_or_default()
+            })
+            .map(|id| id.user.clone())
+            .collect())
+    }
+
     /// Update a user by their id given some data
     async fn update_user(
         &self,
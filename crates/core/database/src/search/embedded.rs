@@ -0,0 +1,327 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::RwLock;
+
+use super::{AbstractSearchIndex, SearchHit};
+
+/// BM25 term frequency saturation
+const K1: f32 = 1.2;
+/// BM25 document length normalisation
+const B: f32 = 0.75;
+
+/// Score multiplier for terms which start with a query term
+const PREFIX_WEIGHT: f32 = 0.6;
+/// Score multiplier for terms within a few typos of a query term
+const FUZZY_WEIGHT: f32 = 0.4;
+/// Maximum number of dictionary terms a single query term may expand to
+const MAX_EXPANSIONS: usize = 64;
+
+/// Indexed message
+struct Document {
+    channel: String,
+    terms: Vec<String>,
+    length: usize,
+}
+
+#[derive(Default)]
+struct Inner {
+    documents: HashMap<String, Document>,
+    /// Term dictionary mapping to term frequency per message,
+    /// kept sorted so that prefixes can be looked up as a range
+    postings: BTreeMap<String, HashMap<String, u32>>,
+    total_length: usize,
+}
+
+/// In-process inverted index ranking messages with BM25
+///
+/// Query terms also match terms they are a prefix of and terms within
+/// one typo (or two for longer terms), at a lower score than exact matches.
+///
+/// The index lives in memory, each process keeps its own copy up to date
+/// from message events on the event bus.
+#[derive(Default)]
+pub struct EmbeddedSearchIndex {
+    inner: RwLock<Inner>,
+}
+
+/// Split text into lowercase terms
+fn tokenise(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Number of typos tolerated for a query term of a given length
+fn max_typos(length: usize) -> usize {
+    match length {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// Levenshtein distance between two terms, giving up once it exceeds `max`
+fn within_distance(a: &[char], b: &[char], max: usize) -> bool {
+    if a.len().abs_diff(b.len()) > max {
+        return false;
+    }
+
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, ca) in a.iter().enumerate() {
+        current[0] = i + 1;
+        let mut row_min = current[0];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            row_min = row_min.min(current[j + 1]);
+        }
+
+        if row_min > max {
+            return false;
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()] <= max
+}
+
+impl Inner {
+    fn remove(&mut self, id: &str) {
+        if let Some(document) = self.documents.remove(id) {
+            for term in document.terms {
+                if let Some(postings) = self.postings.get_mut(&term) {
+                    postings.remove(id);
+                    if postings.is_empty() {
+                        self.postings.remove(&term);
+                    }
+                }
+            }
+
+            self.total_length -= document.length;
+        }
+    }
+
+    /// Find dictionary terms matching a query term along with their weight
+    fn expand(&self, term: &str) -> Vec<(&String, f32)> {
+        let mut expansions = vec![];
+
+        if let Some((key, _)) = self.postings.get_key_value(term) {
+            expansions.push((key, 1.0));
+        }
+
+        expansions.extend(
+            self.postings
+                .range::<str, _>((std::ops::Bound::Excluded(term), std::ops::Bound::Unbounded))
+                .map(|(key, _)| key)
+                .take_while(|key| key.starts_with(term))
+                .take(MAX_EXPANSIONS)
+                .map(|key| (key, PREFIX_WEIGHT)),
+        );
+
+        let max = max_typos(term.chars().count());
+        if max > 0 {
+            let chars: Vec<char> = term.chars().collect();
+            let seen: HashSet<&String> = expansions.iter().map(|(key, _)| *key).collect();
+
+            expansions.extend(
+                self.postings
+                    .keys()
+                    .filter(|key| !seen.contains(key))
+                    .filter(|key| within_distance(&chars, &key.chars().collect::<Vec<_>>(), max))
+                    .take(MAX_EXPANSIONS)
+                    .map(|key| (key, FUZZY_WEIGHT)),
+            );
+        }
+
+        expansions
+    }
+}
+
+impl EmbeddedSearchIndex {
+    /// Add text to the index, replacing anything indexed under the same id
+    pub fn insert(&self, id: &str, channel: &str, text: &str) {
+        let mut frequencies: HashMap<String, u32> = HashMap::new();
+        for term in tokenise(text) {
+            *frequencies.entry(term).or_default() += 1;
+        }
+
+        let mut inner = self.inner.write().unwrap();
+        inner.remove(id);
+
+        if frequencies.is_empty() {
+            return;
+        }
+
+        let length = frequencies.values().sum::<u32>() as usize;
+        for (term, frequency) in &frequencies {
+            inner
+                .postings
+                .entry(term.clone())
+                .or_default()
+                .insert(id.to_string(), *frequency);
+        }
+
+        inner.total_length += length;
+        inner.documents.insert(
+            id.to_string(),
+            Document {
+                channel: channel.to_string(),
+                terms: frequencies.into_keys().collect(),
+                length,
+            },
+        );
+    }
+
+    /// Remove an id from the index
+    pub fn remove(&self, id: &str) {
+        self.inner.write().unwrap().remove(id);
+    }
+
+    /// Find ids matching every term in the query, most relevant first
+    pub fn query(&self, text: &str, channels: Option<&[String]>, limit: usize) -> Vec<SearchHit> {
+        let terms: HashSet<String> = tokenise(text).collect();
+        if terms.is_empty() {
+            return vec![];
+        }
+
+        let inner = self.inner.read().unwrap();
+        let total = inner.documents.len() as f32;
+        let average_length = inner.total_length as f32 / total.max(1.0);
+
+        let mut scores: HashMap<&str, (f32, usize)> = HashMap::new();
+        for term in &terms {
+            // Only count the best matching expansion of each query term
+            let mut best: HashMap<&str, f32> = HashMap::new();
+
+            for (key, weight) in inner.expand(term) {
+                let postings = &inner.postings[key];
+                let frequency = postings.len() as f32;
+                let idf = (1.0 + (total - frequency + 0.5) / (frequency + 0.5)).ln();
+
+                for (id, count) in postings {
+                    let document = &inner.documents[id];
+                    if channels.is_some_and(|channels| !channels.contains(&document.channel)) {
+                        continue;
+                    }
+
+                    let tf = *count as f32;
+                    let norm = 1.0 - B + B * document.length as f32 / average_length;
+                    let score = weight * idf * (tf * (K1 + 1.0)) / (tf + K1 * norm);
+
+                    let entry = best.entry(id.as_str()).or_default();
+                    *entry = entry.max(score);
+                }
+            }
+
+            for (id, score) in best {
+                let entry = scores.entry(id).or_default();
+                entry.0 += score;
+                entry.1 += 1;
+            }
+        }
+
+        let mut hits: Vec<SearchHit> = scores
+            .into_iter()
+            .filter(|(_, (_, matched))| *matched == terms.len())
+            .map(|(id, (score, _))| SearchHit {
+                id: id.to_string(),
+                score,
+            })
+            .collect();
+
+        // Break ties with the newest message first
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| b.id.cmp(&a.id)));
+        hits.truncate(limit);
+        hits
+    }
+}
+
+#[async_trait]
+impl AbstractSearchIndex for EmbeddedSearchIndex {
+    async fn index_message(&self, id: &str, channel: &str, content: Option<&str>) {
+        if let Some(content) = content {
+            self.insert(id, channel, content);
+        } else {
+            self.remove(id);
+        }
+    }
+
+    async fn remove_messages(&self, ids: &[String]) {
+        let mut inner = self.inner.write().unwrap();
+        for id in ids {
+            inner.remove(id);
+        }
+    }
+
+    async fn search(
+        &self,
+        text: &str,
+        channels: Option<&[String]>,
+        limit: usize,
+    ) -> Vec<SearchHit> {
+        self.query(text, channels, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EmbeddedSearchIndex;
+
+    fn ids(index: &EmbeddedSearchIndex, query: &str) -> Vec<String> {
+        index
+            .query(query, None, 10)
+            .into_iter()
+            .map(|hit| hit.id)
+            .collect()
+    }
+
+    #[test]
+    fn exact_prefix_and_typos() {
+        let index = EmbeddedSearchIndex::default();
+        index.insert("a", "channel", "The quick brown fox");
+        index.insert("b", "channel", "Jumps over the lazy dog");
+
+        assert_eq!(ids(&index, "fox"), vec!["a"]);
+        assert_eq!(ids(&index, "qui"), vec!["a"]);
+        assert_eq!(ids(&index, "quikc"), Vec::<String>::new());
+        assert_eq!(ids(&index, "quick brwn"), vec!["a"]);
+        assert_eq!(ids(&index, "lazzy"), vec!["b"]);
+        assert_eq!(ids(&index, "the"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn exact_matches_rank_first() {
+        let index = EmbeddedSearchIndex::default();
+        index.insert("a", "channel", "catalogue");
+        index.insert("b", "channel", "cat");
+
+        assert_eq!(ids(&index, "cat"), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn replace_and_remove() {
+        let index = EmbeddedSearchIndex::default();
+        index.insert("a", "channel", "hello world");
+        index.insert("a", "channel", "goodbye world");
+
+        assert!(ids(&index, "hello").is_empty());
+        assert_eq!(ids(&index, "goodbye"), vec!["a"]);
+
+        index.remove("a");
+        assert!(ids(&index, "world").is_empty());
+    }
+
+    #[test]
+    fn channel_scope() {
+        let index = EmbeddedSearchIndex::default();
+        index.insert("a", "one", "hello");
+        index.insert("b", "two", "hello");
+
+        let hits = index.query("hello", Some(&["two".to_string()]), 10);
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].id, "b");
+    }
+}
@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::StreamExt;
+use revolt_config::config;
+use revolt_models::v0::MessageSort;
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{
+    events::client::EventV1, Database, Message, MessageFilter, MessageQuery, MessageTimePeriod,
+};
+
+mod embedded;
+pub use embedded::EmbeddedSearchIndex;
+
+/// Maximum number of candidates to take from the index for a single query
+const MAX_CANDIDATES: usize = 1000;
+
+/// Number of messages to load at a time when building the index
+const BACKFILL_BATCH: i64 = 1000;
+
+/// Event bus topic for messages written without publishing a client event
+///
+/// Clients never subscribe to it, so only search indexes pick these up
+const SEARCH_TOPIC: &str = "search";
+
+/// How long to wait before reconnecting to the event bus
+const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+static SEARCH_INDEX: OnceLock<Arc<dyn AbstractSearchIndex>> = OnceLock::new();
+
+/// Whether the search index has finished loading existing messages
+static SEARCH_INDEX_READY: AtomicBool = AtomicBool::new(false);
+
+/// Message matched by a search index
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    /// Message Id
+    pub id: String,
+    /// Relevance of the match, higher is better
+    pub score: f32,
+}
+
+/// Full-text index of message content
+///
+/// Results are always re-fetched from the database, so an index may
+/// hold stale entries without leaking deleted or moved messages.
+#[async_trait]
+pub trait AbstractSearchIndex: Sync + Send {
+    /// Add a message's content to the index, replacing any previous version of it
+    ///
+    /// Messages without content are removed from the index
+    async fn index_message(&self, id: &str, channel: &str, content: Option<&str>);
+
+    /// Remove messages from the index
+    async fn remove_messages(&self, ids: &[String]);
+
+    /// Find messages matching the given text, most relevant first
+    ///
+    /// If channels are given, only messages in those channels are matched
+    async fn search(&self, text: &str, channels: Option<&[String]>, limit: usize)
+        -> Vec<SearchHit>;
+}
+
+/// Use the given search index for message search in this process
+///
+/// The index is only queried once it has been marked as ready.
+/// Returns false if an index has already been set
+pub fn set_search_index(index: Arc<dyn AbstractSearchIndex>) -> bool {
+    SEARCH_INDEX.set(index).is_ok()
+}
+
+/// Mark the search index as holding every existing message
+pub fn set_search_index_ready() {
+    SEARCH_INDEX_READY.store(true, Ordering::Release);
+}
+
+/// Set up the search backend specified in configuration
+///
+/// The embedded backend follows message events from every node on the event bus
+/// and loads existing messages in the background, searches fall back to the
+/// database until it has caught up.
+pub async fn init(db: &Database) {
+    let config = config().await;
+    if config.api.search.backend != "embedded" {
+        return;
+    }
+
+    let index: Arc<dyn AbstractSearchIndex> = Arc::new(EmbeddedSearchIndex::default());
+    if !set_search_index(index.clone()) {
+        return;
+    }
+
+    tokio::spawn(listen(db.clone(), index.clone()));
+
+    let db = db.clone();
+    tokio::spawn(async move {
+        backfill(&db, index.as_ref(), None).await;
+        info!("Built embedded search index.");
+        set_search_index_ready();
+    });
+}
+
+/// Load messages sent after the given id (or every message) into an index
+async fn backfill(db: &Database, index: &dyn AbstractSearchIndex, mut cursor: Option<String>) {
+    loop {
+        let messages = match db
+            .fetch_messages(MessageQuery {
+                limit: Some(BACKFILL_BATCH),
+                filter: MessageFilter::default(),
+                time_period: MessageTimePeriod::Absolute {
+                    before: None,
+                    after: cursor.clone(),
+                    sort: Some(MessageSort::Oldest),
+                },
+            })
+            .await
+        {
+            Ok(messages) => messages,
+            Err(err) => {
+                revolt_config::capture_error(&err);
+                break;
+            }
+        };
+
+        for message in &messages {
+            index
+                .index_message(&message.id, &message.channel, message.content.as_deref())
+                .await;
+        }
+
+        let last = messages.into_iter().map(|message| message.id).max();
+        if last.is_none() || last <= cursor {
+            break;
+        }
+
+        cursor = last;
+    }
+}
+
+/// Keep an index up to date with message events published by any node
+///
+/// Messages sent while disconnected are loaded from the database on reconnect
+async fn listen(db: Database, index: Arc<dyn AbstractSearchIndex>) {
+    let mut disconnected_at: Option<u64> = None;
+
+    loop {
+        let mut subscriber = match redis_kiss::open_pubsub_connection().await {
+            Ok(subscriber) => subscriber,
+            Err(err) => {
+                warn!("Search index failed to connect to the event bus: {err:?}");
+                tokio::time::sleep(RECONNECT_DELAY).await;
+                continue;
+            }
+        };
+
+        if let Err(err) = subscriber.psubscribe("*").await {
+            warn!("Search index failed to subscribe to events: {err:?}");
+            tokio::time::sleep(RECONNECT_DELAY).await;
+            continue;
+        }
+
+        if let Some(timestamp) = disconnected_at.take() {
+            let cursor = Ulid::from_parts(timestamp, 0).to_string();
+            backfill(&db, index.as_ref(), Some(cursor)).await;
+        }
+
+        let mut stream = subscriber.on_message();
+        while let Some(item) = stream.next().await {
+            if let Ok(event) = redis_kiss::decode_payload::<EventV1>(&item) {
+                apply_event(index.as_ref(), event).await;
+            }
+        }
+
+        warn!("Search index lost connection to the event bus, reconnecting.");
+        disconnected_at = Some(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Time went backwards")
+                .as_millis() as u64,
+        );
+        tokio::time::sleep(RECONNECT_DELAY).await;
+    }
+}
+
+/// Update an index from a message event
+async fn apply_event(index: &dyn AbstractSearchIndex, event: EventV1) {
+    match event {
+        EventV1::Message(message) => {
+            index
+                .index_message(&message.id, &message.channel, message.content.as_deref())
+                .await;
+        }
+        EventV1::MessageUpdate {
+            id, channel, data, ..
+        } => {
+            if let Some(content) = data.content {
+                index.index_message(&id, &channel, Some(&content)).await;
+            }
+        }
+        EventV1::MessageDelete { id, .. } => {
+            index.remove_messages(std::slice::from_ref(&id)).await;
+        }
+        EventV1::BulkMessageDelete { ids, .. } => {
+            index.remove_messages(&ids).await;
+        }
+        _ => {}
+    }
+}
+
+/// Add a message to the search index, if one is in use
+pub async fn index_message(message: &Message) {
+    if let Some(index) = SEARCH_INDEX.get() {
+        index
+            .index_message(&message.id, &message.channel, message.content.as_deref())
+            .await;
+    }
+}
+
+/// Notify search indexes on every node of a message inserted without a client event
+pub async fn announce_message(message: &Message) {
+    EventV1::Message(message.clone().into_model(None, None))
+        .p(SEARCH_TOPIC.to_string())
+        .await;
+}
+
+/// Remove messages from the search index, if one is in use
+pub async fn remove_messages(ids: &[String]) {
+    if let Some(index) = SEARCH_INDEX.get() {
+        index.remove_messages(ids).await;
+    }
+}
+
+/// Fetch messages for a query, using the search index for full-text queries if one is in use
+pub async fn fetch_messages(db: &Database, mut query: MessageQuery) -> Result<Vec<Message>> {
+    let Some(index) = SEARCH_INDEX
+        .get()
+        .filter(|_| SEARCH_INDEX_READY.load(Ordering::Acquire))
+    else {
+        return db.fetch_messages(query).await;
+    };
+
+    let Some(text) = query.filter.query.take() else {
+        return db.fetch_messages(query).await;
+    };
+
+    let scope = match (&query.filter.channel, &query.filter.channels) {
+        (Some(channel), _) => Some(vec![channel.clone()]),
+        (None, Some(channels)) => Some(channels.clone()),
+        (None, None) => None,
+    };
+
+    let by_relevance = matches!(
+        query.time_period,
+        MessageTimePeriod::Absolute {
+            sort: Some(MessageSort::Relevance),
+            ..
+        }
+    );
+
+    // Other orderings are applied by the database, so every match has to be considered
+    let limit = if by_relevance {
+        MAX_CANDIDATES
+    } else {
+        usize::MAX
+    };
+
+    let hits = index.search(&text, scope.as_deref(), limit).await;
+    if hits.is_empty() {
+        return Ok(vec![]);
+    }
+
+    query.filter.ids = Some(hits.iter().map(|hit| hit.id.clone()).collect());
+
+    if by_relevance {
+        // Apply remaining filters in the database, then order by the index's ranking
+        let limit = query.limit.unwrap_or(50) as usize;
+        query.limit = Some(hits.len() as i64);
+
+        let rank: HashMap<&str, usize> = hits
+            .iter()
+            .enumerate()
+            .map(|(position, hit)| (hit.id.as_str(), position))
+            .collect();
+
+        let mut messages = db.fetch_messages(query).await?;
+        messages.sort_by_key(|message| rank.get(message.id.as_str()).copied());
+        messages.truncate(limit);
+        Ok(messages)
+    } else {
+        db.fetch_messages(query).await
+    }
+}
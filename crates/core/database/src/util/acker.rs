@@ -43,6 +43,8 @@ pub async fn ack_server(user: &User, server: &Server, db: &Database, amqp: &AMQP
                 Channel::TextChannel {
                     last_message_id, ..
                 } => last_message_id,
+                // Forums have no messages of their own
+                Channel::ForumChannel { .. } => continue,
                 _ => unreachable!(),
             }
             .clone();
@@ -31,6 +31,7 @@ impl From<crate::Bot> for Bot {
             analytics: value.analytics,
             discoverable: value.discoverable,
             interactions_url: value.interactions_url,
+            interactions_secret: value.interactions_secret,
             terms_of_service_url: value.terms_of_service_url,
             privacy_policy_url: value.privacy_policy_url,
             flags: value.flags.unwrap_or_default() as u32,
@@ -38,11 +39,38 @@ impl From<crate::Bot> for Bot {
     }
 }
 
+impl From<crate::BotCommand> for BotCommand {
+    fn from(value: crate::BotCommand) -> Self {
+        BotCommand {
+            id: value.id,
+            bot: value.bot,
+            server: value.server,
+            name: value.name,
+            description: value.description,
+            options: value.options,
+        }
+    }
+}
+
+impl From<crate::EventSubscription> for EventSubscription {
+    fn from(value: crate::EventSubscription) -> Self {
+        EventSubscription {
+            id: value.id,
+            bot: value.bot,
+            url: value.url,
+            secret: value.secret,
+            events: value.events,
+            disabled: value.disabled,
+        }
+    }
+}
+
 impl From<FieldsBot> for crate::FieldsBot {
     fn from(value: FieldsBot) -> Self {
         match value {
             FieldsBot::InteractionsURL => crate::FieldsBot::InteractionsURL,
             FieldsBot::Token => crate::FieldsBot::Token,
+            FieldsBot::InteractionsSecret => crate::FieldsBot::InteractionsSecret,
         }
     }
 }
@@ -52,6 +80,7 @@ impl From<crate::FieldsBot> for FieldsBot {
         match value {
             crate::FieldsBot::InteractionsURL => FieldsBot::InteractionsURL,
             crate::FieldsBot::Token => FieldsBot::Token,
+            crate::FieldsBot::InteractionsSecret => FieldsBot::InteractionsSecret,
         }
     }
 }
@@ -112,6 +141,7 @@ impl From<crate::Webhook> for Webhook {
             channel_id: value.channel_id,
             token: value.token,
             permissions: value.permissions,
+            source_channel_id: value.source_channel_id,
         }
     }
 }
@@ -126,6 +156,7 @@ impl From<crate::PartialWebhook> for PartialWebhook {
             channel_id: value.channel_id,
             token: value.token,
             permissions: value.permissions,
+            source_channel_id: value.source_channel_id,
         }
     }
 }
@@ -152,11 +183,13 @@ impl From<crate::Channel> for Channel {
                 active,
                 recipients,
                 last_message_id,
+                message_ttl,
             } => Channel::DirectMessage {
                 id,
                 active,
                 recipients,
                 last_message_id,
+                message_ttl,
             },
             crate::Channel::Group {
                 id,
@@ -168,6 +201,7 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 permissions,
                 nsfw,
+                message_ttl,
             } => Channel::Group {
                 id,
                 name,
@@ -178,6 +212,7 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 permissions,
                 nsfw,
+                message_ttl,
             },
             crate::Channel::TextChannel {
                 id,
@@ -188,9 +223,12 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 nsfw,
                 voice,
                 slowmode,
+                message_ttl,
+                announcement,
             } => Channel::TextChannel {
                 id,
                 server,
@@ -200,9 +238,64 @@ impl From<crate::Channel> for Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 nsfw,
                 voice: voice.map(|voice| voice.into()),
                 slowmode,
+                message_ttl,
+                announcement,
+            },
+            crate::Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon,
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                nsfw,
+                tags,
+                default_sort,
+                require_tag,
+                message_ttl,
+            } => Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon: icon.map(|file| file.into()),
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                nsfw,
+                tags: tags.into_iter().map(Into::into).collect(),
+                default_sort: default_sort.into(),
+                require_tag,
+                message_ttl,
+            },
+            crate::Channel::Thread {
+                id,
+                server,
+                parent,
+                message,
+                name,
+                owner,
+                members,
+                last_message_id,
+                archived,
+                applied_tags,
+            } => Channel::Thread {
+                id,
+                server,
+                parent,
+                message,
+                name,
+                owner,
+                members,
+                last_message_id,
+                archived,
+                applied_tags,
             },
         }
     }
@@ -218,11 +311,13 @@ impl From<Channel> for crate::Channel {
                 active,
                 recipients,
                 last_message_id,
+                message_ttl,
             } => crate::Channel::DirectMessage {
                 id,
                 active,
                 recipients,
                 last_message_id,
+                message_ttl,
             },
             Channel::Group {
                 id,
@@ -234,6 +329,7 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 permissions,
                 nsfw,
+                message_ttl,
             } => crate::Channel::Group {
                 id,
                 name,
@@ -244,6 +340,7 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 permissions,
                 nsfw,
+                message_ttl,
             },
             Channel::TextChannel {
                 id,
@@ -254,9 +351,12 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 nsfw,
                 voice,
                 slowmode,
+                message_ttl,
+                announcement,
             } => crate::Channel::TextChannel {
                 id,
                 server,
@@ -266,9 +366,64 @@ impl From<Channel> for crate::Channel {
                 last_message_id,
                 default_permissions,
                 role_permissions,
+                user_permissions,
                 nsfw,
                 voice: voice.map(|voice| voice.into()),
                 slowmode,
+                message_ttl,
+                announcement,
+            },
+            Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon,
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                nsfw,
+                tags,
+                default_sort,
+                require_tag,
+                message_ttl,
+            } => crate::Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon: icon.map(|file| file.into()),
+                default_permissions,
+                role_permissions,
+                user_permissions,
+                nsfw,
+                tags: tags.into_iter().map(Into::into).collect(),
+                default_sort: default_sort.into(),
+                require_tag,
+                message_ttl,
+            },
+            Channel::Thread {
+                id,
+                server,
+                parent,
+                message,
+                name,
+                owner,
+                members,
+                last_message_id,
+                archived,
+                applied_tags,
+            } => crate::Channel::Thread {
+                id,
+                server,
+                parent,
+                message,
+                name,
+                owner,
+                members,
+                last_message_id,
+                archived,
+                applied_tags,
             },
         }
     }
@@ -285,10 +440,20 @@ impl From<crate::PartialChannel> for PartialChannel {
             active: value.active,
             permissions: value.permissions,
             role_permissions: value.role_permissions,
+            user_permissions: value.user_permissions,
             default_permissions: value.default_permissions,
             last_message_id: value.last_message_id,
             voice: value.voice.map(|voice| voice.into()),
             slowmode: value.slowmode,
+            message_ttl: value.message_ttl,
+            announcement: value.announcement,
+            archived: value.archived,
+            tags: value
+                .tags
+                .map(|tags| tags.into_iter().map(Into::into).collect()),
+            default_sort: value.default_sort.map(Into::into),
+            require_tag: value.require_tag,
+            applied_tags: value.applied_tags,
         }
     }
 }
@@ -304,10 +469,58 @@ impl From<PartialChannel> for crate::PartialChannel {
             active: value.active,
             permissions: value.permissions,
             role_permissions: value.role_permissions,
+            user_permissions: value.user_permissions,
             default_permissions: value.default_permissions,
             last_message_id: value.last_message_id,
             voice: value.voice.map(|voice| voice.into()),
             slowmode: value.slowmode,
+            message_ttl: value.message_ttl,
+            announcement: value.announcement,
+            archived: value.archived,
+            tags: value
+                .tags
+                .map(|tags| tags.into_iter().map(Into::into).collect()),
+            default_sort: value.default_sort.map(Into::into),
+            require_tag: value.require_tag,
+            applied_tags: value.applied_tags,
+        }
+    }
+}
+
+impl From<crate::ForumTag> for ForumTag {
+    fn from(value: crate::ForumTag) -> Self {
+        ForumTag {
+            id: value.id,
+            name: value.name,
+            emoji: value.emoji,
+        }
+    }
+}
+
+impl From<ForumTag> for crate::ForumTag {
+    fn from(value: ForumTag) -> Self {
+        crate::ForumTag {
+            id: value.id,
+            name: value.name,
+            emoji: value.emoji,
+        }
+    }
+}
+
+impl From<crate::ForumSortOrder> for ForumSortOrder {
+    fn from(value: crate::ForumSortOrder) -> Self {
+        match value {
+            crate::ForumSortOrder::LatestActivity => ForumSortOrder::LatestActivity,
+            crate::ForumSortOrder::Creation => ForumSortOrder::Creation,
+        }
+    }
+}
+
+impl From<ForumSortOrder> for crate::ForumSortOrder {
+    fn from(value: ForumSortOrder) -> Self {
+        match value {
+            ForumSortOrder::LatestActivity => crate::ForumSortOrder::LatestActivity,
+            ForumSortOrder::Creation => crate::ForumSortOrder::Creation,
         }
     }
 }
@@ -320,6 +533,7 @@ impl From<FieldsChannel> for crate::FieldsChannel {
             FieldsChannel::DefaultPermissions => crate::FieldsChannel::DefaultPermissions,
             FieldsChannel::Voice => crate::FieldsChannel::Voice,
             FieldsChannel::Slowmode => crate::FieldsChannel::Slowmode,
+            FieldsChannel::MessageTtl => crate::FieldsChannel::MessageTtl,
         }
     }
 }
@@ -332,6 +546,7 @@ impl From<crate::FieldsChannel> for FieldsChannel {
             crate::FieldsChannel::DefaultPermissions => FieldsChannel::DefaultPermissions,
             crate::FieldsChannel::Voice => FieldsChannel::Voice,
             crate::FieldsChannel::Slowmode => FieldsChannel::Slowmode,
+            crate::FieldsChannel::MessageTtl => FieldsChannel::MessageTtl,
         }
     }
 }
@@ -378,6 +593,8 @@ impl From<crate::File> for File {
             size: value.size,
             deleted: value.deleted,
             reported: value.reported,
+            description: value.description,
+            spoiler: value.spoiler,
             message_id: value.message_id,
             user_id: value.user_id,
             server_id: value.server_id,
@@ -397,6 +614,8 @@ impl From<File> for crate::File {
             size: value.size,
             deleted: value.deleted,
             reported: value.reported,
+            description: value.description,
+            spoiler: value.spoiler,
             message_id: value.message_id,
             user_id: value.user_id,
             server_id: value.server_id,
@@ -429,7 +648,7 @@ impl From<crate::Metadata> for Metadata {
                 width: width as usize,
                 height: height as usize,
             },
-            crate::Metadata::Audio => Metadata::Audio,
+            crate::Metadata::Audio { duration, waveform } => Metadata::Audio { duration, waveform },
         }
     }
 }
@@ -454,7 +673,7 @@ impl From<Metadata> for crate::Metadata {
                 width: width as isize,
                 height: height as isize,
             },
-            Metadata::Audio => crate::Metadata::Audio,
+            Metadata::Audio { duration, waveform } => crate::Metadata::Audio { duration, waveform },
         }
     }
 }
@@ -481,9 +700,14 @@ impl crate::Message {
             replies: self.replies,
             reactions: self.reactions,
             interactions: self.interactions.into(),
+            components: self.components,
             masquerade: self.masquerade.map(Into::into),
             flags: self.flags.unwrap_or_default(),
             pinned: self.pinned,
+            thread: self.thread.map(Into::into),
+            poll: self.poll.map(Into::into),
+            forward: self.forward.map(Into::into),
+            expires_at: self.expires_at,
         }
     }
 }
@@ -510,9 +734,14 @@ impl From<crate::PartialMessage> for PartialMessage {
             replies: value.replies,
             reactions: value.reactions,
             interactions: value.interactions.map(Into::into),
+            components: value.components,
             masquerade: value.masquerade.map(Into::into),
             flags: value.flags,
             pinned: value.pinned,
+            thread: value.thread.map(Into::into),
+            poll: value.poll.map(Into::into),
+            forward: value.forward.map(Into::into),
+            expires_at: value.expires_at,
         }
     }
 }
@@ -540,6 +769,15 @@ impl From<crate::SystemMessage> for SystemMessage {
             crate::SystemMessage::CallStarted { by, finished_at } => {
                 Self::CallStarted { by, finished_at }
             }
+            crate::SystemMessage::PollClosed {
+                id,
+                question,
+                results,
+            } => Self::PollClosed {
+                id,
+                question,
+                results: results.into_iter().map(Into::into).collect(),
+            },
         }
     }
 }
@@ -636,6 +874,7 @@ impl From<crate::Member> for Member {
             pronouns: value.pronouns,
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
+            role_expiries: value.role_expiries.into_iter().map(Into::into).collect(),
             timeout: value.timeout,
             can_publish: value.can_publish,
             can_receive: value.can_receive,
@@ -652,6 +891,7 @@ impl From<Member> for crate::Member {
             pronouns: value.pronouns,
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
+            role_expiries: value.role_expiries.into_iter().map(Into::into).collect(),
             timeout: value.timeout,
             can_publish: value.can_publish,
             can_receive: value.can_receive,
@@ -668,6 +908,9 @@ impl From<crate::PartialMember> for PartialMember {
             pronouns: value.pronouns,
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
+            role_expiries: value
+                .role_expiries
+                .map(|expiries| expiries.into_iter().map(Into::into).collect()),
             timeout: value.timeout,
             can_publish: value.can_publish,
             can_receive: value.can_receive,
@@ -684,6 +927,9 @@ impl From<PartialMember> for crate::PartialMember {
             pronouns: value.pronouns,
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
+            role_expiries: value
+                .role_expiries
+                .map(|expiries| expiries.into_iter().map(Into::into).collect()),
             timeout: value.timeout,
             can_publish: value.can_publish,
             can_receive: value.can_receive,
@@ -709,6 +955,24 @@ impl From<MemberCompositeKey> for crate::MemberCompositeKey {
     }
 }
 
+impl From<crate::RoleExpiry> for RoleExpiry {
+    fn from(value: crate::RoleExpiry) -> Self {
+        RoleExpiry {
+            role: value.role,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
+impl From<RoleExpiry> for crate::RoleExpiry {
+    fn from(value: RoleExpiry) -> crate::RoleExpiry {
+        crate::RoleExpiry {
+            role: value.role,
+            expires_at: value.expires_at,
+        }
+    }
+}
+
 impl From<crate::FieldsMember> for FieldsMember {
     fn from(value: crate::FieldsMember) -> Self {
         match value {
@@ -716,6 +980,7 @@ impl From<crate::FieldsMember> for FieldsMember {
             crate::FieldsMember::Nickname => FieldsMember::Nickname,
             crate::FieldsMember::Pronouns => FieldsMember::Pronouns,
             crate::FieldsMember::Roles => FieldsMember::Roles,
+            crate::FieldsMember::RoleExpiries => FieldsMember::RoleExpiries,
             crate::FieldsMember::Timeout => FieldsMember::Timeout,
             crate::FieldsMember::CanReceive => FieldsMember::CanReceive,
             crate::FieldsMember::CanPublish => FieldsMember::CanPublish,
@@ -732,6 +997,7 @@ impl From<FieldsMember> for crate::FieldsMember {
             FieldsMember::Nickname => crate::FieldsMember::Nickname,
             FieldsMember::Pronouns => crate::FieldsMember::Pronouns,
             FieldsMember::Roles => crate::FieldsMember::Roles,
+            FieldsMember::RoleExpiries => crate::FieldsMember::RoleExpiries,
             FieldsMember::Timeout => crate::FieldsMember::Timeout,
             FieldsMember::CanReceive => crate::FieldsMember::CanReceive,
             FieldsMember::CanPublish => crate::FieldsMember::CanPublish,
@@ -889,6 +1155,8 @@ impl From<crate::Category> for Category {
             id: value.id,
             title: value.title,
             channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
         }
     }
 }
@@ -899,6 +1167,8 @@ impl From<Category> for crate::Category {
             id: value.id,
             title: value.title,
             channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
         }
     }
 }
@@ -925,6 +1195,68 @@ impl From<SystemMessageChannels> for crate::SystemMessageChannels {
     }
 }
 
+impl From<crate::ServerTemplate> for ServerTemplate {
+    fn from(value: crate::ServerTemplate) -> Self {
+        ServerTemplate {
+            code: value.code,
+            server: value.server,
+            creator: value.creator,
+            name: value.name,
+            description: value.description,
+            version: value.version,
+            snapshot: value.snapshot.into(),
+        }
+    }
+}
+
+impl From<crate::ServerSnapshot> for ServerSnapshot {
+    fn from(value: crate::ServerSnapshot) -> Self {
+        ServerSnapshot {
+            description: value.description,
+            default_permissions: value.default_permissions,
+            roles: value.roles.into_iter().map(Into::into).collect(),
+            channels: value.channels.into_iter().map(Into::into).collect(),
+            categories: value.categories.into_iter().map(Into::into).collect(),
+            system_messages: value.system_messages.map(Into::into),
+            nsfw: value.nsfw,
+            analytics: value.analytics,
+        }
+    }
+}
+
+impl From<crate::TemplateRole> for TemplateRole {
+    fn from(value: crate::TemplateRole) -> Self {
+        TemplateRole {
+            id: value.id,
+            name: value.name,
+            permissions: value.permissions,
+            colour: value.colour,
+            hoist: value.hoist,
+            rank: value.rank,
+        }
+    }
+}
+
+impl From<crate::TemplateChannel> for TemplateChannel {
+    fn from(value: crate::TemplateChannel) -> Self {
+        TemplateChannel {
+            id: value.id,
+            channel_type: value.channel_type,
+            name: value.name,
+            description: value.description,
+            nsfw: value.nsfw,
+            voice: value.voice.map(Into::into),
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+            slowmode: value.slowmode,
+            message_ttl: value.message_ttl,
+            tags: value.tags.into_iter().map(Into::into).collect(),
+            default_sort: value.default_sort.map(Into::into),
+            require_tag: value.require_tag,
+        }
+    }
+}
+
 impl From<crate::Role> for Role {
     fn from(value: crate::Role) -> Self {
         Role {
@@ -1075,6 +1407,7 @@ impl crate::User {
             bot: self.bot.map(|bot| bot.into()),
             relationship,
             id: self.id,
+            read_receipts: None,
         }
     }
 
@@ -1140,6 +1473,7 @@ impl crate::User {
             bot: self.bot.map(|bot| bot.into()),
             relationship,
             id: self.id,
+            read_receipts: None,
         }
     }
 
@@ -1169,6 +1503,7 @@ impl crate::User {
             bot: self.bot.map(|bot| bot.into()),
             relationship: RelationshipStatus::None, // events client will populate this from cache
             id: self.id,
+            read_receipts: None,
         }
     }
 
@@ -1205,6 +1540,7 @@ impl crate::User {
             bot: self.bot.map(|bot| bot.into()),
             relationship: RelationshipStatus::User,
             id: self.id,
+            read_receipts: self.read_receipts,
         }
     }
 
@@ -1234,6 +1570,7 @@ impl From<User> for crate::User {
             bot: value.bot.map(Into::into),
             suspended_until: None,
             last_acknowledged_policy_change: Timestamp::UNIX_EPOCH,
+            read_receipts: None,
         }
     }
 }
@@ -1259,6 +1596,7 @@ impl From<crate::PartialUser> for PartialUser {
             bot: value.bot.map(|bot| bot.into()),
             relationship: None,
             online: None,
+            read_receipts: None,
             id: value.id,
         }
     }
@@ -1412,6 +1750,7 @@ impl From<crate::FieldsMessage> for FieldsMessage {
     fn from(value: crate::FieldsMessage) -> Self {
         match value {
             crate::FieldsMessage::Pinned => FieldsMessage::Pinned,
+            crate::FieldsMessage::Thread => FieldsMessage::Thread,
         }
     }
 }
@@ -1419,6 +1758,147 @@ impl From<FieldsMessage> for crate::FieldsMessage {
     fn from(value: FieldsMessage) -> Self {
         match value {
             FieldsMessage::Pinned => crate::FieldsMessage::Pinned,
+            FieldsMessage::Thread => crate::FieldsMessage::Thread,
+        }
+    }
+}
+
+impl From<crate::ThreadSummary> for ThreadSummary {
+    fn from(value: crate::ThreadSummary) -> Self {
+        ThreadSummary {
+            id: value.id,
+            reply_count: value.reply_count,
+            last_message_id: value.last_message_id,
+        }
+    }
+}
+
+impl From<ThreadSummary> for crate::ThreadSummary {
+    fn from(value: ThreadSummary) -> Self {
+        crate::ThreadSummary {
+            id: value.id,
+            reply_count: value.reply_count,
+            last_message_id: value.last_message_id,
+        }
+    }
+}
+
+impl From<crate::ChannelExport> for ChannelExport {
+    fn from(value: crate::ChannelExport) -> Self {
+        ChannelExport {
+            id: value.id,
+            channel: value.channel,
+            requester: value.requester,
+            status: value.status.into(),
+            message_count: value.message_count,
+            json: value.json.map(|file| file.into()),
+            html: value.html.map(|file| file.into()),
+        }
+    }
+}
+
+impl From<crate::ChannelExportStatus> for ChannelExportStatus {
+    fn from(value: crate::ChannelExportStatus) -> Self {
+        match value {
+            crate::ChannelExportStatus::Pending => ChannelExportStatus::Pending,
+            crate::ChannelExportStatus::Processing => ChannelExportStatus::Processing,
+            crate::ChannelExportStatus::Complete => ChannelExportStatus::Complete,
+            crate::ChannelExportStatus::Failed => ChannelExportStatus::Failed,
+        }
+    }
+}
+
+impl From<crate::ScheduledMessage> for ScheduledMessage {
+    fn from(value: crate::ScheduledMessage) -> Self {
+        ScheduledMessage {
+            id: value.id,
+            channel: value.channel,
+            author: value.author,
+            data: value.data,
+            send_at: value.send_at,
+            failure: value.failure,
+        }
+    }
+}
+
+impl From<crate::Poll> for Poll {
+    fn from(value: crate::Poll) -> Self {
+        let options = value
+            .options
+            .into_iter()
+            .map(|option| {
+                let voters = value.votes.get(&option.id);
+
+                PollOption {
+                    votes: voters.map(|users| users.len() as u64).unwrap_or_default(),
+                    voters: if value.anonymous {
+                        None
+                    } else {
+                        Some(voters.into_iter().flatten().cloned().collect())
+                    },
+                    id: option.id,
+                    text: option.text,
+                }
+            })
+            .collect();
+
+        Poll {
+            question: value.question,
+            options,
+            multi_select: value.multi_select,
+            anonymous: value.anonymous,
+            expires_at: value.expires_at,
+            closed: value.closed,
+        }
+    }
+}
+
+impl From<crate::PollResult> for PollResult {
+    fn from(value: crate::PollResult) -> Self {
+        PollResult {
+            id: value.id,
+            text: value.text,
+            votes: value.votes,
+        }
+    }
+}
+
+impl From<crate::MessageForward> for MessageForward {
+    fn from(value: crate::MessageForward) -> Self {
+        MessageForward {
+            id: value.id,
+            channel: value.channel,
+            author: value.author,
+            timestamp: value.timestamp,
+            content: value.content,
+            attachments: value
+                .attachments
+                .map(|v| v.into_iter().map(|f| f.into()).collect()),
+        }
+    }
+}
+
+impl From<crate::ForwardedAttachment> for ForwardedAttachment {
+    fn from(value: crate::ForwardedAttachment) -> Self {
+        ForwardedAttachment {
+            id: value.id,
+            tag: value.tag,
+            filename: value.filename,
+            metadata: value.metadata.into(),
+            content_type: value.content_type,
+            size: value.size,
+            description: value.description,
+            spoiler: value.spoiler,
+        }
+    }
+}
+
+impl From<crate::MessageRevision> for MessageRevision {
+    fn from(value: crate::MessageRevision) -> Self {
+        MessageRevision {
+            content: value.content,
+            embeds: value.embeds,
+            timestamp: value.timestamp,
         }
     }
 }
@@ -1511,6 +1991,15 @@ impl From<crate::AuditLogEntryAction> for AuditLogEntryAction {
                 role,
                 permissions: permissions.into(),
             },
+            crate::AuditLogEntryAction::ChannelUserPermissionsEdit {
+                channel,
+                user,
+                permissions,
+            } => AuditLogEntryAction::ChannelUserPermissionsEdit {
+                channel,
+                user,
+                permissions: permissions.map(Into::into),
+            },
             crate::AuditLogEntryAction::ChannelDelete { channel, name } => {
                 AuditLogEntryAction::ChannelDelete { channel, name }
             }
@@ -1550,6 +2039,30 @@ impl From<crate::AuditLogEntryAction> for AuditLogEntryAction {
             crate::AuditLogEntryAction::EmojiDelete { emoji, name } => {
                 AuditLogEntryAction::EmojiDelete { emoji, name }
             }
+            crate::AuditLogEntryAction::CategoryCreate { category, title } => {
+                AuditLogEntryAction::CategoryCreate { category, title }
+            }
+            crate::AuditLogEntryAction::CategoryEdit {
+                category,
+                before,
+                after,
+            } => AuditLogEntryAction::CategoryEdit {
+                category,
+                before: before.into(),
+                after: after.into(),
+            },
+            crate::AuditLogEntryAction::CategoryRolePermissionsEdit {
+                category,
+                role,
+                permissions,
+            } => AuditLogEntryAction::CategoryRolePermissionsEdit {
+                category,
+                role,
+                permissions: permissions.into(),
+            },
+            crate::AuditLogEntryAction::CategoryDelete { category, title } => {
+                AuditLogEntryAction::CategoryDelete { category, title }
+            }
             crate::AuditLogEntryAction::MessagePin {
                 message,
                 author,
@@ -4,6 +4,7 @@ use revolt_permissions::{
     ChannelPermission, ChannelType, Override, OverrideField, PermissionValue, ALLOW_IN_TIMEOUT,
     DEFAULT_PERMISSION_DIRECT_MESSAGE,
 };
+use revolt_result::Result;
 
 use crate::{Channel, Database, Member, Server, User};
 
@@ -131,6 +132,62 @@ impl<'z> BulkDatabasePermissionQuery<'z> {
         }
     }
 
+    /// Calculate which of the given server channels each user can read messages in
+    ///
+    /// Users and members are only fetched once and reused for every channel
+    pub async fn channels_members_can_read(
+        &mut self,
+        channels: &[Channel],
+    ) -> Result<HashMap<String, Vec<String>>> {
+        if self.users.is_none() {
+            let ids: Vec<String> = self
+                .members
+                .as_ref()
+                .expect("No users or members added to the query")
+                .iter()
+                .map(|m| m.id.user.clone())
+                .collect();
+
+            self.users = Some(self.database.fetch_users(&ids[..]).await?);
+        }
+
+        if self.members.is_none() {
+            let ids: Vec<String> = self
+                .users
+                .as_ref()
+                .unwrap()
+                .iter()
+                .map(|u| u.id.clone())
+                .collect();
+
+            self.members = Some(
+                self.database
+                    .fetch_members(&self.server.id, &ids[..])
+                    .await?,
+            );
+        }
+
+        let mut readable: HashMap<String, Vec<String>> = HashMap::new();
+        for channel in channels {
+            let mut query = self.clone();
+            query.channel = Some(channel.clone());
+            query.cached_member_perms = None;
+
+            for (user, permissions) in calculate_members_permissions(&mut query).await {
+                if permissions.has_channel_permission(ChannelPermission::ViewChannel)
+                    && permissions.has_channel_permission(ChannelPermission::ReadMessageHistory)
+                {
+                    readable
+                        .entry(user)
+                        .or_default()
+                        .push(channel.id().to_string());
+                }
+            }
+        }
+
+        Ok(readable)
+    }
+
     /// Get the default channel permissions
     /// Group channel defaults should be mapped to an allow-only override
     #[allow(dead_code)]
@@ -144,6 +201,10 @@ impl<'z> BulkDatabasePermissionQuery<'z> {
                 Channel::TextChannel {
                     default_permissions,
                     ..
+                }
+                | Channel::ForumChannel {
+                    default_permissions,
+                    ..
                 } => default_permissions.unwrap_or_default().into(),
                 _ => Default::default(),
             }
@@ -159,7 +220,9 @@ impl<'z> BulkDatabasePermissionQuery<'z> {
                 Channel::DirectMessage { .. } => ChannelType::DirectMessage,
                 Channel::Group { .. } => ChannelType::Group,
                 Channel::SavedMessages { .. } => ChannelType::SavedMessages,
-                Channel::TextChannel { .. } => ChannelType::ServerChannel,
+                Channel::TextChannel { .. }
+                | Channel::ForumChannel { .. }
+                | Channel::Thread { .. } => ChannelType::ServerChannel,
             }
         } else {
             ChannelType::Unknown
@@ -173,6 +236,9 @@ impl<'z> BulkDatabasePermissionQuery<'z> {
             match channel {
                 Channel::TextChannel {
                     role_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    role_permissions, ..
                 } => role_permissions,
                 _ => panic!("Not supported for non-server channels"),
             }
@@ -188,18 +254,41 @@ async fn calculate_members_permissions<'a>(
 ) -> HashMap<String, PermissionValue> {
     let mut resp = HashMap::new();
 
-    let (_, channel_role_permissions, channel_default_permissions) = match query
+    let mut channel = query
         .channel
         .as_ref()
         .expect("A channel must be assigned to calculate channel permissions")
-        .clone()
-    {
+        .clone();
+
+    // Threads inherit their permissions from the channel they were spawned in,
+    // nobody has access to a thread whose parent no longer exists
+    if let Channel::Thread { parent, .. } = &channel {
+        match query.database.fetch_channel(parent).await {
+            Ok(parent) => channel = parent,
+            Err(_) => return resp,
+        }
+    }
+
+    let (
+        channel_id,
+        channel_role_permissions,
+        channel_user_permissions,
+        channel_default_permissions,
+    ) = match channel {
         Channel::TextChannel {
             id,
             role_permissions,
+            user_permissions,
+            default_permissions,
+            ..
+        }
+        | Channel::ForumChannel {
+            id,
+            role_permissions,
+            user_permissions,
             default_permissions,
             ..
-        } => (id, role_permissions, default_permissions),
+        } => (id, role_permissions, user_permissions, default_permissions),
         _ => panic!("Calculation of member permissions must be done on a server channel"),
     };
 
@@ -253,6 +342,12 @@ async fn calculate_members_permissions<'a>(
             .map(|m| (&m.id.user, m)),
     );
 
+    let category = query.server.categories.as_ref().and_then(|categories| {
+        categories
+            .iter()
+            .find(|category| category.channels.contains(&channel_id))
+    });
+
     for user in users {
         let member = members.get(&user.id);
 
@@ -280,9 +375,13 @@ async fn calculate_members_permissions<'a>(
             continue;
         }
 
-        // Get the server default permission, apply channel default override
+        // Get the server default permission, apply category and channel default overrides
         let mut permission = PermissionValue::from(query.server.default_permissions);
 
+        if let Some(defaults) = category.and_then(|category| category.default_permissions) {
+            permission.apply(defaults.into());
+        }
+
         if let Some(defaults) = channel_default_permissions {
             permission.apply(defaults.into());
         }
@@ -303,6 +402,26 @@ async fn calculate_members_permissions<'a>(
             permission.apply(role_override);
         }
 
+        // Apply the category role overrides
+        if let Some(category) = category {
+            let mut roles = category
+                .role_permissions
+                .iter()
+                .filter(|(id, _)| member.roles.contains(id))
+                .filter_map(|(id, permission)| {
+                    query.server.roles.get(id).map(|role| {
+                        let v: Override = (*permission).into();
+                        (role.rank, v)
+                    })
+                })
+                .collect::<Vec<(i64, Override)>>();
+
+            roles.sort_by(|a, b| b.0.cmp(&a.0));
+            for (_, role_override) in roles {
+                permission.apply(role_override);
+            }
+        }
+
         // Get the applicable channel role overrides
         let mut roles = channel_role_permissions
             .iter()
@@ -322,6 +441,11 @@ async fn calculate_members_permissions<'a>(
             permission.apply(role_override)
         }
 
+        // Apply the member specific override last
+        if let Some(member_override) = channel_user_permissions.get(&user.id) {
+            permission.apply((*member_override).into());
+        }
+
         if member.in_timeout() {
             permission.restrict(*ALLOW_IN_TIMEOUT);
         }
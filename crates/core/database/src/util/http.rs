@@ -0,0 +1,44 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use reqwest::{
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect, Client,
+};
+use revolt_models::v0::is_public_ip;
+
+/// Resolver which only hands out public addresses
+///
+/// Checking the URL before making a request is not enough, the name may
+/// resolve to an internal address by the time we connect to it.
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((name.as_str(), 0))
+                .await?
+                .filter(|addr| is_public_ip(&addr.ip()))
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(
+                    format!("{} does not resolve to a public address", name.as_str()).into(),
+                );
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+/// Build a HTTP client for sending requests to URLs provided by users
+///
+/// Redirects are not followed and hosts may only resolve to public addresses,
+/// so these requests cannot be used to reach internal services.
+pub fn public_client(timeout: Duration) -> reqwest::Result<Client> {
+    Client::builder()
+        .timeout(timeout)
+        .redirect(redirect::Policy::none())
+        .dns_resolver(Arc::new(PublicResolver))
+        .build()
+}
@@ -0,0 +1,145 @@
+use std::{
+    sync::LazyLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use hmac::{Hmac, Mac};
+use redis_kiss::{get_connection, AsyncCommands};
+use reqwest::Client;
+use revolt_models::v0::{validate_public_https_url, Interaction};
+use revolt_result::{create_error, Result, ToRevoltError};
+use sha2::Sha256;
+
+use crate::util::http::public_client;
+
+static CLIENT: LazyLock<Client> =
+    LazyLock::new(|| public_client(Duration::from_secs(10)).expect("HTTP client"));
+
+/// Keep an interaction until the bot responds to it, for up to 15 minutes
+pub async fn store_interaction(bot_id: &str, interaction: &Interaction) -> Result<()> {
+    let mut redis = get_connection()
+        .await
+        .map_err(|_| create_error!(InternalError))?;
+
+    redis
+        .set_ex(
+            format!("interaction:{bot_id}+{}", interaction.id),
+            serde_json::to_string(interaction).to_internal_error()?,
+            15 * 60,
+        )
+        .await
+        .to_internal_error()
+}
+
+/// Fetch an interaction awaiting a response from a bot without consuming it
+pub async fn fetch_interaction(bot_id: &str, id: &str) -> Result<Interaction> {
+    let mut redis = get_connection()
+        .await
+        .map_err(|_| create_error!(InternalError))?;
+
+    let interaction: Option<String> = redis
+        .get(format!("interaction:{bot_id}+{id}"))
+        .await
+        .to_internal_error()?;
+
+    serde_json::from_str(&interaction.ok_or_else(|| create_error!(NotFound))?).to_internal_error()
+}
+
+/// Take an interaction awaiting a response from a bot, it can only be responded to once
+///
+/// Fails with NotFound if the interaction has already been taken.
+pub async fn take_interaction(bot_id: &str, id: &str) -> Result<Interaction> {
+    let mut redis = get_connection()
+        .await
+        .map_err(|_| create_error!(InternalError))?;
+
+    let interaction: Option<String> = redis
+        .get_del(format!("interaction:{bot_id}+{id}"))
+        .await
+        .to_internal_error()?;
+
+    serde_json::from_str(&interaction.ok_or_else(|| create_error!(NotFound))?).to_internal_error()
+}
+
+/// Sign a request body with a secret
+///
+/// Produces the hex encoded HMAC-SHA256 of `{timestamp}.{body}`.
+pub fn sign_payload(secret: &str, timestamp: u64, body: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC can take a key of any size");
+    mac.update(format!("{timestamp}.{body}").as_bytes());
+
+    mac.finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Send an interaction to a bot's interactions URL, signed with its interactions secret
+pub async fn deliver_interaction(url: String, secret: String, interaction: Interaction) {
+    if validate_public_https_url(&url).is_err() {
+        warn!("Refusing to deliver interaction to {url}, it is not a public HTTPS URL");
+        return;
+    }
+
+    if secret.is_empty() {
+        warn!("Refusing to deliver interaction to {url}, the bot has no interactions secret");
+        return;
+    }
+
+    let body = match serde_json::to_string(&interaction) {
+        Ok(body) => body,
+        Err(error) => {
+            error!(
+                "Failed to serialise interaction {}: {error:?}",
+                interaction.id
+            );
+            return;
+        }
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    if let Err(error) = CLIENT
+        .post(&url)
+        .header("Content-Type", "application/json")
+        .header("X-Timestamp", timestamp.to_string())
+        .header("X-Signature", sign_payload(&secret, timestamp, &body))
+        .body(body)
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+    {
+        warn!(
+            "Failed to deliver interaction {} to {url}: {error:?}",
+            interaction.id
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sign_payload;
+
+    #[test]
+    fn signature() {
+        assert_eq!(
+            sign_payload("secret", 1700000000, r#"{"type":"Ready"}"#),
+            "8ac5c453a9ec28178983f2ebc052e56396ecf0154b84dde9ff6b807457bc21f8"
+        );
+
+        assert_ne!(
+            sign_payload("secret", 1700000001, r#"{"type":"Ready"}"#),
+            sign_payload("secret", 1700000000, r#"{"type":"Ready"}"#)
+        );
+
+        assert_ne!(
+            sign_payload("other", 1700000000, r#"{"type":"Ready"}"#),
+            sign_payload("secret", 1700000000, r#"{"type":"Ready"}"#)
+        );
+    }
+}
@@ -5,7 +5,9 @@ pub mod captcha;
 pub mod chunked;
 pub mod email;
 mod funcs;
+pub mod http;
 pub mod idempotency;
+pub mod interactions;
 pub mod ip;
 pub mod password;
 pub mod permissions;
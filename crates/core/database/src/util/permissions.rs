@@ -5,7 +5,7 @@ use revolt_permissions::{
     RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
 };
 
-use crate::{Channel, Database, Member, Server, User};
+use crate::{Category, Channel, Database, Member, Server, User};
 
 /// Permissions calculator
 #[derive(Clone)]
@@ -16,6 +16,7 @@ pub struct DatabasePermissionQuery<'a> {
     perspective: &'a User,
     user: Option<Cow<'a, User>>,
     channel: Option<Cow<'a, Channel>>,
+    parent: Option<Cow<'a, Channel>>,
     server: Option<Cow<'a, Server>>,
     member: Option<Cow<'a, Member>>,
 
@@ -151,7 +152,7 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
     }
 
     /// Get the ordered role overrides (from lowest to highest) for this member in this server
-    async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+    async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
         if let Some(server) = &self.server {
             let member_roles = self
                 .member
@@ -163,14 +164,14 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                 .roles
                 .iter()
                 .filter(|(id, _)| member_roles.contains(id))
-                .map(|(_, role)| {
+                .map(|(id, role)| {
                     let v: Override = role.permissions.into();
-                    (role.rank, v)
+                    (role.rank, id.clone(), v)
                 })
-                .collect::<Vec<(i64, Override)>>();
+                .collect::<Vec<(i64, String, Override)>>();
 
             roles.sort_by(|a, b| b.0.cmp(&a.0));
-            roles.into_iter().map(|(_, v)| v).collect()
+            roles.into_iter().map(|(_, id, v)| (id, v)).collect()
         } else {
             vec![]
         }
@@ -216,7 +217,17 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                 Cow::Borrowed(Channel::SavedMessages { .. })
                 | Cow::Owned(Channel::SavedMessages { .. }) => ChannelType::SavedMessages,
                 Cow::Borrowed(Channel::TextChannel { .. })
-                | Cow::Owned(Channel::TextChannel { .. }) => ChannelType::ServerChannel,
+                | Cow::Owned(Channel::TextChannel { .. })
+                | Cow::Borrowed(Channel::ForumChannel { .. })
+                | Cow::Owned(Channel::ForumChannel { .. }) => ChannelType::ServerChannel,
+                Cow::Borrowed(Channel::Thread { .. }) | Cow::Owned(Channel::Thread { .. }) => {
+                    // Nobody has access to a thread whose parent can't be found
+                    if self.resolve_parent_channel().await {
+                        ChannelType::ServerChannel
+                    } else {
+                        ChannelType::Unknown
+                    }
+                }
             }
         } else {
             ChannelType::Unknown
@@ -226,21 +237,35 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
     /// Get the default channel permissions
     /// Group channel defaults should be mapped to an allow-only override
     async fn get_default_channel_permissions(&mut self) -> Override {
-        if let Some(channel) = &self.channel {
+        self.resolve_parent_channel().await;
+
+        if let Some(channel) = self.parent.as_ref().or(self.channel.as_ref()) {
             match channel {
                 Cow::Borrowed(Channel::Group { permissions, .. })
                 | Cow::Owned(Channel::Group { permissions, .. }) => Override {
                     allow: permissions.unwrap_or(*DEFAULT_PERMISSION_DIRECT_MESSAGE as i64) as u64,
                     deny: 0,
                 },
-                Cow::Borrowed(Channel::TextChannel {
-                    default_permissions,
-                    ..
-                })
-                | Cow::Owned(Channel::TextChannel {
-                    default_permissions,
-                    ..
-                }) => default_permissions.unwrap_or_default().into(),
+                Cow::Borrowed(
+                    Channel::TextChannel {
+                        default_permissions,
+                        ..
+                    }
+                    | Channel::ForumChannel {
+                        default_permissions,
+                        ..
+                    },
+                )
+                | Cow::Owned(
+                    Channel::TextChannel {
+                        default_permissions,
+                        ..
+                    }
+                    | Channel::ForumChannel {
+                        default_permissions,
+                        ..
+                    },
+                ) => default_permissions.unwrap_or_default().into(),
                 _ => Default::default(),
             }
         } else {
@@ -249,15 +274,27 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
     }
 
     /// Get the ordered role overrides (from lowest to highest) for this member in this channel
-    async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
-        if let Some(channel) = &self.channel {
+    async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+        self.resolve_parent_channel().await;
+
+        if let Some(channel) = self.parent.as_ref().or(self.channel.as_ref()) {
             match channel {
-                Cow::Borrowed(Channel::TextChannel {
-                    role_permissions, ..
-                })
-                | Cow::Owned(Channel::TextChannel {
-                    role_permissions, ..
-                }) => {
+                Cow::Borrowed(
+                    Channel::TextChannel {
+                        role_permissions, ..
+                    }
+                    | Channel::ForumChannel {
+                        role_permissions, ..
+                    },
+                )
+                | Cow::Owned(
+                    Channel::TextChannel {
+                        role_permissions, ..
+                    }
+                    | Channel::ForumChannel {
+                        role_permissions, ..
+                    },
+                ) => {
                     if let Some(server) = &self.server {
                         let member_roles = self
                             .member
@@ -271,13 +308,13 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                             .filter_map(|(id, permission)| {
                                 server.roles.get(id).map(|role| {
                                     let v: Override = (*permission).into();
-                                    (role.rank, v)
+                                    (role.rank, id.clone(), v)
                                 })
                             })
-                            .collect::<Vec<(i64, Override)>>();
+                            .collect::<Vec<(i64, String, Override)>>();
 
                         roles.sort_by(|a, b| b.0.cmp(&a.0));
-                        roles.into_iter().map(|(_, v)| v).collect()
+                        roles.into_iter().map(|(_, id, v)| (id, v)).collect()
                     } else {
                         vec![]
                     }
@@ -289,6 +326,72 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         }
     }
 
+    /// Get the default permissions of the category this channel is in
+    async fn get_default_category_permissions(&mut self) -> Override {
+        self.resolve_parent_channel().await;
+
+        self.category()
+            .and_then(|category| category.default_permissions)
+            .map(Into::into)
+            .unwrap_or_default()
+    }
+
+    /// Get the ordered role overrides (from lowest to highest) for this member in this channel's category
+    async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+        self.resolve_parent_channel().await;
+
+        if let (Some(server), Some(category)) = (&self.server, self.category()) {
+            let member_roles = self
+                .member
+                .as_ref()
+                .map(|member| member.roles.clone())
+                .unwrap_or_default();
+
+            let mut roles = category
+                .role_permissions
+                .iter()
+                .filter(|(id, _)| member_roles.contains(id))
+                .filter_map(|(id, permission)| {
+                    server.roles.get(id).map(|role| {
+                        let v: Override = (*permission).into();
+                        (role.rank, id.clone(), v)
+                    })
+                })
+                .collect::<Vec<(i64, String, Override)>>();
+
+            roles.sort_by(|a, b| b.0.cmp(&a.0));
+            roles.into_iter().map(|(_, id, v)| (id, v)).collect()
+        } else {
+            vec![]
+        }
+    }
+
+    /// Get the override for this member specifically in this channel
+    async fn get_our_channel_member_override(&mut self) -> Override {
+        self.resolve_parent_channel().await;
+
+        match self
+            .parent
+            .as_ref()
+            .or(self.channel.as_ref())
+            .map(|c| c.as_ref())
+        {
+            Some(
+                Channel::TextChannel {
+                    user_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    user_permissions, ..
+                },
+            ) => user_permissions
+                .get(&self.perspective.id)
+                .copied()
+                .map(Into::into)
+                .unwrap_or_default(),
+            _ => Default::default(),
+        }
+    }
+
     /// Do we own this group or saved messages channel if it is one of those?
     async fn do_we_own_the_channel(&mut self) -> bool {
         if let Some(channel) = &self.channel {
@@ -346,8 +449,16 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         if let Some(channel) = &self.channel {
             #[allow(deprecated)]
             match channel {
-                Cow::Borrowed(Channel::TextChannel { server, .. })
-                | Cow::Owned(Channel::TextChannel { server, .. }) => {
+                Cow::Borrowed(
+                    Channel::TextChannel { server, .. }
+                    | Channel::ForumChannel { server, .. }
+                    | Channel::Thread { server, .. },
+                )
+                | Cow::Owned(
+                    Channel::TextChannel { server, .. }
+                    | Channel::ForumChannel { server, .. }
+                    | Channel::Thread { server, .. },
+                ) => {
                     if let Some(known_server) =
                         // I'm not sure why I can't just pattern match both at once here?
                         // It throws some weird error and the provided fix doesn't work :/
@@ -383,6 +494,7 @@ impl<'a> DatabasePermissionQuery<'a> {
             perspective,
             user: None,
             channel: None,
+            parent: None,
             server: None,
             member: None,
 
@@ -429,6 +541,17 @@ impl<'a> DatabasePermissionQuery<'a> {
     pub fn channel(self, channel: &'a Channel) -> DatabasePermissionQuery<'a> {
         DatabasePermissionQuery {
             channel: Some(Cow::Borrowed(channel)),
+            parent: None,
+            ..self
+        }
+    }
+
+    /// Use parent channel
+    ///
+    /// Only applies to threads, otherwise it is fetched when required.
+    pub fn parent(self, parent: &'a Channel) -> DatabasePermissionQuery<'a> {
+        DatabasePermissionQuery {
+            parent: Some(Cow::Borrowed(parent)),
             ..self
         }
     }
@@ -469,6 +592,42 @@ impl<'a> DatabasePermissionQuery<'a> {
         &self.member
     }
 
+    /// Fetch the channel a thread was spawned in, as threads inherit its permissions
+    ///
+    /// Returns false if the channel is a thread and its parent could not be fetched.
+    async fn resolve_parent_channel(&mut self) -> bool {
+        if self.parent.is_some() {
+            return true;
+        }
+
+        if let Some(
+            Cow::Borrowed(Channel::Thread { parent, .. })
+            | Cow::Owned(Channel::Thread { parent, .. }),
+        ) = &self.channel
+        {
+            match self.database.fetch_channel(parent).await {
+                Ok(channel) => {
+                    self.parent.replace(Cow::Owned(channel));
+                }
+                Err(_) => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Find the category the channel, or a thread's parent, is placed in
+    fn category(&self) -> Option<&Category> {
+        let server = self.server.as_ref()?;
+        let channel_id = self.parent.as_ref().or(self.channel.as_ref())?.id();
+
+        server
+            .categories
+            .as_ref()?
+            .iter()
+            .find(|category| category.channels.iter().any(|id| id == channel_id))
+    }
+
     /// Get the known member's current ranking
     pub fn get_member_rank(&self) -> Option<i64> {
         self.member
@@ -4,6 +4,7 @@ use jxl_oxide::integration::JxlDecoder;
 use revolt_config::report_internal_error;
 use usvg::Transform;
 use std::io::{BufRead, Read, Seek};
+use std::process::Command;
 use tempfile::NamedTempFile;
 use tiny_skia::Pixmap;
 
@@ -200,6 +201,67 @@ impl MediaRepository for MediaImpl {
             None
         }
     }
+
+    fn audio_duration(&self, f: &NamedTempFile) -> Option<u64> {
+        let data = ffprobe::ffprobe(f.path())
+            .inspect_err(|err| tracing::error!("Failed to ffprobe file! {err:?}"))
+            .ok()?;
+
+        let seconds: f64 = data.format.duration?.parse().ok()?;
+        Some((seconds * 1000.0) as u64)
+    }
+
+    fn audio_waveform(&self, f: &NamedTempFile) -> Option<Vec<u8>> {
+        // Decode to mono 16-bit PCM, a low sample rate is plenty for peak detection
+        let output = Command::new("ffmpeg")
+            .args(["-v", "error", "-i"])
+            .arg(f.path())
+            .args(["-ac", "1", "-ar", "4000", "-f", "s16le", "-"])
+            .output()
+            .inspect_err(|err| tracing::error!("Failed to decode audio! {err:?}"))
+            .ok()?;
+
+        if !output.status.success() {
+            return None;
+        }
+
+        let samples: Vec<i16> = output
+            .stdout
+            .chunks_exact(2)
+            .map(|bytes| i16::from_le_bytes([bytes[0], bytes[1]]))
+            .collect();
+
+        waveform_from_samples(&samples)
+    }
+}
+
+/// Number of segments in an audio waveform
+pub const WAVEFORM_LENGTH: usize = 64;
+
+/// Summarise audio samples as the peak amplitude of each segment, scaled to 0-255
+pub fn waveform_from_samples(samples: &[i16]) -> Option<Vec<u8>> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let peaks: Vec<u32> = samples
+        .chunks(samples.len().div_ceil(WAVEFORM_LENGTH))
+        .map(|segment| {
+            segment
+                .iter()
+                .map(|sample| sample.unsigned_abs() as u32)
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let loudest = peaks.iter().copied().max().unwrap_or_default().max(1);
+    Some(
+        peaks
+            .into_iter()
+            .map(|peak| (peak * 255 / loudest) as u8)
+            .collect(),
+    )
 }
 
 #[cfg(test)]
@@ -400,4 +462,18 @@ mod tests {
             .unwrap();
         assert_eq!(media.is_animated(&f, "image/gif"), Some(true));
     }
+
+    #[test]
+    fn waveform_is_scaled_to_loudest_segment() {
+        let mut samples = vec![0i16; 6400];
+        samples[50] = -8000;
+        samples[6350] = 16000;
+
+        let waveform = crate::waveform_from_samples(&samples).unwrap();
+        assert_eq!(waveform.len(), crate::WAVEFORM_LENGTH);
+        assert_eq!(waveform[0], 127);
+        assert_eq!(waveform[63], 255);
+        assert_eq!(waveform[32], 0);
+        assert_eq!(crate::waveform_from_samples(&[]), None);
+    }
 }
@@ -3,5 +3,5 @@ mod media_impl;
 mod s3_impl;
 
 pub use encryption_impl::EncryptionKey;
-pub use media_impl::MediaImpl;
+pub use media_impl::{waveform_from_samples, MediaImpl, WAVEFORM_LENGTH};
 pub use s3_impl::S3Storage;
@@ -22,6 +22,10 @@ pub trait MediaRepository: Send + Sync + 'static {
     fn create_thumbnail(&self, image: DynamicImage, tag: &str) -> Vec<u8>;
 
     fn video_size(&self, f: &NamedTempFile) -> Option<(i64, i64)>;
+
+    fn audio_duration(&self, f: &NamedTempFile) -> Option<u64>;
+
+    fn audio_waveform(&self, f: &NamedTempFile) -> Option<Vec<u8>>;
 }
 
 #[derive(Debug, Error)]
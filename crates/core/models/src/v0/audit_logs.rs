@@ -1,4 +1,4 @@
-use crate::v0::{Member, PartialChannel, PartialEmoji, PartialMember, PartialRole, PartialServer, User};
+use crate::v0::{Category, Member, PartialChannel, PartialEmoji, PartialMember, PartialRole, PartialServer, User};
 use revolt_permissions::Override;
 
 auto_derived!(
@@ -62,6 +62,11 @@ auto_derived!(
             role: String,
             permissions: Override,
         },
+        ChannelUserPermissionsEdit {
+            channel: String,
+            user: String,
+            permissions: Option<Override>,
+        },
         ChannelDelete {
             channel: String,
             name: String,
@@ -126,6 +131,24 @@ auto_derived!(
             emoji: String,
             name: String,
         },
+        CategoryCreate {
+            category: String,
+            title: String,
+        },
+        CategoryEdit {
+            category: String,
+            before: Category,
+            after: Category,
+        },
+        CategoryRolePermissionsEdit {
+            category: String,
+            role: String,
+            permissions: Override,
+        },
+        CategoryDelete {
+            category: String,
+            title: String,
+        },
     }
 
     /// Audit log query filters
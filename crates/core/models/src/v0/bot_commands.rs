@@ -0,0 +1,248 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+#[cfg(feature = "validator")]
+use validator::{Validate, ValidationError};
+
+#[cfg(feature = "rocket")]
+use rocket::FromForm;
+
+use super::{Member, User};
+
+/// Regex for valid command and option names
+///
+/// Lowercase alphanumeric, underscores and dashes
+pub static RE_COMMAND_NAME: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9_-]+$").unwrap());
+
+/// Maximum number of options on a command
+pub const MAX_COMMAND_OPTIONS: usize = 25;
+
+/// Maximum number of choices on a command option
+pub const MAX_OPTION_CHOICES: usize = 25;
+
+auto_derived!(
+    /// Command which can be invoked on a bot
+    pub struct BotCommand {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the bot this command belongs to
+        pub bot: String,
+        /// Id of the server this command is limited to
+        ///
+        /// Global commands are available everywhere the bot is
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub server: Option<String>,
+        /// Command name
+        pub name: String,
+        /// Command description
+        pub description: String,
+        /// Options which can be passed to this command
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub options: Vec<CommandOption>,
+    }
+
+    /// Option which can be passed to a command
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct CommandOption {
+        /// Option name
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 1, max = 32), regex = "RE_COMMAND_NAME")
+        )]
+        pub name: String,
+        /// Option description
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub description: String,
+        /// Type of value this option takes
+        #[serde(rename = "type")]
+        pub option_type: CommandOptionType,
+        /// Whether this option must be given
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub required: bool,
+        /// Values which may be given, for string and integer options
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub choices: Option<Vec<CommandChoice>>,
+    }
+
+    /// Type of value a command option takes
+    pub enum CommandOptionType {
+        String,
+        Integer,
+        Boolean,
+        /// Id of a user
+        User,
+        /// Id of a channel in the same server
+        Channel,
+        /// Id of a role in the same server
+        Role,
+    }
+
+    /// Value which may be given for a command option
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct CommandChoice {
+        /// Name shown for this choice
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub name: String,
+        /// Value passed to the bot
+        pub value: CommandOptionValue,
+    }
+
+    /// Value of a command option
+    #[serde(untagged)]
+    pub enum CommandOptionValue {
+        Boolean(bool),
+        Integer(i64),
+        String(String),
+    }
+
+    /// Value given for a command option
+    pub struct CommandOptionData {
+        /// Option name
+        pub name: String,
+        /// Given value
+        pub value: CommandOptionValue,
+    }
+
+    /// Invocation of a command, delivered to the bot it belongs to
+    pub struct CommandInvocation {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the command
+        pub command: String,
+        /// Command name
+        pub name: String,
+        /// Id of the channel the command was invoked in
+        pub channel: String,
+        /// Options given
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub options: Vec<CommandOptionData>,
+        /// User who invoked the command
+        pub user: User,
+        /// Member who invoked the command, in server channels
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub member: Option<Member>,
+    }
+
+    /// New command details
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateCommand {
+        /// Command name
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 1, max = 32), regex = "RE_COMMAND_NAME")
+        )]
+        pub name: String,
+        /// Command description
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub description: String,
+        /// Options which can be passed to this command
+        #[serde(default)]
+        #[cfg_attr(feature = "validator", validate(custom = "validate_command_options"))]
+        pub options: Vec<CommandOption>,
+        /// Id of the server to limit this command to
+        ///
+        /// The bot must be a member of this server
+        pub server: Option<String>,
+    }
+
+    /// Changes to make to a command
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditCommand {
+        /// Command description
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub description: Option<String>,
+        /// Options which can be passed to this command
+        #[cfg_attr(feature = "validator", validate(custom = "validate_command_options"))]
+        pub options: Option<Vec<CommandOption>>,
+    }
+
+    /// Command to invoke
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataInvokeCommand {
+        /// Id of the bot the command belongs to
+        pub bot: String,
+        /// Command name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Options to pass to the command
+        #[serde(default)]
+        #[cfg_attr(feature = "validator", validate(length(max = 25)))]
+        pub options: Vec<CommandOptionData>,
+    }
+
+    /// Options for listing the commands available in a server
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsQueryCommands {
+        /// Only include commands whose name starts with this
+        pub prefix: Option<String>,
+    }
+);
+
+impl CommandOptionValue {
+    /// Check whether this value can be given for an option type
+    pub fn is_type(&self, option_type: &CommandOptionType) -> bool {
+        matches!(
+            (self, option_type),
+            (CommandOptionValue::Boolean(_), CommandOptionType::Boolean)
+                | (CommandOptionValue::Integer(_), CommandOptionType::Integer)
+                | (
+                    CommandOptionValue::String(_),
+                    CommandOptionType::String
+                        | CommandOptionType::User
+                        | CommandOptionType::Channel
+                        | CommandOptionType::Role
+                )
+        )
+    }
+}
+
+/// Validate the options of a command
+#[cfg(feature = "validator")]
+pub fn validate_command_options(options: &[CommandOption]) -> Result<(), ValidationError> {
+    if options.len() > MAX_COMMAND_OPTIONS {
+        return Err(ValidationError::new("too_many_options"));
+    }
+
+    for (index, option) in options.iter().enumerate() {
+        option
+            .validate()
+            .map_err(|_| ValidationError::new("invalid_option"))?;
+
+        if options[..index]
+            .iter()
+            .any(|other| other.name == option.name)
+        {
+            return Err(ValidationError::new("duplicate_option"));
+        }
+
+        // Required options must come before any optional ones
+        if option.required && index > 0 && !options[index - 1].required {
+            return Err(ValidationError::new("required_after_optional"));
+        }
+
+        if let Some(choices) = &option.choices {
+            if !matches!(
+                option.option_type,
+                CommandOptionType::String | CommandOptionType::Integer
+            ) || choices.is_empty()
+                || choices.len() > MAX_OPTION_CHOICES
+            {
+                return Err(ValidationError::new("invalid_choices"));
+            }
+
+            for choice in choices {
+                choice
+                    .validate()
+                    .map_err(|_| ValidationError::new("invalid_choices"))?;
+
+                if !choice.value.is_type(&option.option_type) {
+                    return Err(ValidationError::new("invalid_choices"));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
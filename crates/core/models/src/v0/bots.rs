@@ -1,5 +1,10 @@
 use super::User;
 
+#[cfg(feature = "validator")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+#[cfg(feature = "validator")]
+use validator::ValidationError;
+
 auto_derived!(
     /// Bot
     #[derive(Default)]
@@ -29,12 +34,22 @@ auto_derived!(
             serde(skip_serializing_if = "crate::if_false", default)
         )]
         pub discoverable: bool,
-        /// Reserved; URL for handling interactions
+        /// URL which component interactions are sent to, in addition to the event
+        ///
+        /// Each request carries a `X-Signature` header, the hex encoded
+        /// HMAC-SHA256 of `{timestamp}.{body}` keyed with the bot's interactions secret,
+        /// where timestamp is the value of the `X-Timestamp` header.
         #[cfg_attr(
             feature = "serde",
             serde(skip_serializing_if = "String::is_empty", default)
         )]
         pub interactions_url: String,
+        /// Secret used to sign requests sent to the interactions URL
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "String::is_empty", default)
+        )]
+        pub interactions_secret: String,
         /// URL for terms of service
         #[cfg_attr(
             feature = "serde",
@@ -60,6 +75,7 @@ auto_derived!(
     pub enum FieldsBot {
         Token,
         InteractionsURL,
+        InteractionsSecret,
     }
 
     /// Flags that may be attributed to a bot
@@ -128,8 +144,11 @@ auto_derived!(
         ///
         /// Must be enabled in order to show up on [Revolt Discover](https://rvlt.gg).
         pub analytics: Option<bool>,
-        /// Interactions URL
-        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2048)))]
+        /// HTTPS URL which component interactions are sent to
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 1, max = 2048), custom = "validate_public_https_url")
+        )]
         pub interactions_url: Option<String>,
         /// Fields to remove from bot object
         #[cfg_attr(feature = "serde", serde(default))]
@@ -170,3 +189,70 @@ auto_derived!(
         pub user: User,
     }
 );
+
+/// Check whether an IPv4 address is reachable on the public internet
+#[cfg(feature = "validator")]
+fn is_public_ipv4(ip: &Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // Carrier-grade NAT (100.64.0.0/10)
+        || (a == 100 && (b & 0b1100_0000) == 64))
+}
+
+/// Check whether an IPv6 address is reachable on the public internet
+#[cfg(feature = "validator")]
+fn is_public_ipv6(ip: &Ipv6Addr) -> bool {
+    if let Some(ip) = ip.to_ipv4_mapped() {
+        return is_public_ipv4(&ip);
+    }
+
+    let segment = ip.segments()[0];
+    !(ip.is_loopback()
+        || ip.is_unspecified()
+        // Unique local (fc00::/7)
+        || (segment & 0xfe00) == 0xfc00
+        // Link-local (fe80::/10)
+        || (segment & 0xffc0) == 0xfe80)
+}
+
+/// Check whether an IP address is reachable on the public internet
+#[cfg(feature = "validator")]
+pub fn is_public_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => is_public_ipv6(ip),
+    }
+}
+
+/// Validate that a URL uses HTTPS and points at a public host
+///
+/// Loopback, private and link-local addresses are refused so that
+/// requests made on behalf of bots cannot reach internal services.
+#[cfg(feature = "validator")]
+pub fn validate_public_https_url(url: &str) -> Result<(), ValidationError> {
+    let url = url::Url::parse(url).map_err(|_| ValidationError::new("invalid_url"))?;
+    if url.scheme() != "https" {
+        return Err(ValidationError::new("https_required"));
+    }
+
+    let public = match url.host() {
+        Some(url::Host::Domain(domain)) => {
+            let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+            !domain.is_empty() && domain != "localhost" && !domain.ends_with(".localhost")
+        }
+        Some(url::Host::Ipv4(ip)) => is_public_ipv4(&ip),
+        Some(url::Host::Ipv6(ip)) => is_public_ipv6(&ip),
+        None => false,
+    };
+
+    if public {
+        Ok(())
+    } else {
+        Err(ValidationError::new("public_host_required"))
+    }
+}
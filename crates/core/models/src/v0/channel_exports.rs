@@ -0,0 +1,37 @@
+use super::File;
+
+auto_derived!(
+    /// Export of a channel's message history
+    pub struct ChannelExport {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the channel being exported
+        pub channel: String,
+        /// Id of the user who requested this export
+        pub requester: String,
+        /// Progress of this export
+        pub status: ChannelExportStatus,
+        /// Number of messages included in the export
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub message_count: Option<usize>,
+        /// Transcript as a JSON document
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub json: Option<File>,
+        /// Transcript as a self-contained HTML page
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub html: Option<File>,
+    }
+
+    /// Progress of a channel export
+    pub enum ChannelExportStatus {
+        /// Waiting to be processed
+        Pending,
+        /// Messages are being exported
+        Processing,
+        /// Transcripts are ready to download
+        Complete,
+        /// Export could not be completed
+        Failed,
+    }
+);
@@ -24,4 +24,12 @@ auto_derived!(
         /// User Id
         pub user: String,
     }
+
+    /// Read receipt of a recipient in a direct message or group
+    pub struct ChannelReceipt {
+        /// User Id
+        pub user: String,
+        /// Id of the last message read by this user
+        pub message_id: String,
+    }
 );
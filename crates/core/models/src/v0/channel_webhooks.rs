@@ -27,6 +27,10 @@ auto_derived_partial!(
 
         /// The private token for the webhook
         pub token: Option<String>,
+
+        /// Announcement channel this webhook follows
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub source_channel_id: Option<String>,
     },
     "PartialWebhook"
 );
@@ -92,6 +96,12 @@ auto_derived!(
         #[validate(length(min = 1, max = 128))]
         pub avatar: Option<String>,
     }
+
+    /// Channel to deliver published announcements into
+    pub struct DataFollowChannel {
+        /// Id of the server text channel which should receive announcements
+        pub channel_id: String,
+    }
 );
 
 impl From<Webhook> for MessageWebhook {
@@ -1,11 +1,11 @@
 #![allow(deprecated)]
-use super::{File, UserVoiceState};
+use super::{DataMessageSend, File, UserVoiceState};
 
-use revolt_permissions::{Override, OverrideField};
+use revolt_permissions::{Override, OverrideField, PermissionStep};
 use std::collections::{HashMap, HashSet};
 
 #[cfg(feature = "rocket")]
-use rocket::FromForm;
+use rocket::{FromForm, FromFormField};
 
 auto_derived!(
     /// Channel
@@ -32,6 +32,9 @@ auto_derived!(
             /// Id of the last message sent in this channel
             #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
             last_message_id: Option<String>,
+            /// Time in seconds after which messages sent in this channel expire
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            message_ttl: Option<u64>,
         },
         /// Group channel between 1 or more participants
         Group {
@@ -67,6 +70,10 @@ auto_derived!(
                 serde(skip_serializing_if = "crate::if_false", default)
             )]
             nsfw: bool,
+
+            /// Time in seconds after which messages sent in this channel expire
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            message_ttl: Option<u64>,
         },
         /// Text channel belonging to a server
         TextChannel {
@@ -101,6 +108,15 @@ auto_derived!(
                 )
             )]
             role_permissions: HashMap<String, OverrideField>,
+            /// Permissions assigned to specific members of this channel
+            #[cfg_attr(
+                feature = "serde",
+                serde(
+                    default = "HashMap::<String, OverrideField>::new",
+                    skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+                )
+            )]
+            user_permissions: HashMap<String, OverrideField>,
 
             /// Whether this channel is marked as not safe for work
             #[cfg_attr(
@@ -116,7 +132,146 @@ auto_derived!(
             /// The channel's slowmode delay in seconds
             #[serde(skip_serializing_if = "Option::is_none")]
             slowmode: Option<u64>,
+            /// Time in seconds after which messages sent in this channel expire
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            message_ttl: Option<u64>,
+
+            /// Whether this is an announcement channel which other servers can follow
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            announcement: bool,
         },
+        /// Forum channel belonging to a server, each post is a thread
+        ForumChannel {
+            /// Unique Id
+            #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+            id: String,
+            /// Id of the server this channel belongs to
+            server: String,
+
+            /// Display name of the channel
+            name: String,
+            /// Channel description
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            description: Option<String>,
+
+            /// Custom icon attachment
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            icon: Option<File>,
+
+            /// Default permissions assigned to users in this channel
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            default_permissions: Option<OverrideField>,
+            /// Permissions assigned based on role to this channel
+            #[cfg_attr(
+                feature = "serde",
+                serde(
+                    default = "HashMap::<String, OverrideField>::new",
+                    skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+                )
+            )]
+            role_permissions: HashMap<String, OverrideField>,
+            /// Permissions assigned to specific members of this channel
+            #[cfg_attr(
+                feature = "serde",
+                serde(
+                    default = "HashMap::<String, OverrideField>::new",
+                    skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+                )
+            )]
+            user_permissions: HashMap<String, OverrideField>,
+
+            /// Whether this channel is marked as not safe for work
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            nsfw: bool,
+
+            /// Tags which can be applied to posts
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            tags: Vec<ForumTag>,
+            /// Order posts are listed in by default
+            #[cfg_attr(feature = "serde", serde(default))]
+            default_sort: ForumSortOrder,
+            /// Whether posts must have at least one tag applied
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            require_tag: bool,
+            /// Time in seconds after which messages sent in posts in this channel expire
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            message_ttl: Option<u64>,
+        },
+        /// Thread spawned from a message in a server channel or a post in a forum
+        Thread {
+            /// Unique Id
+            #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+            id: String,
+            /// Id of the server this thread belongs to
+            server: String,
+            /// Id of the channel this thread was spawned in
+            parent: String,
+            /// Id of the message this thread was spawned from
+            ///
+            /// Forum posts are not spawned from a message
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            message: Option<String>,
+
+            /// Display name of the thread
+            name: String,
+            /// User id of the creator of the thread
+            owner: String,
+            /// Array of user ids following this thread
+            #[cfg_attr(feature = "serde", serde(default))]
+            members: Vec<String>,
+
+            /// Id of the last message sent in this thread
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            last_message_id: Option<String>,
+
+            /// Whether this thread has been archived
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            archived: bool,
+
+            /// Ids of the forum tags applied to this post
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            applied_tags: Vec<String>,
+        },
+    }
+
+    /// Tag which can be applied to posts in a forum
+    pub struct ForumTag {
+        /// Unique Id
+        pub id: String,
+        /// Tag name
+        pub name: String,
+        /// Emoji shown alongside the tag
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub emoji: Option<String>,
+    }
+
+    /// Order in which forum posts are listed
+    #[derive(Default)]
+    #[cfg_attr(feature = "rocket", derive(FromFormField))]
+    pub enum ForumSortOrder {
+        /// Most recently active posts first
+        #[default]
+        LatestActivity,
+        /// Most recently created posts first
+        Creation,
     }
 
     /// Voice information for a channel
@@ -149,6 +304,8 @@ auto_derived!(
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub role_permissions: Option<HashMap<String, OverrideField>>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub user_permissions: Option<HashMap<String, OverrideField>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub default_permissions: Option<OverrideField>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub last_message_id: Option<String>,
@@ -156,6 +313,20 @@ auto_derived!(
         pub voice: Option<VoiceInformation>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub slowmode: Option<u64>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub message_ttl: Option<u64>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub announcement: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub archived: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub tags: Option<Vec<ForumTag>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_sort: Option<ForumSortOrder>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub require_tag: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub applied_tags: Option<Vec<String>>,
     }
 
     /// Optional fields on channel object
@@ -165,6 +336,7 @@ auto_derived!(
         DefaultPermissions,
         Voice,
         Slowmode,
+        MessageTtl,
     }
 
     /// New webhook information
@@ -200,6 +372,19 @@ auto_derived!(
         #[cfg_attr(feature = "validator", validate(range(min = 0, max = 21600)))]
         pub slowmode: Option<u64>,
 
+        /// Time in seconds after which new messages in this channel expire, up to 30 days
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 2592000)))]
+        pub message_ttl: Option<u64>,
+
+        /// Whether other servers can follow this channel, only for server text channels
+        pub announcement: Option<bool>,
+
+        /// Order posts are listed in by default for forum channels
+        pub default_sort: Option<ForumSortOrder>,
+
+        /// Whether posts must have a tag applied for forum channels
+        pub require_tag: Option<bool>,
+
         /// Fields to remove from channel
         #[cfg_attr(feature = "serde", serde(default))]
         pub remove: Vec<FieldsChannel>,
@@ -237,6 +422,8 @@ auto_derived!(
         Text,
         /// Voice Channel
         Voice,
+        /// Forum Channel
+        Forum,
     }
 
     /// Create new server channel
@@ -261,6 +448,63 @@ auto_derived!(
         pub voice: Option<VoiceInformation>,
     }
 
+    /// Create new thread
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateThread {
+        /// Thread name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+    }
+
+    /// Changes to make to a thread
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditThread {
+        /// Thread name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: Option<String>,
+        /// Whether this thread is archived
+        pub archived: Option<bool>,
+        /// Ids of the forum tags to apply, only for forum posts
+        pub applied_tags: Option<Vec<String>>,
+    }
+
+    /// Create new forum post
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateForumPost {
+        /// Post title
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: String,
+        /// Ids of the forum tags to apply
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub applied_tags: Vec<String>,
+        /// Opening message of the post
+        #[cfg_attr(feature = "validator", validate)]
+        pub message: DataMessageSend,
+    }
+
+    /// Create new forum tag
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateForumTag {
+        /// Tag name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Emoji shown alongside the tag
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub emoji: Option<String>,
+    }
+
+    /// Options when fetching forum posts
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsFetchForumPosts {
+        /// Order to list posts in, defaults to the forum's default order
+        pub sort: Option<ForumSortOrder>,
+        /// Only include posts with this tag applied
+        pub tag: Option<String>,
+        /// Whether to include archived posts
+        pub include_archived: Option<bool>,
+    }
+
     /// New default permissions
     #[serde(untagged)]
     pub enum DataDefaultChannelPermissions {
@@ -280,6 +524,33 @@ auto_derived!(
         pub permissions: Override,
     }
 
+    /// New member permissions
+    pub struct DataSetMemberPermissions {
+        /// Allow / deny values to set for this member
+        pub permissions: Override,
+    }
+
+    /// Options when explaining channel permissions
+    #[cfg_attr(feature = "rocket", derive(FromForm))]
+    pub struct OptionsExplainPermissions {
+        /// Id of the member to calculate permissions for, defaults to yourself
+        pub user: Option<String>,
+        /// Role ids to calculate permissions with instead of the member's own
+        ///
+        /// If no user is given, this previews the channel as a new member with these roles.
+        pub roles: Option<Vec<String>>,
+    }
+
+    /// Breakdown of how permissions in a channel were calculated
+    pub struct PermissionExplanation {
+        /// Resulting permission value
+        pub permissions: u64,
+        /// Role ids which were considered, from lowest to highest ranking
+        pub roles: Vec<String>,
+        /// Steps taken to reach the resulting value
+        pub steps: Vec<PermissionStep>,
+    }
+
     /// Options when deleting a channel
     #[cfg_attr(feature = "rocket", derive(FromForm))]
     pub struct OptionsChannelDelete {
@@ -330,7 +601,9 @@ impl Channel {
             Channel::DirectMessage { id, .. }
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
-            | Channel::TextChannel { id, .. } => id,
+            | Channel::TextChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::Thread { id, .. } => id,
         }
     }
 
@@ -342,7 +615,10 @@ impl Channel {
         match self {
             Channel::DirectMessage { .. } => None,
             Channel::SavedMessages { .. } => Some("Saved Messages"),
-            Channel::TextChannel { name, .. } | Channel::Group { name, .. } => Some(name),
+            Channel::TextChannel { name, .. }
+            | Channel::ForumChannel { name, .. }
+            | Channel::Group { name, .. }
+            | Channel::Thread { name, .. } => Some(name),
         }
     }
 }
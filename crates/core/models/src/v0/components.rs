@@ -0,0 +1,288 @@
+#[cfg(feature = "validator")]
+use validator::{Validate, ValidationError};
+
+use super::{DataEditMessage, Member, User};
+
+/// Maximum number of action rows on a message
+pub const MAX_ACTION_ROWS: usize = 5;
+
+/// Maximum number of components in an action row
+pub const MAX_ROW_COMPONENTS: usize = 5;
+
+/// Maximum number of options in a select menu
+pub const MAX_SELECT_OPTIONS: usize = 25;
+
+auto_derived!(
+    /// Row of interactive components shown beneath a message
+    pub struct ActionRow {
+        /// Components in this row
+        ///
+        /// Either up to 5 buttons or a single select menu
+        pub components: Vec<Component>,
+    }
+
+    /// Interactive component
+    #[serde(tag = "type")]
+    pub enum Component {
+        /// Clickable button
+        Button(Button),
+        /// Dropdown menu of options
+        Select(SelectMenu),
+    }
+
+    /// Clickable button
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct Button {
+        /// Id used to identify this button in interactions
+        ///
+        /// Required unless this is a link button
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub id: Option<String>,
+        /// Appearance of this button
+        #[serde(default)]
+        pub style: ButtonStyle,
+        /// Text shown on this button
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 80)))]
+        pub label: Option<String>,
+        /// Unicode emoji or custom emoji id shown on this button
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub emoji: Option<String>,
+        /// URL to open, only for link buttons
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 512)))]
+        pub url: Option<String>,
+        /// Whether this button can't be clicked
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub disabled: bool,
+    }
+
+    /// Appearance of a button
+    #[derive(Default)]
+    pub enum ButtonStyle {
+        Primary,
+        #[default]
+        Secondary,
+        Success,
+        Danger,
+        /// Opens a URL instead of sending an interaction
+        Link,
+    }
+
+    /// Dropdown menu of options
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct SelectMenu {
+        /// Id used to identify this menu in interactions
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub id: String,
+        /// Text shown when nothing is selected
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 150)))]
+        pub placeholder: Option<String>,
+        /// Options which can be selected
+        #[cfg_attr(feature = "validator", validate)]
+        pub options: Vec<SelectOption>,
+        /// Minimum number of options which must be selected
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min_values: Option<usize>,
+        /// Maximum number of options which may be selected
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max_values: Option<usize>,
+        /// Whether this menu can't be used
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub disabled: bool,
+    }
+
+    /// Option in a select menu
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct SelectOption {
+        /// Text shown for this option
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub label: String,
+        /// Value sent in interactions when this option is selected
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub value: String,
+        /// Additional text shown beneath the label
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub description: Option<String>,
+        /// Unicode emoji or custom emoji id shown beside this option
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub emoji: Option<String>,
+    }
+
+    /// Use of a component, delivered to the bot which sent the message
+    pub struct Interaction {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel the message is in
+        pub channel: String,
+        /// Id of the message the component is attached to
+        pub message: String,
+        /// Id of the component which was used
+        pub component_id: String,
+        /// Values chosen, for select menus
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub values: Vec<String>,
+        /// User who used the component
+        pub user: User,
+        /// Member who used the component, in server channels
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub member: Option<Member>,
+    }
+
+    /// Use of a component
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataInteract {
+        /// Id of the component
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub component_id: String,
+        /// Values chosen, for select menus
+        #[serde(default)]
+        #[cfg_attr(feature = "validator", validate(length(max = 25)))]
+        pub values: Vec<String>,
+    }
+
+    /// Bot response to an interaction
+    #[serde(tag = "type")]
+    pub enum DataInteractionResponse {
+        /// Edit the message the component is attached to
+        UpdateMessage(DataEditMessage),
+        /// Reply with a message only the interacting user can see
+        EphemeralReply(DataEphemeralReply),
+    }
+
+    /// Message only visible to the user who interacted
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEphemeralReply {
+        /// Message content
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+    }
+);
+
+impl Component {
+    /// Id used to identify this component in interactions
+    pub fn id(&self) -> Option<&str> {
+        match self {
+            Component::Button(button) => button.id.as_deref(),
+            Component::Select(menu) => Some(&menu.id),
+        }
+    }
+
+    /// Whether this component can't be used
+    pub fn disabled(&self) -> bool {
+        match self {
+            Component::Button(button) => button.disabled,
+            Component::Select(menu) => menu.disabled,
+        }
+    }
+}
+
+impl SelectMenu {
+    /// Check that the chosen values are options of this menu and within its limits
+    pub fn accepts(&self, values: &[String]) -> bool {
+        let min = self.min_values.unwrap_or(1);
+        let max = self.max_values.unwrap_or(1);
+
+        values.len() >= min
+            && values.len() <= max
+            && values
+                .iter()
+                .all(|value| self.options.iter().any(|option| &option.value == value))
+            && values
+                .iter()
+                .enumerate()
+                .all(|(index, value)| !values[..index].contains(value))
+    }
+}
+
+/// Find a component by its id within action rows
+pub fn find_component<'a>(rows: &'a [ActionRow], id: &str) -> Option<&'a Component> {
+    rows.iter()
+        .flat_map(|row| &row.components)
+        .find(|component| component.id() == Some(id))
+}
+
+#[cfg(feature = "validator")]
+impl Validate for DataInteractionResponse {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            DataInteractionResponse::UpdateMessage(edit) => edit.validate(),
+            DataInteractionResponse::EphemeralReply(reply) => reply.validate(),
+        }
+    }
+}
+
+/// Validate the layout and limits of components attached to a message
+#[cfg(feature = "validator")]
+pub fn validate_components(rows: &[ActionRow]) -> Result<(), ValidationError> {
+    if rows.len() > MAX_ACTION_ROWS {
+        return Err(ValidationError::new("too_many_rows"));
+    }
+
+    let mut ids: Vec<&str> = vec![];
+    for row in rows {
+        let selects = row
+            .components
+            .iter()
+            .filter(|component| matches!(component, Component::Select(_)))
+            .count();
+
+        if row.components.is_empty()
+            || row.components.len() > MAX_ROW_COMPONENTS
+            || (selects > 0 && row.components.len() > 1)
+        {
+            return Err(ValidationError::new("invalid_row"));
+        }
+
+        for component in &row.components {
+            match component {
+                Component::Button(button) => {
+                    button
+                        .validate()
+                        .map_err(|_| ValidationError::new("invalid_button"))?;
+
+                    // Link buttons open a URL, all others send an interaction
+                    let valid = match button.style {
+                        ButtonStyle::Link => button.url.is_some() && button.id.is_none(),
+                        _ => button.id.is_some() && button.url.is_none(),
+                    };
+
+                    if !valid || (button.label.is_none() && button.emoji.is_none()) {
+                        return Err(ValidationError::new("invalid_button"));
+                    }
+                }
+                Component::Select(menu) => {
+                    menu.validate()
+                        .map_err(|_| ValidationError::new("invalid_select"))?;
+
+                    let min = menu.min_values.unwrap_or(1);
+                    let max = menu.max_values.unwrap_or(1);
+                    if menu.options.is_empty()
+                        || menu.options.len() > MAX_SELECT_OPTIONS
+                        || min > max
+                        || max == 0
+                        || max > menu.options.len()
+                    {
+                        return Err(ValidationError::new("invalid_select"));
+                    }
+                }
+            }
+
+            if let Some(id) = component.id() {
+                if ids.contains(&id) {
+                    return Err(ValidationError::new("duplicate_component_id"));
+                }
+
+                ids.push(id);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "validator")]
+use validator::{Validate, ValidationError};
+
+#[cfg(feature = "validator")]
+use super::validate_public_https_url;
+
+/// Maximum number of event types a subscription can be filtered to
+pub const MAX_SUBSCRIPTION_EVENTS: usize = 64;
+
+auto_derived!(
+    /// HTTP endpoint which a bot's events are delivered to
+    pub struct EventSubscription {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the bot this subscription belongs to
+        pub bot: String,
+        /// HTTPS endpoint events are sent to
+        pub url: String,
+        /// Secret used to sign requests
+        ///
+        /// Each request carries a `X-Signature` header, the hex encoded
+        /// HMAC-SHA256 of `{timestamp}.{body}` keyed with this secret,
+        /// where timestamp is the value of the `X-Timestamp` header.
+        pub secret: String,
+        /// Event types to deliver, such as `Message` or `ServerMemberJoin`
+        pub events: Vec<String>,
+        /// Whether delivery has been stopped after repeated failures
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub disabled: bool,
+    }
+
+    /// New event subscription details
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateEventSubscription {
+        /// Public HTTPS endpoint to send events to
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 1, max = 2048), custom = "validate_public_https_url")
+        )]
+        pub url: String,
+        /// Event types to deliver
+        #[cfg_attr(feature = "validator", validate(custom = "validate_event_types"))]
+        pub events: Vec<String>,
+    }
+
+    /// Changes to make to an event subscription
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditEventSubscription {
+        /// Public HTTPS endpoint to send events to
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 1, max = 2048), custom = "validate_public_https_url")
+        )]
+        pub url: Option<String>,
+        /// Event types to deliver
+        #[cfg_attr(feature = "validator", validate(custom = "validate_event_types"))]
+        pub events: Option<Vec<String>>,
+        /// Whether to resume delivery to a disabled endpoint
+        pub enabled: Option<bool>,
+        /// Whether to generate a new secret
+        #[serde(default)]
+        pub regenerate_secret: bool,
+    }
+);
+
+/// Validate a list of event types to subscribe to
+#[cfg(feature = "validator")]
+pub fn validate_event_types(events: &[String]) -> Result<(), ValidationError> {
+    if events.is_empty() || events.len() > MAX_SUBSCRIPTION_EVENTS {
+        return Err(ValidationError::new("invalid_event_count"));
+    }
+
+    if events
+        .iter()
+        .any(|event| event.is_empty() || event.len() > 64)
+    {
+        return Err(ValidationError::new("invalid_event_type"));
+    }
+
+    Ok(())
+}
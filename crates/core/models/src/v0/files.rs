@@ -22,6 +22,16 @@ auto_derived!(
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub reported: Option<bool>,
 
+        /// Description of this file for accessibility
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Whether this file should be hidden until clicked
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_option_false")
+        )]
+        pub spoiler: Option<bool>,
+
         // TODO: migrate this mess to having:
         // - author_id
         // - parent: Parent { Message(id), User(id), etc }
@@ -56,6 +66,11 @@ auto_derived!(
         /// File is a video with specific dimensions
         Video { width: usize, height: usize },
         /// File is audio
-        Audio,
+        Audio {
+            /// Length of the audio in milliseconds
+            duration: Option<u64>,
+            /// Peak amplitude of evenly spaced segments of the audio, scaled to 0-255
+            waveform: Option<Vec<u8>>,
+        },
     }
 );
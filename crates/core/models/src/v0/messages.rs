@@ -11,7 +11,12 @@ use rocket::{FromForm, FromFormField};
 
 use iso8601_timestamp::Timestamp;
 
-use super::{Channel, Embed, File, Member, MessageWebhook, User, Webhook, RE_COLOUR};
+use super::{
+    ActionRow, Channel, Embed, File, Member, MessageWebhook, Metadata, User, Webhook, RE_COLOUR,
+};
+
+#[cfg(feature = "validator")]
+use super::validate_components;
 
 auto_derived_partial!(
     /// Message
@@ -65,12 +70,27 @@ auto_derived_partial!(
         /// Information about how this message should be interacted with
         #[serde(skip_serializing_if = "Interactions::is_default", default)]
         pub interactions: Interactions,
+        /// Buttons and select menus attached to this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub components: Option<Vec<ActionRow>>,
         /// Name and / or avatar overrides for this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub masquerade: Option<Masquerade>,
         /// Whether or not the message in pinned
         #[serde(skip_serializing_if = "crate::if_option_false")]
         pub pinned: Option<bool>,
+        /// Summary of the thread spawned from this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub thread: Option<ThreadSummary>,
+        /// Poll attached to this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub poll: Option<Poll>,
+        /// Message this message was forwarded from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub forward: Option<MessageForward>,
+        /// Time at which this message will be deleted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
 
         /// Bitfield of message flags
         ///
@@ -137,6 +157,12 @@ auto_derived!(
             by: String,
             finished_at: Option<Timestamp>,
         },
+        #[serde(rename = "poll_closed")]
+        PollClosed {
+            id: String,
+            question: String,
+            results: Vec<PollResult>,
+        },
     }
 
     /// Name and / or avatar override information
@@ -202,6 +228,9 @@ auto_derived!(
         /// URL to first matching attachment
         #[serde(skip_serializing_if = "Option::is_none")]
         pub image: Option<String>,
+        /// Description of the attachment used for the image
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub image_description: Option<String>,
         /// Message content or system message information
         pub body: String,
         /// The raw body, if the body has been rendered
@@ -251,6 +280,27 @@ auto_derived!(
         pub fail_if_not_exists: Option<bool>,
     }
 
+    /// Attachment to include in a message
+    #[serde(untagged)]
+    pub enum SendableAttachment {
+        /// Id of an uploaded file
+        Id(String),
+        /// Uploaded file with additional information
+        Detailed(AttachmentIntent),
+    }
+
+    /// Uploaded file with additional information
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct AttachmentIntent {
+        /// Id of an uploaded file
+        pub id: String,
+        /// Description of the file for accessibility
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1024)))]
+        pub description: Option<String>,
+        /// Whether the file should be hidden until clicked
+        pub spoiler: Option<bool>,
+    }
+
     /// Message to send
     #[cfg_attr(feature = "validator", derive(Validate))]
     pub struct DataMessageSend {
@@ -264,7 +314,8 @@ auto_derived!(
         #[cfg_attr(feature = "validator", validate(length(min = 0)))]
         pub content: Option<String>,
         /// Attachments to include in message
-        pub attachments: Option<Vec<String>>,
+        #[cfg_attr(feature = "validator", validate)]
+        pub attachments: Option<Vec<SendableAttachment>>,
         /// Messages to reply to
         pub replies: Option<Vec<ReplyIntent>>,
         /// Embeds to include in message
@@ -277,6 +328,19 @@ auto_derived!(
         pub masquerade: Option<Masquerade>,
         /// Information about how this message should be interacted with
         pub interactions: Option<Interactions>,
+        /// Buttons and select menus to attach to this message
+        ///
+        /// Only bots may attach components
+        #[cfg_attr(feature = "validator", validate(custom = "validate_components"))]
+        pub components: Option<Vec<ActionRow>>,
+        /// Poll to attach to this message
+        #[cfg_attr(feature = "validator", validate)]
+        pub poll: Option<DataPoll>,
+        /// Time in seconds after which this message should be deleted, up to 30 days
+        ///
+        /// Defaults to the channel's message expiry, if one is set
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 2592000)))]
+        pub expires_in: Option<u64>,
 
         /// Bitfield of message flags
         ///
@@ -284,6 +348,75 @@ auto_derived!(
         pub flags: Option<u32>,
     }
 
+    /// Poll to create alongside a message
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataPoll {
+        /// Question being asked
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 300)))]
+        pub question: String,
+        /// Options which can be voted for
+        #[cfg_attr(feature = "validator", validate)]
+        pub options: Vec<DataPollOption>,
+        /// Whether users may vote for more than one option
+        #[serde(default)]
+        pub multi_select: bool,
+        /// Whether voters should be hidden from everyone but moderators
+        #[serde(default)]
+        pub anonymous: bool,
+        /// Number of seconds after which the poll closes
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 2592000)))]
+        pub duration: Option<u64>,
+    }
+
+    /// Option to include in a new poll
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataPollOption {
+        /// Option text
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub text: String,
+    }
+
+    /// Message scheduled to be sent at a later time
+    pub struct ScheduledMessage {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel this message will be sent in
+        pub channel: String,
+        /// Id of the user who scheduled this message
+        pub author: String,
+        /// Message to send
+        pub data: DataMessageSend,
+        /// Time at which this message will be sent
+        pub send_at: Timestamp,
+        /// Type of the error which stopped this message from being sent
+        ///
+        /// Temporary errors are retried automatically, failed messages are
+        /// not sent until they are edited.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub failure: Option<String>,
+    }
+
+    /// Message to schedule
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataScheduleMessage {
+        /// Message to send
+        #[cfg_attr(feature = "validator", validate)]
+        pub message: DataMessageSend,
+        /// Time at which the message should be sent
+        pub send_at: Timestamp,
+    }
+
+    /// Changes to make to a scheduled message
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataEditScheduledMessage {
+        /// New message to send
+        #[cfg_attr(feature = "validator", validate)]
+        pub message: Option<DataMessageSend>,
+        /// New time at which the message should be sent
+        pub send_at: Option<Timestamp>,
+    }
+
     /// Options for querying messages
     #[cfg_attr(feature = "validator", derive(Validate))]
     #[cfg_attr(feature = "rocket", derive(FromForm))]
@@ -317,8 +450,9 @@ auto_derived!(
     pub struct DataMessageSearch {
         /// Full-text search query
         ///
-        /// See [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator) for more information.
-        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 64)))]
+        /// Supports the operators `from:`, `mentions:`, `has:`, `before:`, `after:` and `in:`,
+        /// remaining text is matched as described in the [MongoDB documentation](https://docs.mongodb.com/manual/text-search/#-text-operator).
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 256)))]
         pub query: Option<String>,
         /// Whether to only search for pinned messages, cannot be sent with `query`.
         pub pinned: Option<bool>,
@@ -341,6 +475,12 @@ auto_derived!(
         pub include_users: Option<bool>,
     }
 
+    /// Message forward information
+    pub struct DataForwardMessage {
+        /// Id of the channel to forward the message into
+        pub channel: String,
+    }
+
     /// Changes to make to message
     #[cfg_attr(feature = "validator", derive(Validate))]
     pub struct DataEditMessage {
@@ -350,6 +490,14 @@ auto_derived!(
         /// Embeds to include in the message
         #[cfg_attr(feature = "validator", validate(length(min = 0, max = 10)))]
         pub embeds: Option<Vec<SendableEmbed>>,
+        /// Descriptions and spoiler flags to change on existing attachments
+        #[cfg_attr(feature = "validator", validate)]
+        pub attachments: Option<Vec<AttachmentIntent>>,
+        /// Buttons and select menus to replace existing components with
+        ///
+        /// Only bots may attach components
+        #[cfg_attr(feature = "validator", validate(custom = "validate_components"))]
+        pub components: Option<Vec<ActionRow>>,
     }
 
     /// Options for bulk deleting messages
@@ -382,11 +530,127 @@ auto_derived!(
         /// Message will mention all users who are online and can see the channel.
         /// This cannot be true if MentionsEveryone is true
         MentionsOnline = 3,
+        /// Message has been published to channels following this announcement channel
+        Published = 4,
+        /// Message is a voice message, consisting of a single audio attachment
+        VoiceMessage = 5,
+        /// Message is only visible to a single user and is not stored
+        Ephemeral = 6,
+    }
+
+    /// Summary of a thread spawned from a message
+    pub struct ThreadSummary {
+        /// Id of the thread channel
+        pub id: String,
+        /// Number of messages sent in the thread
+        pub reply_count: u64,
+        /// Id of the last message sent in the thread
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub last_message_id: Option<String>,
+    }
+
+    /// Snapshot of a message forwarded from another channel
+    pub struct MessageForward {
+        /// Id of the original message
+        pub id: String,
+        /// Id of the channel the original message was sent in
+        pub channel: String,
+        /// Id of the user or webhook that sent the original message
+        pub author: String,
+        /// Time at which the original message was sent
+        pub timestamp: Timestamp,
+        /// Content of the original message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Attachments on the original message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub attachments: Option<Vec<ForwardedAttachment>>,
+    }
+
+    /// Snapshot of an attachment on a forwarded message
+    pub struct ForwardedAttachment {
+        /// Id of the file
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Tag / bucket the file was uploaded to
+        pub tag: String,
+        /// Original filename
+        pub filename: String,
+        /// Parsed metadata of the file
+        pub metadata: Metadata,
+        /// Raw content type of the file
+        pub content_type: String,
+        /// Size of the file (in bytes)
+        pub size: isize,
+        /// Description of the file for accessibility
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Whether the file should be hidden until clicked
+        #[serde(skip_serializing_if = "crate::if_option_false")]
+        pub spoiler: Option<bool>,
+    }
+
+    /// Poll attached to a message
+    pub struct Poll {
+        /// Question being asked
+        pub question: String,
+        /// Options which can be voted for
+        pub options: Vec<PollOption>,
+        /// Whether users may vote for more than one option
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub multi_select: bool,
+        /// Whether voters are hidden from everyone but moderators
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub anonymous: bool,
+        /// Time at which this poll closes
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires_at: Option<Timestamp>,
+        /// Whether this poll has been closed
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub closed: bool,
+    }
+
+    /// Option on a poll
+    pub struct PollOption {
+        /// Option Id
+        pub id: String,
+        /// Option text
+        pub text: String,
+        /// Number of votes cast for this option
+        pub votes: u64,
+        /// Ids of users who voted for this option
+        ///
+        /// Omitted for anonymous polls
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub voters: Option<Vec<String>>,
+    }
+
+    /// Final tally for an option on a closed poll
+    pub struct PollResult {
+        /// Option Id
+        pub id: String,
+        /// Option text
+        pub text: String,
+        /// Number of votes cast for this option
+        pub votes: u64,
+    }
+
+    /// Prior version of a message before it was edited
+    pub struct MessageRevision {
+        /// Message content
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub content: Option<String>,
+        /// Attached embeds
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub embeds: Option<Vec<Embed>>,
+        /// Time at which this version was replaced
+        pub timestamp: Timestamp,
     }
 
     /// Optional fields on message
     pub enum FieldsMessage {
         Pinned,
+        Thread,
     }
 );
 
@@ -455,6 +719,43 @@ impl From<SystemMessage> for String {
             SystemMessage::MessagePinned { .. } => "Message pinned.".to_string(),
             SystemMessage::MessageUnpinned { .. } => "Message unpinned.".to_string(),
             SystemMessage::CallStarted { .. } => "Call started.".to_string(),
+            SystemMessage::PollClosed { question, .. } => format!("Poll closed: {question}"),
+        }
+    }
+}
+
+impl SendableAttachment {
+    /// Id of the uploaded file
+    pub fn id(&self) -> &str {
+        match self {
+            SendableAttachment::Id(id) => id,
+            SendableAttachment::Detailed(intent) => &intent.id,
+        }
+    }
+
+    /// Description to apply to the file
+    pub fn description(&self) -> Option<&str> {
+        match self {
+            SendableAttachment::Id(_) => None,
+            SendableAttachment::Detailed(intent) => intent.description.as_deref(),
+        }
+    }
+
+    /// Whether the file should be marked as a spoiler
+    pub fn spoiler(&self) -> Option<bool> {
+        match self {
+            SendableAttachment::Id(_) => None,
+            SendableAttachment::Detailed(intent) => intent.spoiler,
+        }
+    }
+}
+
+#[cfg(feature = "validator")]
+impl Validate for SendableAttachment {
+    fn validate(&self) -> Result<(), validator::ValidationErrors> {
+        match self {
+            SendableAttachment::Id(_) => Ok(()),
+            SendableAttachment::Detailed(intent) => intent.validate(),
         }
     }
 }
@@ -474,16 +775,38 @@ impl PushNotification {
             format!("{}/assets/logo.png", config.hosts.app)
         };
 
-        let image = msg.attachments.as_ref().and_then(|attachments| {
-            attachments
-                .first()
-                .map(|v| format!("{}/attachments/{}", config.hosts.autumn, v.id))
-        });
+        // (id, spoiler, description) of the first attachment, including forwarded ones
+        let attachment = if let Some(file) = msg.attachments.as_ref().and_then(|v| v.first()) {
+            Some((&file.id, file.spoiler, &file.description))
+        } else {
+            msg.forward
+                .as_ref()
+                .and_then(|forward| forward.attachments.as_ref())
+                .and_then(|attachments| attachments.first())
+                .map(|file| (&file.id, file.spoiler, &file.description))
+        };
+
+        // Spoilers should not be revealed in notifications, including through their description
+        let attachment = attachment.filter(|(_, spoiler, _)| *spoiler != Some(true));
+        let image =
+            attachment.map(|(id, _, _)| format!("{}/attachments/{}", config.hosts.autumn, id));
+
+        let image_description = attachment.and_then(|(_, _, description)| description.clone());
 
         let body = if let Some(ref sys) = msg.system {
             sys.clone().into()
+        } else if msg.flags & (1 << MessageFlags::VoiceMessage as u32) != 0 {
+            "Sent a voice message".to_string()
         } else if let Some(ref text) = msg.content {
             text.clone()
+        } else if let Some(ref forward) = msg.forward {
+            forward
+                .content
+                .as_ref()
+                .map(|content| format!("Forwarded: {content}"))
+                .unwrap_or_else(|| "Forwarded a message".to_string())
+        } else if let Some(ref description) = image_description {
+            description.clone()
         } else if let Some(text) = msg.embeds.as_ref().and_then(|embeds| match embeds.first() {
             Some(Embed::Image(_)) => Some("Sent an image".to_string()),
             Some(Embed::Video(_)) => Some("Sent a video".to_string()),
@@ -514,6 +837,7 @@ impl PushNotification {
                 .unwrap_or_else(|| "Revolt".to_string()),
             icon,
             image,
+            image_description,
             body,
             raw_body: None,
             tag: channel.id().to_string(),
@@ -524,3 +848,69 @@ impl PushNotification {
         }
     }
 }
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use serde_json::json;
+
+    use super::{AttachmentIntent, SendableAttachment};
+
+    #[test]
+    fn sendable_attachment_by_id() {
+        let attachment: SendableAttachment =
+            serde_json::from_value(json!("file")).expect("Failed to parse attachment");
+
+        assert_eq!(attachment, SendableAttachment::Id("file".to_string()));
+        assert_eq!(
+            serde_json::to_value(&attachment).expect("Failed to serialise attachment"),
+            json!("file")
+        );
+    }
+
+    #[test]
+    fn sendable_attachment_with_details() {
+        let value = json!({
+            "id": "file",
+            "description": "A cat sitting in a box",
+            "spoiler": true
+        });
+
+        let attachment: SendableAttachment =
+            serde_json::from_value(value.clone()).expect("Failed to parse attachment");
+
+        assert_eq!(
+            attachment,
+            SendableAttachment::Detailed(AttachmentIntent {
+                id: "file".to_string(),
+                description: Some("A cat sitting in a box".to_string()),
+                spoiler: Some(true),
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(&attachment).expect("Failed to serialise attachment"),
+            value
+        );
+    }
+
+    #[test]
+    fn attachment_intent_optional_details() {
+        let intent: AttachmentIntent =
+            serde_json::from_value(json!({ "id": "file" })).expect("Failed to parse intent");
+
+        assert_eq!(
+            intent,
+            AttachmentIntent {
+                id: "file".to_string(),
+                description: None,
+                spoiler: None,
+            }
+        );
+
+        let round_trip: AttachmentIntent = serde_json::from_value(
+            serde_json::to_value(&intent).expect("Failed to serialise intent"),
+        )
+        .expect("Failed to parse intent");
+
+        assert_eq!(round_trip, intent);
+    }
+}
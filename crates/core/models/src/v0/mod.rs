@@ -1,17 +1,22 @@
 mod audit_logs;
+mod bot_commands;
 mod bots;
+mod channel_exports;
 mod channel_invites;
 mod channel_unreads;
 mod channel_webhooks;
 mod channels;
+mod components;
 mod embeds;
 mod emojis;
+mod event_subscriptions;
 mod files;
 mod messages;
 mod policy_changes;
 mod safety_reports;
 mod server_bans;
 mod server_members;
+mod server_templates;
 mod servers;
 mod user_settings;
 mod users;
@@ -20,19 +25,24 @@ mod mfa_tickets;
 mod sessions;
 
 pub use audit_logs::*;
+pub use bot_commands::*;
 pub use bots::*;
+pub use channel_exports::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
 pub use channel_webhooks::*;
 pub use channels::*;
+pub use components::*;
 pub use embeds::*;
 pub use emojis::*;
+pub use event_subscriptions::*;
 pub use files::*;
 pub use messages::*;
 pub use policy_changes::*;
 pub use safety_reports::*;
 pub use server_bans::*;
 pub use server_members::*;
+pub use server_templates::*;
 pub use servers::*;
 pub use user_settings::*;
 pub use users::*;
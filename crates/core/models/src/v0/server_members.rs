@@ -66,6 +66,12 @@ auto_derived_partial!(
             serde(skip_serializing_if = "Vec::is_empty", default)
         )]
         pub roles: Vec<String>,
+        /// Expiry times for temporarily assigned roles
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub role_expiries: Vec<RoleExpiry>,
         /// Timestamp this member is timed out until
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub timeout: Option<Timestamp>,
@@ -90,12 +96,21 @@ auto_derived!(
         pub user: String,
     }
 
+    /// Expiry of a temporarily assigned role
+    pub struct RoleExpiry {
+        /// Role Id
+        pub role: String,
+        /// Time at which the role is removed from the member
+        pub expires_at: Timestamp,
+    }
+
     /// Optional fields on server member object
     pub enum FieldsMember {
         Nickname,
         Pronouns,
         Avatar,
         Roles,
+        RoleExpiries,
         Timeout,
         CanReceive,
         CanPublish,
@@ -148,6 +163,10 @@ auto_derived!(
         pub avatar: Option<String>,
         /// Array of role ids
         pub roles: Option<Vec<String>>,
+        /// Expiry times for roles the member has or is being given
+        ///
+        /// Replaces all existing expiries, roles without an entry are kept indefinitely.
+        pub role_expiries: Option<Vec<RoleExpiry>>,
         /// Timestamp this member is timed out until
         pub timeout: Option<Timestamp>,
         /// server-wide voice muted
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use revolt_permissions::OverrideField;
+
+#[cfg(feature = "validator")]
+use validator::Validate;
+
+use super::{
+    Category, ForumSortOrder, ForumTag, LegacyServerChannelType, SystemMessageChannels,
+    VoiceInformation,
+};
+
+auto_derived!(
+    /// Snapshot of a server's structure which new servers can be created from
+    pub struct ServerTemplate {
+        /// Template code
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub code: String,
+        /// Id of the server this template was taken from
+        pub server: String,
+        /// Id of the user who created this template
+        pub creator: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+
+        /// Version of this template, incremented each time it is synced
+        pub version: i32,
+        /// Captured server structure
+        pub snapshot: ServerSnapshot,
+    }
+
+    /// Captured server structure
+    ///
+    /// Ids are those of the source server and are replaced when a server is created.
+    pub struct ServerSnapshot {
+        /// Description of the server
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+        /// Roles in the server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub roles: Vec<TemplateRole>,
+        /// Channels in the server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub channels: Vec<TemplateChannel>,
+        /// Categories in the server
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub system_messages: Option<SystemMessageChannels>,
+
+        /// Whether the server is flagged as not safe for work
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+        /// Whether analytics are enabled
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub analytics: bool,
+    }
+
+    /// Role captured in a template
+    pub struct TemplateRole {
+        /// Id of the role in the source server
+        pub id: String,
+        /// Role name
+        pub name: String,
+        /// Permissions available to this role
+        pub permissions: OverrideField,
+        /// Colour used for this role
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub colour: Option<String>,
+        /// Whether this role should be shown separately on the member sidebar
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub hoist: bool,
+        /// Ranking of this role
+        pub rank: i64,
+    }
+
+    /// Channel captured in a template
+    pub struct TemplateChannel {
+        /// Id of the channel in the source server
+        pub id: String,
+        /// Channel type
+        pub channel_type: LegacyServerChannelType,
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Whether this channel is marked as not safe for work
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+        /// Voice information for when this channel is also a voice channel
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub voice: Option<VoiceInformation>,
+
+        /// Default permissions assigned to users in this channel
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "HashMap::is_empty", default)
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+
+        /// The channel's slowmode delay in seconds
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub slowmode: Option<u64>,
+        /// Time in seconds after which messages sent in this channel expire
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub message_ttl: Option<u64>,
+
+        /// Tags which can be applied to posts in a forum
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub tags: Vec<ForumTag>,
+        /// Order forum posts are listed in by default
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_sort: Option<ForumSortOrder>,
+        /// Whether forum posts must have at least one tag applied
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub require_tag: bool,
+    }
+
+    /// New server template details
+    #[cfg_attr(feature = "validator", derive(Validate))]
+    pub struct DataCreateServerTemplate {
+        /// Template name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Template description
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        pub description: Option<String>,
+    }
+);
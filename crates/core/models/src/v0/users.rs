@@ -79,6 +79,12 @@ auto_derived_partial!(
         pub relationship: RelationshipStatus,
         /// Whether this user is currently online
         pub online: bool,
+
+        /// Whether this user shares read receipts in direct messages and groups
+        ///
+        /// Only present for the current user
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub read_receipts: Option<bool>,
     },
     "PartialUser"
 );
@@ -244,6 +250,9 @@ auto_derived!(
         /// This is applied as a partial.
         #[cfg_attr(feature = "validator", validate)]
         pub profile: Option<DataUserProfile>,
+        /// Whether to share read receipts in direct messages and groups
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub read_receipts: Option<bool>,
 
         /// Bitfield of user badges
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
@@ -2,6 +2,9 @@ use std::collections::{HashSet, VecDeque};
 
 use logos::Logos;
 
+mod search;
+pub use search::*;
+
 #[derive(Debug, Clone, Logos, PartialEq)]
 #[logos(skip "\n")]
 #[logos(subpattern id="[0123456789ABCDEFGHJKMNPQRSTVWXYZ]{26}")]
@@ -0,0 +1,232 @@
+use std::fmt;
+
+use logos::Logos;
+
+const DAY_MS: u64 = 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, Logos, PartialEq)]
+#[logos(skip r"[ \t\r\n\f]+")]
+pub enum SearchToken<'a> {
+    #[regex(r#"(from|mentions|has|before|after|in):[^\s"]+"#, |lex| lex.slice().split_once(':'), priority = 10)]
+    Operator((&'a str, &'a str)),
+    #[regex(r#""[^"]*"?"#)]
+    Phrase(&'a str),
+    #[regex(r#"[^\s"]+"#)]
+    Word(&'a str),
+}
+
+/// Content a message can be required to have using `has:`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchHas {
+    Attachment,
+    Embed,
+    Link,
+}
+
+/// Structured search query
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SearchQuery {
+    /// Remaining free-text terms and phrases
+    pub text: Option<String>,
+    /// Users (ids or `username#discriminator`) the message may be sent by
+    pub from: Vec<String>,
+    /// Users (ids or `username#discriminator`) the message must mention
+    pub mentions: Vec<String>,
+    /// Content the message must have
+    pub has: Vec<SearchHas>,
+    /// Only match messages sent before the start of this day, as a Unix timestamp in milliseconds
+    pub before: Option<u64>,
+    /// Only match messages sent after the end of this day, as a Unix timestamp in milliseconds
+    pub after: Option<u64>,
+    /// Channel ids the message may be sent in
+    pub channels: Vec<String>,
+}
+
+/// Error encountered while parsing a search query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchError {
+    pub operator: String,
+    pub value: String,
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid value `{}` for `{}:`", self.value, self.operator)
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+/// Strip mention syntax, such as `<@id>`, from an operator value
+fn strip_mention<'a>(value: &'a str, prefix: &str) -> &'a str {
+    value
+        .strip_prefix(prefix)
+        .and_then(|value| value.strip_suffix('>'))
+        .unwrap_or(value)
+}
+
+/// Parse a `YYYY-MM-DD` date into a Unix timestamp in milliseconds at the start of that day
+fn parse_date(value: &str) -> Option<u64> {
+    let mut parts = value.splitn(3, '-');
+    let year: u64 = parts.next()?.parse().ok()?;
+    let month: u64 = parts.next()?.parse().ok()?;
+    let day: u64 = parts.next()?.parse().ok()?;
+
+    let leap = (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400);
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return None,
+    };
+
+    if year < 1970 || day == 0 || day > days_in_month {
+        return None;
+    }
+
+    // Days since the Unix epoch, counting years from March so leap days fall last
+    let (year, month) = if month <= 2 {
+        (year - 1, month + 9)
+    } else {
+        (year, month - 3)
+    };
+
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    Some((era * 146097 + day_of_era - 719468) * DAY_MS)
+}
+
+/// Parse a search query, pulling out operators such as `from:` and `has:`
+pub fn parse_search_query(text: &str) -> Result<SearchQuery, SearchError> {
+    let mut query = SearchQuery::default();
+    let mut terms = vec![];
+
+    for token in SearchToken::lexer(text).flatten() {
+        match token {
+            SearchToken::Operator((operator, value)) => {
+                let error = || SearchError {
+                    operator: operator.to_string(),
+                    value: value.to_string(),
+                };
+
+                match operator {
+                    "from" => query.from.push(strip_mention(value, "<@").to_string()),
+                    "mentions" => query.mentions.push(strip_mention(value, "<@").to_string()),
+                    "in" => query.channels.push(strip_mention(value, "<#").to_string()),
+                    "has" => query.has.push(match value {
+                        "attachment" => SearchHas::Attachment,
+                        "embed" => SearchHas::Embed,
+                        "link" => SearchHas::Link,
+                        _ => return Err(error()),
+                    }),
+                    "before" => query.before = Some(parse_date(value).ok_or_else(error)?),
+                    "after" => query.after = Some(parse_date(value).ok_or_else(error)? + DAY_MS),
+                    _ => unreachable!(),
+                }
+            }
+            SearchToken::Phrase(phrase) => {
+                if phrase.ends_with('"') && phrase.len() > 1 {
+                    terms.push(phrase.to_string());
+                } else {
+                    terms.push(format!("{phrase}\""));
+                }
+            }
+            SearchToken::Word(word) => terms.push(word.to_string()),
+        }
+    }
+
+    if !terms.is_empty() {
+        query.text = Some(terms.join(" "));
+    }
+
+    Ok(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_text() {
+        let output = parse_search_query("hello   world").unwrap();
+
+        assert_eq!(output.text.as_deref(), Some("hello world"));
+        assert!(output.from.is_empty());
+    }
+
+    #[test]
+    fn test_operators() {
+        let output = parse_search_query(
+            "from:<@01FD58YK5W7QRV5H3D64KTQYX3> mentions:01FD58YK5W7QRV5H3D64KTQYX4 in:<#01FD58YK5W7QRV5H3D64KTQYX5> has:link cats",
+        )
+        .unwrap();
+
+        assert_eq!(output.text.as_deref(), Some("cats"));
+        assert_eq!(output.from, vec!["01FD58YK5W7QRV5H3D64KTQYX3"]);
+        assert_eq!(output.mentions, vec!["01FD58YK5W7QRV5H3D64KTQYX4"]);
+        assert_eq!(output.channels, vec!["01FD58YK5W7QRV5H3D64KTQYX5"]);
+        assert_eq!(output.has, vec![SearchHas::Link]);
+    }
+
+    #[test]
+    fn test_username() {
+        let output = parse_search_query("from:insert#0001").unwrap();
+
+        assert_eq!(output.text, None);
+        assert_eq!(output.from, vec!["insert#0001"]);
+    }
+
+    #[test]
+    fn test_dates() {
+        let output = parse_search_query("before:2024-03-01 after:1970-01-01").unwrap();
+
+        assert_eq!(output.before, Some(1_709_251_200_000));
+        assert_eq!(output.after, Some(86_400_000));
+    }
+
+    #[test]
+    fn test_leap_day() {
+        assert!(parse_search_query("before:2024-02-29").is_ok());
+        assert!(parse_search_query("before:2023-02-29").is_err());
+    }
+
+    #[test]
+    fn test_invalid_values() {
+        assert_eq!(
+            parse_search_query("has:banana"),
+            Err(SearchError {
+                operator: "has".to_string(),
+                value: "banana".to_string()
+            })
+        );
+
+        assert!(parse_search_query("before:yesterday").is_err());
+        assert!(parse_search_query("after:1969-12-31").is_err());
+    }
+
+    #[test]
+    fn test_phrases() {
+        let output = parse_search_query("\"hello world\" from:01FD58YK5W7QRV5H3D64KTQYX3").unwrap();
+
+        assert_eq!(output.text.as_deref(), Some("\"hello world\""));
+        assert_eq!(output.from, vec!["01FD58YK5W7QRV5H3D64KTQYX3"]);
+    }
+
+    #[test]
+    fn test_unclosed_phrase() {
+        let output = parse_search_query("\"hello from:x").unwrap();
+
+        assert_eq!(output.text.as_deref(), Some("\"hello from:x\""));
+    }
+
+    #[test]
+    fn test_unknown_operator_is_text() {
+        let output = parse_search_query("https://revolt.chat from:").unwrap();
+
+        assert_eq!(output.text.as_deref(), Some("https://revolt.chat from:"));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    ChannelPermission, ChannelType, PermissionQuery, PermissionValue, RelationshipStatus,
-    UserPermission, ALLOW_IN_TIMEOUT, DEFAULT_PERMISSION_DIRECT_MESSAGE,
+    ChannelPermission, ChannelType, PermissionQuery, PermissionStep, PermissionValue,
+    RelationshipStatus, UserPermission, ALLOW_IN_TIMEOUT, DEFAULT_PERMISSION_DIRECT_MESSAGE,
     DEFAULT_PERMISSION_SAVED_MESSAGES, DEFAULT_PERMISSION_VIEW_ONLY,
 };
 
@@ -57,7 +57,7 @@ pub async fn calculate_server_permissions<P: PermissionQuery>(query: &mut P) ->
 
     let mut permissions: PermissionValue = query.get_default_server_permissions().await.into();
 
-    for role_override in query.get_our_server_role_overrides().await {
+    for (_, role_override) in query.get_our_server_role_overrides().await {
         permissions.apply(role_override);
     }
 
@@ -79,40 +79,94 @@ pub async fn calculate_server_permissions<P: PermissionQuery>(query: &mut P) ->
 
 /// Calculate permissions against a channel
 pub async fn calculate_channel_permissions<P: PermissionQuery>(query: &mut P) -> PermissionValue {
+    calculate_channel_permissions_with_steps(query, &mut None).await
+}
+
+/// Calculate permissions against a channel, recording each step taken
+pub async fn explain_channel_permissions<P: PermissionQuery>(
+    query: &mut P,
+) -> (PermissionValue, Vec<PermissionStep>) {
+    let mut steps = Some(vec![]);
+    let permissions = calculate_channel_permissions_with_steps(query, &mut steps).await;
+    (permissions, steps.unwrap_or_default())
+}
+
+/// Record a step if they are being collected
+fn record<F: FnOnce() -> PermissionStep>(steps: &mut Option<Vec<PermissionStep>>, step: F) {
+    if let Some(steps) = steps {
+        steps.push(step());
+    }
+}
+
+async fn calculate_channel_permissions_with_steps<P: PermissionQuery>(
+    query: &mut P,
+    steps: &mut Option<Vec<PermissionStep>>,
+) -> PermissionValue {
     if query.are_we_privileged().await {
-        return ChannelPermission::GrantAllSafe.into();
+        let permissions: PermissionValue = ChannelPermission::GrantAllSafe.into();
+        record(steps, || PermissionStep::Privileged {
+            permissions: permissions.into(),
+        });
+
+        return permissions;
     }
 
     match query.get_channel_type().await {
         ChannelType::SavedMessages => {
-            if query.do_we_own_the_channel().await {
+            let permissions: PermissionValue = if query.do_we_own_the_channel().await {
                 DEFAULT_PERMISSION_SAVED_MESSAGES.into()
             } else {
                 0_u64.into()
-            }
+            };
+
+            record(steps, || PermissionStep::ChannelBase {
+                permissions: permissions.into(),
+            });
+
+            permissions
         }
         ChannelType::DirectMessage => {
             if query.are_we_part_of_the_channel().await {
                 query.set_recipient_as_user().await;
 
                 let permissions = calculate_user_permissions(query).await;
-                if permissions.has_user_permission(UserPermission::SendMessage) {
-                    (*DEFAULT_PERMISSION_DIRECT_MESSAGE).into()
-                } else {
-                    (*DEFAULT_PERMISSION_VIEW_ONLY).into()
-                }
+                let permissions: PermissionValue =
+                    if permissions.has_user_permission(UserPermission::SendMessage) {
+                        (*DEFAULT_PERMISSION_DIRECT_MESSAGE).into()
+                    } else {
+                        (*DEFAULT_PERMISSION_VIEW_ONLY).into()
+                    };
+
+                record(steps, || PermissionStep::ChannelBase {
+                    permissions: permissions.into(),
+                });
+
+                permissions
             } else {
+                record(steps, || PermissionStep::NotMember { permissions: 0 });
                 0_u64.into()
             }
         }
         ChannelType::Group => {
             if query.do_we_own_the_channel().await {
-                ChannelPermission::GrantAllSafe.into()
+                let permissions: PermissionValue = ChannelPermission::GrantAllSafe.into();
+                record(steps, || PermissionStep::Owner {
+                    permissions: permissions.into(),
+                });
+
+                permissions
             } else if query.are_we_part_of_the_channel().await {
-                (*DEFAULT_PERMISSION_VIEW_ONLY
+                let permissions: PermissionValue = (*DEFAULT_PERMISSION_VIEW_ONLY
                     | query.get_default_channel_permissions().await.allow)
-                    .into()
+                    .into();
+
+                record(steps, || PermissionStep::ChannelBase {
+                    permissions: permissions.into(),
+                });
+
+                permissions
             } else {
+                record(steps, || PermissionStep::NotMember { permissions: 0 });
                 0_u64.into()
             }
         }
@@ -120,38 +174,100 @@ pub async fn calculate_channel_permissions<P: PermissionQuery>(query: &mut P) ->
             query.set_server_from_channel().await;
 
             if query.are_we_server_owner().await {
-                ChannelPermission::GrantAllSafe.into()
+                let permissions: PermissionValue = ChannelPermission::GrantAllSafe.into();
+                record(steps, || PermissionStep::Owner {
+                    permissions: permissions.into(),
+                });
+
+                permissions
             } else if query.are_we_a_member().await {
                 let mut permissions = PermissionValue::from(query.get_default_server_permissions().await);
-                permissions.apply(query.get_default_channel_permissions().await);
+                record(steps, || PermissionStep::ServerDefault {
+                    permissions: permissions.into(),
+                });
 
-                for role_override in query.get_our_server_role_overrides().await {
-                    permissions.apply(role_override);
+                // Category permissions apply beneath the channel's own
+                let value = query.get_default_category_permissions().await;
+                permissions.apply(value.clone());
+                record(steps, || PermissionStep::CategoryDefault {
+                    value,
+                    permissions: permissions.into(),
+                });
+
+                let value = query.get_default_channel_permissions().await;
+                permissions.apply(value.clone());
+                record(steps, || PermissionStep::ChannelDefault {
+                    value,
+                    permissions: permissions.into(),
+                });
+
+                for (role, value) in query.get_our_server_role_overrides().await {
+                    permissions.apply(value.clone());
+                    record(steps, || PermissionStep::ServerRole {
+                        role,
+                        value,
+                        permissions: permissions.into(),
+                    });
+                }
+
+                for (role, value) in query.get_our_category_role_overrides().await {
+                    permissions.apply(value.clone());
+                    record(steps, || PermissionStep::CategoryRole {
+                        role,
+                        value,
+                        permissions: permissions.into(),
+                    });
                 }
 
-                for role_override in query.get_our_channel_role_overrides().await {
-                    permissions.apply(role_override);
+                for (role, value) in query.get_our_channel_role_overrides().await {
+                    permissions.apply(value.clone());
+                    record(steps, || PermissionStep::ChannelRole {
+                        role,
+                        value,
+                        permissions: permissions.into(),
+                    });
                 }
 
-                if !query.do_we_have_publish_overwrites().await {
+                // Member specific overrides take precedence over all roles
+                let value = query.get_our_channel_member_override().await;
+                permissions.apply(value.clone());
+                record(steps, || PermissionStep::MemberOverride {
+                    value,
+                    permissions: permissions.into(),
+                });
+
+                let can_publish = query.do_we_have_publish_overwrites().await;
+                if !can_publish {
                     permissions.revoke(ChannelPermission::Speak as u64);
                     permissions.revoke(ChannelPermission::Video as u64);
                 }
 
-                if !query.do_we_have_receive_overwrites().await {
+                let can_receive = query.do_we_have_receive_overwrites().await;
+                if !can_receive {
                     permissions.revoke(ChannelPermission::Listen as u64);
                 }
 
+                if !can_publish || !can_receive {
+                    record(steps, || PermissionStep::VoiceRestricted {
+                        permissions: permissions.into(),
+                    });
+                }
+
                 if query.are_we_timed_out().await {
                     permissions.restrict(*ALLOW_IN_TIMEOUT);
+                    record(steps, || PermissionStep::TimedOut {
+                        permissions: permissions.into(),
+                    });
                 }
 
                 if !permissions.has_channel_permission(ChannelPermission::ViewChannel) {
                     permissions.revoke_all();
+                    record(steps, || PermissionStep::CannotView { permissions: 0 });
                 }
 
                 permissions
             } else {
+                record(steps, || PermissionStep::NotMember { permissions: 0 });
                 0_u64.into()
             }
         }
@@ -102,9 +102,11 @@ pub enum ChannelPermission {
 
     /// Access server audit logs
     ViewAuditLogs = 1 << 40,
+    /// Export a channel's message history
+    ExportMessages = 1 << 41,
 
     // * Misc. permissions
-    // % Bits 41 to 52: free area
+    // % Bits 42 to 52: free area
     // % Bits 53 to 64: do not use
 
     // * Grant all permissions
@@ -1,10 +1,12 @@
 mod channel;
 mod server;
+mod trace;
 mod user;
 
 pub use channel::*;
 use revolt_result::{create_error, Result};
 pub use server::*;
+pub use trace::*;
 pub use user::*;
 
 /// Holds a permission value to manipulate.
@@ -0,0 +1,54 @@
+#[cfg(feature = "schemas")]
+use schemars::JsonSchema;
+
+use super::Override;
+
+/// Single step taken while calculating permissions
+///
+/// Each step carries the resulting permission value after it was applied.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemas", derive(JsonSchema))]
+#[cfg_attr(feature = "serde", serde(tag = "type"))]
+pub enum PermissionStep {
+    /// User is privileged and is granted everything
+    Privileged { permissions: u64 },
+    /// User owns the server or channel and is granted everything
+    Owner { permissions: u64 },
+    /// User is not a member of the server or channel
+    NotMember { permissions: u64 },
+    /// Fixed permissions for saved messages, direct messages and groups
+    ChannelBase { permissions: u64 },
+    /// Server default permissions
+    ServerDefault { permissions: u64 },
+    /// Category default override
+    CategoryDefault { value: Override, permissions: u64 },
+    /// Channel default override
+    ChannelDefault { value: Override, permissions: u64 },
+    /// Server role override, applied from lowest to highest ranking
+    ServerRole {
+        role: String,
+        value: Override,
+        permissions: u64,
+    },
+    /// Category role override, applied from lowest to highest ranking
+    CategoryRole {
+        role: String,
+        value: Override,
+        permissions: u64,
+    },
+    /// Channel role override, applied from lowest to highest ranking
+    ChannelRole {
+        role: String,
+        value: Override,
+        permissions: u64,
+    },
+    /// Channel override for this member specifically
+    MemberOverride { value: Override, permissions: u64 },
+    /// Server-wide voice mute or deafen revoked voice permissions
+    VoiceRestricted { permissions: u64 },
+    /// Member is timed out and restricted to the timeout allow list
+    TimedOut { permissions: u64 },
+    /// Member cannot view the channel so every permission was revoked
+    CannotView { permissions: u64 },
+}
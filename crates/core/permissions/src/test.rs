@@ -1,7 +1,7 @@
 use crate::{
-    calculate_channel_permissions, calculate_user_permissions, ChannelPermission, ChannelType,
-    Override, PermissionQuery, RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
-    DEFAULT_PERMISSION_SERVER, DEFAULT_PERMISSION_VIEW_ONLY,
+    calculate_channel_permissions, calculate_user_permissions, explain_channel_permissions,
+    ChannelPermission, ChannelType, Override, PermissionQuery, PermissionStep, RelationshipStatus,
+    DEFAULT_PERMISSION_DIRECT_MESSAGE, DEFAULT_PERMISSION_SERVER, DEFAULT_PERMISSION_VIEW_ONLY,
 };
 
 #[tokio::test]
@@ -56,7 +56,7 @@ async fn validate_user_permissions() {
             unreachable!()
         }
 
-        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
             unreachable!()
         }
 
@@ -80,7 +80,19 @@ async fn validate_user_permissions() {
             unreachable!()
         }
 
-        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            unreachable!()
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            unreachable!()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+            unreachable!()
+        }
+
+        async fn get_our_channel_member_override(&mut self) -> Override {
             unreachable!()
         }
 
@@ -153,7 +165,7 @@ async fn validate_group_permissions() {
             unreachable!()
         }
 
-        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
             unreachable!()
         }
 
@@ -180,7 +192,19 @@ async fn validate_group_permissions() {
             }
         }
 
-        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            unreachable!()
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            unreachable!()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+            unreachable!()
+        }
+
+        async fn get_our_channel_member_override(&mut self) -> Override {
             unreachable!()
         }
 
@@ -259,11 +283,14 @@ async fn validate_server_permissions() {
                 | ChannelPermission::ReadMessageHistory as u64
         }
 
-        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
-            vec![Override {
-                allow: ChannelPermission::UploadFiles as u64 | ChannelPermission::React as u64,
-                deny: ChannelPermission::ReadMessageHistory as u64,
-            }]
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![(
+                "role".to_string(),
+                Override {
+                    allow: ChannelPermission::UploadFiles as u64 | ChannelPermission::React as u64,
+                    deny: ChannelPermission::ReadMessageHistory as u64,
+                },
+            )]
         }
 
         async fn are_we_timed_out(&mut self) -> bool {
@@ -289,11 +316,26 @@ async fn validate_server_permissions() {
             }
         }
 
-        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
-            vec![Override {
-                allow: 0,
-                deny: ChannelPermission::React as u64,
-            }]
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![(
+                "role".to_string(),
+                Override {
+                    allow: 0,
+                    deny: ChannelPermission::React as u64,
+                },
+            )]
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn get_our_channel_member_override(&mut self) -> Override {
+            Default::default()
         }
 
         async fn do_we_own_the_channel(&mut self) -> bool {
@@ -362,7 +404,7 @@ async fn validate_timed_out_member() {
             *DEFAULT_PERMISSION_SERVER
         }
 
-        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
             vec![]
         }
 
@@ -386,10 +428,22 @@ async fn validate_timed_out_member() {
             Override { allow: 0, deny: 0 }
         }
 
-        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
             vec![]
         }
 
+        async fn get_our_channel_member_override(&mut self) -> Override {
+            Default::default()
+        }
+
         async fn do_we_own_the_channel(&mut self) -> bool {
             unreachable!()
         }
@@ -466,11 +520,14 @@ async fn validate_channel_default_below_server_roles() {
             ChannelPermission::ViewChannel as u64
         }
 
-        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
-            vec![Override {
-                allow: ChannelPermission::SendMessage as u64,
-                deny: 0,
-            }]
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![(
+                "role".to_string(),
+                Override {
+                    allow: ChannelPermission::SendMessage as u64,
+                    deny: 0,
+                },
+            )]
         }
 
         async fn are_we_timed_out(&mut self) -> bool {
@@ -496,10 +553,22 @@ async fn validate_channel_default_below_server_roles() {
             }
         }
 
-        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
             vec![]
         }
 
+        async fn get_our_channel_member_override(&mut self) -> Override {
+            Default::default()
+        }
+
         async fn do_we_own_the_channel(&mut self) -> bool {
             unreachable!()
         }
@@ -518,3 +587,271 @@ async fn validate_channel_default_below_server_roles() {
     }
 }
 
+#[tokio::test]
+async fn validate_category_below_channel() {
+    /// Scenario where:
+    /// - Server default allows ViewChannel.
+    /// - Category default override allows SendMessage.
+    /// - Channel default override denies SendMessage.
+    /// - Category role override allows React.
+    /// Category permissions sit beneath the channel's own permissions,
+    /// so the channel default (denying SendMessage) should take precedence
+    /// while the category role override still applies.
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(
+        value,
+        ChannelPermission::ViewChannel as u64 | ChannelPermission::React as u64
+    );
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            ChannelPermission::ViewChannel as u64
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn do_we_have_publish_overwrites(&mut self) -> bool {
+            true
+        }
+
+        async fn do_we_have_receive_overwrites(&mut self) -> bool {
+            true
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            ChannelType::ServerChannel
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            Override {
+                allow: 0,
+                deny: ChannelPermission::SendMessage as u64,
+            }
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Override {
+                allow: ChannelPermission::SendMessage as u64,
+                deny: 0,
+            }
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![(
+                "role".to_string(),
+                Override {
+                    allow: ChannelPermission::React as u64,
+                    deny: 0,
+                },
+            )]
+        }
+
+        async fn get_our_channel_member_override(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            // no-op
+        }
+    }
+}
+
+#[tokio::test]
+async fn validate_member_override_last() {
+    /// Scenario where:
+    /// - Server default allows ViewChannel and SendMessage.
+    /// - Channel role override denies ViewChannel and SendMessage.
+    /// - Channel member override allows ViewChannel.
+    /// The member override is applied last, so the member should
+    /// be able to view the channel but not send messages.
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_channel_permissions(&mut query).await;
+    let value: u64 = perms.into();
+    assert_eq!(value, ChannelPermission::ViewChannel as u64);
+
+    // The explanation should agree and end on the member override
+    let (perms, steps) = explain_channel_permissions(&mut query).await;
+    assert_eq!(u64::from(perms), value);
+    assert_eq!(
+        steps.last(),
+        Some(&PermissionStep::MemberOverride {
+            value: Override {
+                allow: ChannelPermission::ViewChannel as u64,
+                deny: 0,
+            },
+            permissions: value,
+        })
+    );
+
+    // Role steps name the role they came from
+    assert!(steps.iter().any(|step| matches!(
+        step,
+        PermissionStep::ChannelRole { role, .. } if role == "role"
+    )));
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            ChannelPermission::ViewChannel as u64 | ChannelPermission::SendMessage as u64
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn do_we_have_publish_overwrites(&mut self) -> bool {
+            true
+        }
+
+        async fn do_we_have_receive_overwrites(&mut self) -> bool {
+            true
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            ChannelType::ServerChannel
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![(
+                "role".to_string(),
+                Override {
+                    allow: 0,
+                    deny: ChannelPermission::ViewChannel as u64
+                        | ChannelPermission::SendMessage as u64,
+                },
+            )]
+        }
+
+        async fn get_default_category_permissions(&mut self) -> Override {
+            Default::default()
+        }
+
+        async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)> {
+            vec![]
+        }
+
+        async fn get_our_channel_member_override(&mut self) -> Override {
+            Override {
+                allow: ChannelPermission::ViewChannel as u64,
+                deny: 0,
+            }
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            // no-op
+        }
+    }
+}
@@ -34,7 +34,9 @@ pub trait PermissionQuery {
     async fn get_default_server_permissions(&mut self) -> u64;
 
     /// Get the ordered role overrides (from lowest to highest) for this member in this server
-    async fn get_our_server_role_overrides(&mut self) -> Vec<Override>;
+    ///
+    /// Each override is given alongside the id of the role it belongs to.
+    async fn get_our_server_role_overrides(&mut self) -> Vec<(String, Override)>;
 
     /// Is our perspective user timed out on this server?
     async fn are_we_timed_out(&mut self) -> bool;
@@ -55,7 +57,20 @@ pub trait PermissionQuery {
     async fn get_default_channel_permissions(&mut self) -> Override;
 
     /// Get the ordered role overrides (from lowest to highest) for this member in this channel
-    async fn get_our_channel_role_overrides(&mut self) -> Vec<Override>;
+    ///
+    /// Each override is given alongside the id of the role it belongs to.
+    async fn get_our_channel_role_overrides(&mut self) -> Vec<(String, Override)>;
+
+    /// Get the default permissions of the category this channel is in
+    async fn get_default_category_permissions(&mut self) -> Override;
+
+    /// Get the ordered role overrides (from lowest to highest) for this member in this channel's category
+    ///
+    /// Each override is given alongside the id of the role it belongs to.
+    async fn get_our_category_role_overrides(&mut self) -> Vec<(String, Override)>;
+
+    /// Get the override for this member specifically in this channel
+    async fn get_our_channel_member_override(&mut self) -> Override;
 
     /// Do we own this group or saved messages channel if it is one of those?
     async fn do_we_own_the_channel(&mut self) -> bool;
@@ -41,7 +41,15 @@ impl IntoResponse for Error {
             ErrorType::NotInGroup => StatusCode::NOT_FOUND,
             ErrorType::AlreadyPinned => StatusCode::BAD_REQUEST,
             ErrorType::NotPinned => StatusCode::BAD_REQUEST,
+            ErrorType::AlreadyPublished => StatusCode::BAD_REQUEST,
+            ErrorType::AlreadyFollowing => StatusCode::CONFLICT,
             ErrorType::InSlowmode { retry_after: _ } => StatusCode::TOO_MANY_REQUESTS,
+            ErrorType::ThreadAlreadyExists => StatusCode::CONFLICT,
+            ErrorType::ThreadArchived => StatusCode::FORBIDDEN,
+            ErrorType::ForumTagRequired => StatusCode::BAD_REQUEST,
+            ErrorType::TooManyForumTags { .. } => StatusCode::BAD_REQUEST,
+            ErrorType::TooManyPollOptions { .. } => StatusCode::BAD_REQUEST,
+            ErrorType::PollClosed => StatusCode::FORBIDDEN,
 
             ErrorType::CantCreateServers => StatusCode::FORBIDDEN,
             ErrorType::UnknownServer => StatusCode::NOT_FOUND,
@@ -55,11 +63,15 @@ impl IntoResponse for Error {
             ErrorType::TooManyEmoji { .. } => StatusCode::BAD_REQUEST,
             ErrorType::TooManyChannels { .. } => StatusCode::BAD_REQUEST,
             ErrorType::TooManyRoles { .. } => StatusCode::BAD_REQUEST,
+            ErrorType::TooManyServerTemplates { .. } => StatusCode::BAD_REQUEST,
 
             ErrorType::ReachedMaximumBots => StatusCode::BAD_REQUEST,
             ErrorType::IsBot => StatusCode::BAD_REQUEST,
             ErrorType::IsNotBot => StatusCode::BAD_REQUEST,
             ErrorType::BotIsPrivate => StatusCode::FORBIDDEN,
+            ErrorType::TooManyBotCommands { .. } => StatusCode::BAD_REQUEST,
+            ErrorType::CommandAlreadyExists => StatusCode::CONFLICT,
+            ErrorType::TooManyEventSubscriptions { .. } => StatusCode::BAD_REQUEST,
 
             ErrorType::CannotReportYourself => StatusCode::BAD_REQUEST,
 
@@ -103,9 +103,21 @@ pub enum ErrorType {
     NotInGroup,
     AlreadyPinned,
     NotPinned,
+    AlreadyPublished,
+    AlreadyFollowing,
     InSlowmode {
         retry_after: u64,
     },
+    ThreadAlreadyExists,
+    ThreadArchived,
+    ForumTagRequired,
+    TooManyForumTags {
+        max: usize,
+    },
+    TooManyPollOptions {
+        max: usize,
+    },
+    PollClosed,
 
     // ? Server related errors
     CantCreateServers,
@@ -121,6 +133,9 @@ pub enum ErrorType {
     TooManyRoles {
         max: usize,
     },
+    TooManyServerTemplates {
+        max: usize,
+    },
     AlreadyInServer,
     CannotTimeoutYourself,
 
@@ -129,6 +144,13 @@ pub enum ErrorType {
     IsBot,
     IsNotBot,
     BotIsPrivate,
+    TooManyBotCommands {
+        max: usize,
+    },
+    CommandAlreadyExists,
+    TooManyEventSubscriptions {
+        max: usize,
+    },
 
     // ? User safety related errors
     CannotReportYourself,
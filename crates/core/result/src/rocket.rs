@@ -43,7 +43,15 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::NotInGroup => Status::NotFound,
             ErrorType::AlreadyPinned => Status::BadRequest,
             ErrorType::NotPinned => Status::BadRequest,
+            ErrorType::AlreadyPublished => Status::BadRequest,
+            ErrorType::AlreadyFollowing => Status::Conflict,
             ErrorType::InSlowmode { retry_after: _ } => Status::TooManyRequests,
+            ErrorType::ThreadAlreadyExists => Status::Conflict,
+            ErrorType::ThreadArchived => Status::Forbidden,
+            ErrorType::ForumTagRequired => Status::BadRequest,
+            ErrorType::TooManyForumTags { .. } => Status::BadRequest,
+            ErrorType::TooManyPollOptions { .. } => Status::BadRequest,
+            ErrorType::PollClosed => Status::Forbidden,
             ErrorType::InvalidFlagValue => Status::BadRequest,
 
             ErrorType::CantCreateServers => Status::Forbidden,
@@ -58,11 +66,15 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::TooManyEmoji { .. } => Status::BadRequest,
             ErrorType::TooManyChannels { .. } => Status::BadRequest,
             ErrorType::TooManyRoles { .. } => Status::BadRequest,
+            ErrorType::TooManyServerTemplates { .. } => Status::BadRequest,
 
             ErrorType::ReachedMaximumBots => Status::BadRequest,
             ErrorType::IsBot => Status::BadRequest,
             ErrorType::IsNotBot => Status::BadRequest,
             ErrorType::BotIsPrivate => Status::Forbidden,
+            ErrorType::TooManyBotCommands { .. } => Status::BadRequest,
+            ErrorType::CommandAlreadyExists => Status::Conflict,
+            ErrorType::TooManyEventSubscriptions { .. } => Status::BadRequest,
 
             ErrorType::CannotReportYourself => Status::BadRequest,
 
@@ -47,5 +47,10 @@ async fn main() {
         cron_task_wrapper(prune_members::task, db.clone(), amqp.clone()),
         cron_task_wrapper(delete_accounts::task, db.clone(), amqp.clone()),
         cron_task_wrapper(acks::task, db.clone(), amqp.clone()),
+        cron_task_wrapper(close_polls::task, db.clone(), amqp.clone()),
+        cron_task_wrapper(send_scheduled_messages::task, db.clone(), amqp.clone()),
+        cron_task_wrapper(delete_expired_messages::task, db.clone(), amqp.clone()),
+        cron_task_wrapper(export_channels::task, db.clone(), amqp.clone()),
+        cron_task_wrapper(expire_roles::task, db.clone(), amqp.clone()),
     );
 }
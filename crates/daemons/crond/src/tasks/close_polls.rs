@@ -0,0 +1,26 @@
+use std::time::Duration;
+
+use log::warn;
+use revolt_database::{Database, AMQP};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database, amqp: AMQP) -> Result<()> {
+    loop {
+        let messages = db.fetch_expired_polls().await?;
+        let count = messages.len();
+
+        for mut message in messages {
+            if let Err(error) = message.close_poll(&db, Some(&amqp)).await {
+                revolt_config::capture_error(&error);
+                warn!("Failed to close poll on {}: {:?}", message.id, &error);
+            }
+        }
+
+        if count > 0 {
+            log::info!("Closed {count} polls.");
+        }
+
+        sleep(Duration::from_secs(30)).await;
+    }
+}
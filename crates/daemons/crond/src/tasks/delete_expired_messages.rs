@@ -0,0 +1,18 @@
+use std::time::Duration;
+
+use log::info;
+use revolt_database::{Database, Message};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database, _: revolt_database::AMQP) -> Result<()> {
+    loop {
+        let count = Message::delete_expired(&db).await?;
+
+        if count > 0 {
+            info!("Deleted {count} expired messages.");
+        }
+
+        sleep(Duration::from_secs(10)).await;
+    }
+}
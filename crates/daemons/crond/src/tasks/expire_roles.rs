@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use log::warn;
+use revolt_database::{
+    util::reference::Reference,
+    voice::{
+        get_channel_node, get_user_voice_channel_in_server, sync_user_voice_permissions,
+        VoiceClient,
+    },
+    AuditLogEntryAction, Database, Member, AMQP,
+};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+/// Actor recorded for changes made by the server itself rather than a user
+///
+/// Expired roles are logged as a `MemberEdit` made by this id, so they can be
+/// told apart from edits made by the member or a moderator.
+const SYSTEM_USER_ID: &str = "00000000000000000000000000";
+
+pub async fn task(db: Database, _: AMQP) -> Result<()> {
+    let voice_client = VoiceClient::from_revolt_config().await;
+
+    loop {
+        let members = db.fetch_members_with_expired_roles().await?;
+        let mut count = 0;
+
+        for mut member in members {
+            match expire_roles(&db, &voice_client, &mut member).await {
+                Ok(true) => count += 1,
+                Ok(false) => {}
+                Err(error) => {
+                    revolt_config::capture_error(&error);
+                    warn!(
+                        "Failed to remove expired roles from {} in {}: {:?}",
+                        member.id.user, member.id.server, &error
+                    );
+                }
+            }
+        }
+
+        if count > 0 {
+            log::info!("Removed expired roles from {count} members.");
+        }
+
+        sleep(Duration::from_secs(30)).await;
+    }
+}
+
+async fn expire_roles(
+    db: &Database,
+    voice_client: &VoiceClient,
+    member: &mut Member,
+) -> Result<bool> {
+    let Some((before, after)) = member.remove_expired_roles(db).await? else {
+        return Ok(false);
+    };
+
+    AuditLogEntryAction::MemberEdit {
+        user: member.id.user.clone(),
+        before,
+        after,
+    }
+    .insert(
+        db,
+        member.id.server.clone(),
+        "Role assignment expired".to_string(),
+        SYSTEM_USER_ID.to_string(),
+        Some(member.id.user.clone()),
+    )
+    .await;
+
+    if voice_client.is_enabled() {
+        if let Some(channel) =
+            get_user_voice_channel_in_server(&member.id.user, &member.id.server).await?
+        {
+            if let Some(node) = get_channel_node(&channel).await? {
+                let user = db.fetch_user(&member.id.user).await?;
+                let server = db.fetch_server(&member.id.server).await?;
+                let channel = Reference::from_unchecked(&channel).as_channel(db).await?;
+
+                sync_user_voice_permissions(
+                    db,
+                    voice_client,
+                    &node,
+                    &user,
+                    &channel,
+                    Some(&server),
+                    None,
+                )
+                .await?;
+            }
+        }
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use log::{info, warn};
+use revolt_config::config;
+use revolt_database::{
+    ChannelExport, Database, File, FileHash, FileUsedFor, FileUsedForType, Metadata, Transcript,
+};
+use revolt_files::upload_to_s3;
+use revolt_result::Result;
+use sha2::Digest;
+use tokio::time::sleep;
+
+/// Store a transcript through file storage, returning the file for it
+async fn store(
+    db: &Database,
+    export: &ChannelExport,
+    filename: String,
+    content_type: &str,
+    buf: Vec<u8>,
+) -> Result<File> {
+    let config = config().await;
+    let hash = format!("{:02x}", sha2::Sha256::digest(&buf));
+
+    let file_hash = match db.fetch_attachment_hash(&hash).await {
+        Ok(file_hash) if !file_hash.iv.is_empty() => file_hash,
+        existing => {
+            let file_hash = FileHash {
+                id: hash.clone(),
+                processed_hash: hash.clone(),
+
+                created_at: Timestamp::now_utc(),
+
+                bucket_id: config.files.s3.default_bucket,
+                path: hash.clone(),
+                iv: String::new(),
+
+                metadata: Metadata::File,
+                content_type: content_type.to_owned(),
+                size: buf.len() as isize,
+            };
+
+            if existing.is_err() {
+                db.insert_attachment_hash(&file_hash).await?;
+            }
+
+            let nonce = upload_to_s3(&file_hash.bucket_id, &file_hash.path, &buf).await?;
+            db.set_attachment_hash_nonce(&file_hash.id, &nonce).await?;
+            file_hash
+        }
+    };
+
+    let mut file = file_hash.into_file(
+        nanoid::nanoid!(42),
+        "attachments".to_owned(),
+        filename,
+        export.requester.clone(),
+    );
+
+    file.used_for = Some(FileUsedFor {
+        object_type: FileUsedForType::ChannelExport,
+        id: export.id.clone(),
+    });
+
+    db.insert_attachment(&file).await?;
+    Ok(file)
+}
+
+/// Render and store transcripts for an export
+async fn process(db: &Database, export: &mut ChannelExport) -> Result<()> {
+    export.start(db).await?;
+
+    let channel = db.fetch_channel(&export.channel).await?;
+    let transcript = Transcript::render(db, &channel).await?;
+
+    let json = store(
+        db,
+        export,
+        format!("transcript-{}.json", export.channel),
+        "application/json",
+        transcript.json.into_bytes().await?,
+    )
+    .await?;
+
+    let html = store(
+        db,
+        export,
+        format!("transcript-{}.html", export.channel),
+        "text/html",
+        transcript.html.into_bytes().await?,
+    )
+    .await?;
+
+    export
+        .complete(db, transcript.message_count, json, html)
+        .await
+}
+
+pub async fn task(db: Database, _: revolt_database::AMQP) -> Result<()> {
+    // Exports are only processed here, so any still processing were interrupted
+    db.requeue_processing_channel_exports().await?;
+
+    loop {
+        let exports = db.fetch_pending_channel_exports().await?;
+
+        for mut export in exports {
+            if let Err(error) = process(&db, &mut export).await {
+                revolt_config::capture_error(&error);
+                warn!("Failed to export channel {}: {error:?}", export.channel);
+                export.fail(&db).await?;
+            } else {
+                info!("Exported channel {}", export.channel);
+            }
+        }
+
+        sleep(Duration::from_secs(10)).await;
+    }
+}
@@ -1,6 +1,11 @@
 pub mod delete_accounts;
 pub mod acks;
+pub mod close_polls;
+pub mod delete_expired_messages;
+pub mod expire_roles;
+pub mod export_channels;
 pub mod file_deletion;
 pub mod prune_dangling_files;
 pub mod prune_members;
 pub mod prune_mfa_tickets;
+pub mod send_scheduled_messages;
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use log::warn;
+use revolt_database::{Database, AMQP};
+use revolt_result::Result;
+use tokio::time::sleep;
+
+pub async fn task(db: Database, amqp: AMQP) -> Result<()> {
+    loop {
+        let scheduled_messages = db.fetch_due_scheduled_messages().await?;
+        let mut count = 0;
+
+        for scheduled_message in scheduled_messages {
+            let id = scheduled_message.id.clone();
+            match scheduled_message.send(&db, Some(&amqp)).await {
+                Ok(_) => count += 1,
+                // The message is retried later or kept and marked as failed for its author to see
+                Err(error) => {
+                    revolt_config::capture_error(&error);
+                    warn!("Failed to send scheduled message {id}: {error:?}");
+                }
+            }
+        }
+
+        if count > 0 {
+            log::info!("Sent {count} scheduled messages.");
+        }
+
+        sleep(Duration::from_secs(10)).await;
+    }
+}
@@ -0,0 +1,170 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use revolt_database::{
+    util::interactions::sign_payload, Database, EventSubscription, PartialEventSubscription,
+};
+use tokio::{
+    sync::{
+        mpsc::{self, error::TrySendError},
+        Semaphore,
+    },
+    time::sleep,
+};
+
+/// Number of times to try delivering a single event
+const MAX_ATTEMPTS: u32 = 5;
+
+/// Number of consecutive undeliverable events before an endpoint is disabled
+const MAX_FAILURES: u32 = 10;
+
+/// Number of events which may be waiting to be sent to a single endpoint
+const QUEUE_SIZE: usize = 256;
+
+/// Number of events which may be in flight to a single endpoint at once
+const MAX_IN_FLIGHT: usize = 4;
+
+/// Event waiting to be delivered to an endpoint
+type Delivery = (Arc<EventSubscription>, String);
+
+/// Try to send an event to an endpoint once
+async fn send(client: &reqwest::Client, subscription: &EventSubscription, body: &str) -> bool {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default();
+
+    client
+        .post(&subscription.url)
+        .header("Content-Type", "application/json")
+        .header("X-Timestamp", timestamp.to_string())
+        .header(
+            "X-Signature",
+            sign_payload(&subscription.secret, timestamp, body),
+        )
+        .body(body.to_string())
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .is_ok()
+}
+
+/// Deliver an event to an endpoint, retrying with backoff
+///
+/// Endpoints which keep failing are disabled until the bot's owner re-enables them.
+async fn deliver(
+    db: &Database,
+    client: &reqwest::Client,
+    failures: &AtomicU32,
+    subscription: &EventSubscription,
+    body: &str,
+) {
+    for attempt in 0..MAX_ATTEMPTS {
+        if attempt > 0 {
+            sleep(Duration::from_secs(1 << (attempt - 1))).await;
+        }
+
+        if send(client, subscription, body).await {
+            failures.store(0, Ordering::Relaxed);
+            return;
+        }
+    }
+
+    log::warn!(
+        "Failed to deliver event to {} after {MAX_ATTEMPTS} attempts",
+        subscription.url
+    );
+
+    if failures.fetch_add(1, Ordering::Relaxed) + 1 == MAX_FAILURES {
+        log::info!(
+            "Disabling event subscription {} for bot {}",
+            subscription.id,
+            subscription.bot
+        );
+
+        if let Err(error) = db
+            .update_event_subscription(
+                &subscription.id,
+                &PartialEventSubscription {
+                    disabled: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+        {
+            log::error!("Failed to disable event subscription: {error:?}");
+        }
+
+        EventSubscription::announce_changes(&subscription.bot).await;
+        failures.store(0, Ordering::Relaxed);
+    }
+}
+
+/// Work through the queue of events for a single endpoint
+async fn run_queue(db: Database, client: reqwest::Client, mut queue: mpsc::Receiver<Delivery>) {
+    let semaphore = Arc::new(Semaphore::new(MAX_IN_FLIGHT));
+    let failures = Arc::new(AtomicU32::new(0));
+
+    while let Some((subscription, body)) = queue.recv().await {
+        let Ok(permit) = semaphore.clone().acquire_owned().await else {
+            break;
+        };
+
+        let db = db.clone();
+        let client = client.clone();
+        let failures = failures.clone();
+        tokio::spawn(async move {
+            deliver(&db, &client, &failures, &subscription, &body).await;
+            drop(permit);
+        });
+    }
+}
+
+/// Bounded delivery queues, one for each subscription
+pub struct Queues {
+    db: Database,
+    client: reqwest::Client,
+    senders: HashMap<String, mpsc::Sender<Delivery>>,
+}
+
+impl Queues {
+    pub fn new(db: Database, client: reqwest::Client) -> Queues {
+        Queues {
+            db,
+            client,
+            senders: HashMap::new(),
+        }
+    }
+
+    /// Queue an event for delivery, dropping it if the endpoint has fallen too far behind
+    pub fn push(&mut self, subscription: &Arc<EventSubscription>, body: String) {
+        let sender = self
+            .senders
+            .entry(subscription.id.clone())
+            .or_insert_with(|| {
+                let (sender, receiver) = mpsc::channel(QUEUE_SIZE);
+                tokio::spawn(run_queue(self.db.clone(), self.client.clone(), receiver));
+                sender
+            });
+
+        if let Err(TrySendError::Full(_)) = sender.try_send((subscription.clone(), body)) {
+            log::warn!(
+                "Delivery queue for event subscription {} is full, dropping event",
+                subscription.id
+            );
+        }
+    }
+
+    /// Stop the queues of subscriptions which are no longer active
+    ///
+    /// Events which are already queued are still delivered.
+    pub fn retain(&mut self, active: &HashSet<String>) {
+        self.senders.retain(|id, _| active.contains(id));
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use revolt_config::{capture_error, configure};
+use revolt_database::DatabaseInfo;
+use tokio::time::sleep;
+
+mod delivery;
+mod routing;
+
+#[tokio::main]
+async fn main() {
+    configure!(eventd);
+
+    let db = DatabaseInfo::Auto.connect().await.expect("database");
+
+    loop {
+        if let Err(error) = routing::listen(&db).await {
+            log::error!("event delivery stopped unexpectedly: {error:?}\nRetrying after 10s");
+            capture_error(&error);
+        }
+
+        sleep(Duration::from_secs(10)).await;
+    }
+}
@@ -0,0 +1,627 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+use fred::{
+    interfaces::{ClientLike, EventInterface, PubsubInterface},
+    types::{Builder, ReconnectPolicy, RedisConfig, RedisValue},
+};
+use redis_kiss::{PayloadType, REDIS_PAYLOAD_TYPE, REDIS_URI};
+use revolt_config::report_internal_error;
+use revolt_database::{
+    events::client::EventV1,
+    util::{http::public_client, permissions::DatabasePermissionQuery},
+    Channel, Database, EventSubscription, EVENT_SUBSCRIPTIONS_TOPIC,
+};
+use revolt_models::v0::{validate_public_https_url, FieldsChannel, FieldsMember};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+
+use crate::delivery::Queues;
+
+/// Subscriptions listening on each topic
+type Routes = HashMap<String, Vec<Arc<EventSubscription>>>;
+
+/// Find every topic a bot receives events on
+///
+/// This mirrors what a bot would be subscribed to over the websocket:
+/// its own topics, its direct messages and groups, and the servers
+/// it is a member of along with the channels and threads it can view in them.
+async fn bot_topics(db: &Database, bot_id: &str) -> Result<HashSet<String>> {
+    let user = db.fetch_user(bot_id).await?;
+
+    let mut topics = HashSet::new();
+    topics.insert(format!("{bot_id}!"));
+    topics.insert(bot_id.to_string());
+
+    for channel in db.find_direct_messages(bot_id).await? {
+        topics.insert(channel.id().to_string());
+    }
+
+    for member in db.fetch_all_memberships(bot_id).await? {
+        let server = match db.fetch_server(&member.id.server).await {
+            Ok(server) => server,
+            Err(error) => {
+                log::warn!(
+                    "Skipping server {} for bot {bot_id}: {error:?}",
+                    member.id.server
+                );
+
+                continue;
+            }
+        };
+
+        topics.insert(server.id.clone());
+        topics.insert(format!("{}u", server.id));
+
+        let channels = db.fetch_channels(&server.channels).await?;
+        let parent_ids: Vec<String> = channels
+            .iter()
+            .map(|channel| channel.id().to_string())
+            .collect();
+
+        let threads = db.fetch_threads(&parent_ids, true).await?;
+        for channel in channels.iter().chain(&threads) {
+            let mut query = DatabasePermissionQuery::new(db, &user)
+                .channel(channel)
+                .server(&server)
+                .member(&member);
+
+            if let Channel::Thread { parent, .. } = channel {
+                if let Some(parent) = channels.iter().find(|channel| channel.id() == parent) {
+                    query = query.parent(parent);
+                }
+            }
+
+            if calculate_channel_permissions(&mut query)
+                .await
+                .has_channel_permission(ChannelPermission::ViewChannel)
+            {
+                topics.insert(channel.id().to_string());
+            }
+        }
+    }
+
+    Ok(topics)
+}
+
+/// Add routes from each topic to a bot's subscriptions
+fn add_routes(
+    routes: &mut Routes,
+    subscriptions: &[Arc<EventSubscription>],
+    topics: HashSet<String>,
+) {
+    for topic in topics {
+        routes
+            .entry(topic)
+            .or_default()
+            .extend(subscriptions.iter().cloned());
+    }
+}
+
+/// Copy routes, leaving out those which lead to a bot's subscriptions
+fn without_bot(routes: &Routes, bot_id: &str) -> Routes {
+    routes
+        .iter()
+        .filter_map(|(topic, subscriptions)| {
+            let subscriptions: Vec<_> = subscriptions
+                .iter()
+                .filter(|subscription| subscription.bot != bot_id)
+                .cloned()
+                .collect();
+
+            (!subscriptions.is_empty()).then(|| (topic.clone(), subscriptions))
+        })
+        .collect()
+}
+
+/// Ids of every subscription in the routing table
+fn subscription_ids(routes: &Routes) -> HashSet<String> {
+    routes
+        .values()
+        .flatten()
+        .map(|subscription| subscription.id.clone())
+        .collect()
+}
+
+/// Bots with a subscription listening on a topic
+fn bots_on(routes: &Routes, topic: &str) -> HashSet<String> {
+    routes
+        .get(topic)
+        .into_iter()
+        .flatten()
+        .map(|subscription| subscription.bot.clone())
+        .collect()
+}
+
+/// Find the channel an event is about, if bots must be able to view it to receive the event
+///
+/// Events about server channels and threads are published to the whole server.
+fn channel_of(event: &EventV1) -> Option<&str> {
+    match event {
+        EventV1::ChannelCreate(channel) | EventV1::ThreadCreate(channel) => Some(channel.id()),
+        EventV1::ChannelUpdate { id, .. }
+        | EventV1::ThreadUpdate { id, .. }
+        | EventV1::ThreadArchive { id, .. } => Some(id),
+        _ => None,
+    }
+}
+
+/// Find bots on a topic whose topics may change because of an event
+///
+/// These are the events which make bonfire recalculate what a client is subscribed to.
+fn bots_to_rebuild(routes: &Routes, topic: &str, event: &EventV1) -> HashSet<String> {
+    let mut bots = bots_on(routes, topic);
+    match event {
+        EventV1::ServerCreate { .. }
+        | EventV1::ServerDelete { .. }
+        | EventV1::ServerRoleDelete { .. }
+        | EventV1::ChannelCreate(_)
+        | EventV1::ChannelDelete { .. }
+        | EventV1::ThreadCreate(_) => bots,
+        EventV1::ServerUpdate { data, .. } if data.default_permissions.is_some() => bots,
+        EventV1::ServerRoleUpdate { data, .. }
+            if data.permissions.is_some() || data.rank.is_some() =>
+        {
+            bots
+        }
+        EventV1::ChannelUpdate { data, clear, .. }
+            if data.role_permissions.is_some()
+                || data.default_permissions.is_some()
+                || clear.contains(&FieldsChannel::DefaultPermissions) =>
+        {
+            bots
+        }
+        EventV1::ServerMemberUpdate { id, data, clear }
+            if data.roles.is_some() || clear.contains(&FieldsMember::Roles) =>
+        {
+            bots.retain(|bot| bot == &id.user);
+            bots
+        }
+        EventV1::ServerMemberLeave { user, .. } | EventV1::ChannelGroupLeave { user, .. } => {
+            bots.retain(|bot| bot == user);
+            bots
+        }
+        _ => HashSet::new(),
+    }
+}
+
+/// Group subscriptions which may be delivered to by bot
+///
+/// Subscriptions created before endpoints had to be public are skipped.
+fn group_by_bot(
+    subscriptions: Vec<EventSubscription>,
+) -> HashMap<String, Vec<Arc<EventSubscription>>> {
+    let mut subscriptions_by_bot: HashMap<String, Vec<Arc<EventSubscription>>> = HashMap::new();
+    for subscription in subscriptions {
+        if subscription.disabled {
+            continue;
+        }
+
+        if validate_public_https_url(&subscription.url).is_err() {
+            log::warn!(
+                "Skipping event subscription {} as {} is not a public HTTPS URL",
+                subscription.id,
+                subscription.url
+            );
+
+            continue;
+        }
+
+        subscriptions_by_bot
+            .entry(subscription.bot.clone())
+            .or_default()
+            .push(Arc::new(subscription));
+    }
+
+    subscriptions_by_bot
+}
+
+/// Build the topic routing table from all active subscriptions
+async fn build_routes(db: &Database) -> Result<Routes> {
+    let mut routes = Routes::new();
+    for (bot_id, subscriptions) in group_by_bot(db.fetch_active_event_subscriptions().await?) {
+        match bot_topics(db, &bot_id).await {
+            Ok(topics) => add_routes(&mut routes, &subscriptions, topics),
+            Err(error) => log::warn!("Failed to resolve topics for bot {bot_id}: {error:?}"),
+        }
+    }
+
+    Ok(routes)
+}
+
+/// Rebuild routes for a single bot whose subscriptions have changed
+async fn rebuild_bot_routes(db: &Database, routes: &Routes, bot_id: &str) -> Result<Routes> {
+    let mut new_routes = without_bot(routes, bot_id);
+    if let Some(subscriptions) =
+        group_by_bot(db.fetch_event_subscriptions(bot_id).await?).remove(bot_id)
+    {
+        add_routes(
+            &mut new_routes,
+            &subscriptions,
+            bot_topics(db, bot_id).await?,
+        );
+    }
+
+    Ok(new_routes)
+}
+
+/// Switch to new routes, subscribing to and unsubscribing from topics as needed
+async fn apply_routes(
+    subscriber: &impl PubsubInterface,
+    queues: &mut Queues,
+    routes: &mut Routes,
+    new_routes: Routes,
+) -> Result<()> {
+    for topic in routes
+        .keys()
+        .filter(|topic| !new_routes.contains_key(*topic))
+    {
+        report_internal_error!(subscriber.unsubscribe(topic).await)?;
+    }
+
+    for topic in new_routes
+        .keys()
+        .filter(|topic| !routes.contains_key(*topic))
+    {
+        report_internal_error!(subscriber.subscribe(topic).await)?;
+    }
+
+    queues.retain(&subscription_ids(&new_routes));
+    *routes = new_routes;
+    Ok(())
+}
+
+/// Decode an event received from Redis
+fn decode_event(value: &RedisValue) -> Option<EventV1> {
+    match *REDIS_PAYLOAD_TYPE {
+        PayloadType::Json => value
+            .as_str()
+            .and_then(|s| serde_json::from_str::<EventV1>(s.as_ref()).ok()),
+        PayloadType::Msgpack => value
+            .as_bytes()
+            .and_then(|b| rmp_serde::from_slice::<EventV1>(b).ok()),
+        PayloadType::Bincode => value
+            .as_bytes()
+            .and_then(|b| bincode::deserialize::<EventV1>(b).ok()),
+    }
+}
+
+/// Split bulk events into the events they contain
+fn flatten_event(event: EventV1, events: &mut Vec<EventV1>) {
+    if let EventV1::Bulk { v } = event {
+        for event in v {
+            flatten_event(event, events);
+        }
+    } else {
+        events.push(event);
+    }
+}
+
+/// Serialise an event, along with its type
+fn encode_event(event: &EventV1) -> Option<(String, String)> {
+    let payload = serde_json::to_value(event).ok()?;
+    let event_type = payload.get("type")?.as_str()?.to_string();
+    Some((event_type, payload.to_string()))
+}
+
+/// Queue an event for the subscriptions which want it
+fn push_event(queues: &mut Queues, subscriptions: &[&Arc<EventSubscription>], event: &EventV1) {
+    let Some((event_type, body)) = encode_event(event) else {
+        return;
+    };
+
+    for subscription in subscriptions {
+        if subscription.wants(&event_type) {
+            queues.push(subscription, body.clone());
+        }
+    }
+}
+
+/// Hand an event off to the subscriptions listening on its topic
+///
+/// Routes are rebuilt first for bots whose view of a server changes, and
+/// events about channels are only sent to bots which can view the channel.
+/// Like bonfire, a channel update which hides or reveals a channel is sent
+/// as the channel being deleted or created instead.
+async fn route_event(
+    db: &Database,
+    subscriber: &impl PubsubInterface,
+    queues: &mut Queues,
+    routes: &mut Routes,
+    topic: &str,
+    event: EventV1,
+) -> Result<()> {
+    let recipients: Vec<Arc<EventSubscription>> =
+        routes.get(topic).into_iter().flatten().cloned().collect();
+
+    let channel_id = channel_of(&event).map(str::to_string);
+    let could_view = channel_id
+        .as_deref()
+        .map(|id| bots_on(routes, id))
+        .unwrap_or_default();
+
+    let rebuild = bots_to_rebuild(routes, topic, &event);
+    for bot_id in &rebuild {
+        match rebuild_bot_routes(db, routes, bot_id).await {
+            Ok(new_routes) => apply_routes(subscriber, queues, routes, new_routes).await?,
+            Err(error) => {
+                log::warn!("Failed to reload event subscriptions for {bot_id}: {error:?}")
+            }
+        }
+    }
+
+    // Don't start queues again for subscriptions which were just dropped
+    let active = (!rebuild.is_empty()).then(|| subscription_ids(routes));
+    let recipients: Vec<&Arc<EventSubscription>> = recipients
+        .iter()
+        .filter(|subscription| {
+            active
+                .as_ref()
+                .is_none_or(|active| active.contains(&subscription.id))
+        })
+        .collect();
+
+    let Some(channel_id) = channel_id else {
+        push_event(queues, &recipients, &event);
+        return Ok(());
+    };
+
+    let can_view = bots_on(routes, &channel_id);
+    let (visible, hidden): (Vec<_>, Vec<_>) = recipients
+        .into_iter()
+        .filter(|subscription| {
+            can_view.contains(&subscription.bot) || could_view.contains(&subscription.bot)
+        })
+        .partition(|subscription| can_view.contains(&subscription.bot));
+
+    if !matches!(event, EventV1::ChannelUpdate { .. }) {
+        push_event(queues, &visible, &event);
+        return Ok(());
+    }
+
+    let (unchanged, revealed): (Vec<_>, Vec<_>) = visible
+        .into_iter()
+        .partition(|subscription| could_view.contains(&subscription.bot));
+
+    push_event(queues, &unchanged, &event);
+    push_event(
+        queues,
+        &hidden,
+        &EventV1::ChannelDelete {
+            id: channel_id.clone(),
+        },
+    );
+
+    if !revealed.is_empty() {
+        match db.fetch_channel(&channel_id).await {
+            Ok(channel) => push_event(queues, &revealed, &EventV1::ChannelCreate(channel.into())),
+            Err(error) => log::warn!("Failed to fetch channel {channel_id}: {error:?}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Listen for events and hand them off to subscriptions interested in them
+pub async fn listen(db: &Database) -> Result<()> {
+    let config = revolt_config::config().await;
+    let url = config
+        .database
+        .redis_pubsub
+        .unwrap_or(REDIS_URI.to_string());
+
+    let mut builder = Builder::from_config(report_internal_error!(RedisConfig::from_url(&url))?);
+    builder.set_policy(ReconnectPolicy::new_exponential(8, 100, 30_000, 2));
+    let subscriber = report_internal_error!(builder.build_subscriber_client())?;
+    report_internal_error!(subscriber.init().await)?;
+
+    // Let Fred automatically re-subscribe to tracked channels on reconnect.
+    subscriber.manage_subscriptions();
+
+    let client = report_internal_error!(public_client(Duration::from_secs(10)))?;
+
+    report_internal_error!(subscriber.subscribe(EVENT_SUBSCRIPTIONS_TOPIC).await)?;
+
+    let mut queues = Queues::new(db.clone(), client);
+    let mut routes = Routes::new();
+    let mut message_rx = subscriber.message_rx();
+
+    // Routes are kept up to date from here on by the events which change them
+    apply_routes(
+        &subscriber,
+        &mut queues,
+        &mut routes,
+        build_routes(db).await?,
+    )
+    .await?;
+
+    loop {
+        let message = report_internal_error!(message_rx.recv().await)?;
+        if &*message.channel == EVENT_SUBSCRIPTIONS_TOPIC {
+            let Some(bot_id) = message.value.as_str() else {
+                continue;
+            };
+
+            match rebuild_bot_routes(db, &routes, &bot_id).await {
+                Ok(new_routes) => {
+                    apply_routes(&subscriber, &mut queues, &mut routes, new_routes).await?
+                }
+                Err(error) => {
+                    log::warn!("Failed to reload event subscriptions for {bot_id}: {error:?}")
+                }
+            }
+
+            continue;
+        }
+
+        if !routes.contains_key(&*message.channel) {
+            continue;
+        }
+
+        let Some(event) = decode_event(&message.value) else {
+            log::warn!("Failed to deserialise event for {}", message.channel);
+            continue;
+        };
+
+        let mut events = vec![];
+        flatten_event(event, &mut events);
+
+        for event in events {
+            route_event(
+                db,
+                &subscriber,
+                &mut queues,
+                &mut routes,
+                &message.channel,
+                event,
+            )
+            .await?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc};
+
+    use revolt_database::{events::client::EventV1, EventSubscription};
+    use revolt_models::v0::RemovalIntention;
+
+    use super::{add_routes, bots_to_rebuild, flatten_event, without_bot, Routes};
+
+    fn subscription(id: &str, bot: &str, events: &[&str]) -> Arc<EventSubscription> {
+        Arc::new(EventSubscription {
+            id: id.to_string(),
+            bot: bot.to_string(),
+            url: "https://example.com/events".to_string(),
+            secret: "secret".to_string(),
+            events: events.iter().map(|event| event.to_string()).collect(),
+            disabled: false,
+        })
+    }
+
+    fn topics(topics: &[&str]) -> HashSet<String> {
+        topics.iter().map(|topic| topic.to_string()).collect()
+    }
+
+    fn matching_ids(routes: &Routes, topic: &str, event_type: &str) -> Vec<String> {
+        routes
+            .get(topic)
+            .into_iter()
+            .flatten()
+            .filter(|subscription| subscription.wants(event_type))
+            .map(|subscription| subscription.id.clone())
+            .collect()
+    }
+
+    #[test]
+    fn routes_events_to_interested_subscriptions() {
+        let mut routes = Routes::new();
+        add_routes(
+            &mut routes,
+            &[subscription("a", "bot_a", &["Message"])],
+            topics(&["server", "channel"]),
+        );
+        add_routes(
+            &mut routes,
+            &[subscription("b", "bot_b", &["Message", "ChannelUpdate"])],
+            topics(&["channel"]),
+        );
+
+        assert_eq!(matching_ids(&routes, "server", "Message"), vec!["a"]);
+        assert_eq!(matching_ids(&routes, "channel", "Message"), vec!["a", "b"]);
+        assert_eq!(matching_ids(&routes, "channel", "ChannelUpdate"), vec!["b"]);
+        assert!(matching_ids(&routes, "server", "ChannelUpdate").is_empty());
+        assert!(matching_ids(&routes, "elsewhere", "Message").is_empty());
+    }
+
+    #[test]
+    fn skips_disabled_subscriptions() {
+        let mut disabled = (*subscription("a", "bot_a", &["Message"])).clone();
+        disabled.disabled = true;
+
+        let mut routes = Routes::new();
+        add_routes(&mut routes, &[Arc::new(disabled)], topics(&["channel"]));
+
+        assert!(matching_ids(&routes, "channel", "Message").is_empty());
+    }
+
+    #[test]
+    fn removes_routes_of_a_bot() {
+        let mut routes = Routes::new();
+        add_routes(
+            &mut routes,
+            &[subscription("a", "bot_a", &["Message"])],
+            topics(&["server", "channel"]),
+        );
+        add_routes(
+            &mut routes,
+            &[subscription("b", "bot_b", &["Message"])],
+            topics(&["channel"]),
+        );
+
+        let routes = without_bot(&routes, "bot_a");
+        assert!(!routes.contains_key("server"));
+        assert_eq!(matching_ids(&routes, "channel", "Message"), vec!["b"]);
+    }
+
+    #[test]
+    fn rebuilds_bots_affected_by_an_event() {
+        let mut routes = Routes::new();
+        add_routes(
+            &mut routes,
+            &[subscription("a", "bot_a", &["Message"])],
+            topics(&["server"]),
+        );
+        add_routes(
+            &mut routes,
+            &[subscription("b", "bot_b", &["Message"])],
+            topics(&["server"]),
+        );
+
+        let leave = EventV1::ServerMemberLeave {
+            id: "server".to_string(),
+            user: "bot_a".to_string(),
+            reason: RemovalIntention::Leave,
+        };
+
+        let role_delete = EventV1::ServerRoleDelete {
+            id: "server".to_string(),
+            role_id: "role".to_string(),
+        };
+
+        assert_eq!(
+            bots_to_rebuild(&routes, "server", &leave),
+            topics(&["bot_a"])
+        );
+        assert_eq!(
+            bots_to_rebuild(&routes, "server", &role_delete),
+            topics(&["bot_a", "bot_b"])
+        );
+        assert!(bots_to_rebuild(&routes, "elsewhere", &role_delete).is_empty());
+        assert!(bots_to_rebuild(&routes, "server", &EventV1::Logout).is_empty());
+    }
+
+    #[test]
+    fn flattens_bulk_events() {
+        let mut events = vec![];
+        flatten_event(
+            EventV1::Bulk {
+                v: vec![
+                    EventV1::Authenticated,
+                    EventV1::Bulk {
+                        v: vec![EventV1::Logout],
+                    },
+                ],
+            },
+            &mut events,
+        );
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], EventV1::Authenticated));
+        assert!(matches!(events[1], EventV1::Logout));
+    }
+}
@@ -196,7 +196,10 @@ async fn get_channel_name(id: &str, _server: Option<&str>, db: &Database) -> Res
     let channel = db.fetch_channel(id).await?;
     let name = match channel {
         Channel::DirectMessage { .. } => "DM Channel".to_string(),
-        Channel::Group { name, .. } | Channel::TextChannel { name, .. } => name,
+        Channel::Group { name, .. }
+        | Channel::TextChannel { name, .. }
+        | Channel::ForumChannel { name, .. }
+        | Channel::Thread { name, .. } => name,
         Channel::SavedMessages { .. } => "Err".to_string(),
     };
 
@@ -260,7 +263,9 @@ async fn get_channel_server_id(channel_id: &str, db: &Database) -> Result<String
         Channel::DirectMessage { .. } | Channel::Group { .. } | Channel::SavedMessages { .. } => {
             Err(create_error!(NotFound))
         }
-        Channel::TextChannel { server, .. } => Ok(server),
+        Channel::TextChannel { server, .. }
+        | Channel::ForumChannel { server, .. }
+        | Channel::Thread { server, .. } => Ok(server),
     }
 }
 
@@ -30,6 +30,7 @@ pub async fn web() -> Rocket<Build> {
     // Setup database
     let db = revolt_database::DatabaseInfo::Auto.connect().await.unwrap();
     db.migrate_database().await.unwrap();
+    revolt_database::search::init(&db).await;
 
     // Configure CORS
     let cors = CorsOptions {
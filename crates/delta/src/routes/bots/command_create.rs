@@ -0,0 +1,176 @@
+use revolt_database::{util::reference::Reference, BotCommand, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Bot Command
+///
+/// Register a new command for a bot.
+///
+/// Commands limited to a server can only be created for servers the bot is in.
+#[openapi(tag = "Bots")]
+#[post("/<bot_id>/commands", data = "<data>")]
+pub async fn create_command(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    data: Json<v0::DataCreateCommand>,
+) -> Result<Json<v0::BotCommand>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if let Some(server) = &data.server {
+        db.fetch_member(server, &bot.id).await?;
+    }
+
+    Ok(Json(BotCommand::create(db, &bot.id, data).await?.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{events::client::EventV1, Bot, Member};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn create_and_invoke_command() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (bot, bot_user) = Bot::create(&harness.db, TestHarness::rand_string(), &user, None)
+            .await
+            .expect("`Bot`");
+
+        Member::create(&harness.db, &server, &bot_user, Some(channels.clone()))
+            .await
+            .expect("`Member`");
+
+        let response = harness
+            .client
+            .post(format!("/bots/{}/commands", bot.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataCreateCommand {
+                    name: "roll".to_string(),
+                    description: "Roll a die".to_string(),
+                    options: vec![v0::CommandOption {
+                        name: "sides".to_string(),
+                        description: "Number of sides".to_string(),
+                        option_type: v0::CommandOptionType::Integer,
+                        required: true,
+                        choices: None,
+                    }],
+                    server: Some(server.id.clone()),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let command: v0::BotCommand = response.into_json().await.expect("`BotCommand`");
+        assert_eq!(command.server.as_deref(), Some(server.id.as_str()));
+
+        let response = harness
+            .client
+            .get(format!("/servers/{}/commands?prefix=ro", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let commands: Vec<v0::BotCommand> = response.into_json().await.expect("`BotCommand`s");
+        assert_eq!(commands, vec![command.clone()]);
+
+        // Options must match the type they were registered with
+        let response = harness
+            .client
+            .post(format!("/channels/{}/commands", channels[0].id()))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataInvokeCommand {
+                    bot: bot.id.clone(),
+                    name: "roll".to_string(),
+                    options: vec![v0::CommandOptionData {
+                        name: "sides".to_string(),
+                        value: v0::CommandOptionValue::String("six".to_string()),
+                    }],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/commands", channels[0].id()))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataInvokeCommand {
+                    bot: bot.id.clone(),
+                    name: "roll".to_string(),
+                    options: vec![v0::CommandOptionData {
+                        name: "sides".to_string(),
+                        value: v0::CommandOptionValue::Integer(6),
+                    }],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        drop(response);
+
+        let event = harness
+            .wait_for_event(&format!("{}!", bot.id), |event| {
+                matches!(event, EventV1::CommandInvoke(_))
+            })
+            .await;
+
+        let EventV1::CommandInvoke(invocation) = event else {
+            unreachable!()
+        };
+
+        assert_eq!(invocation.command, command.id);
+        assert_eq!(invocation.user.id, user.id);
+        assert_eq!(
+            invocation.options[0].value,
+            v0::CommandOptionValue::Integer(6)
+        );
+
+        // Server commands are removed along with the bot
+        let response = harness
+            .client
+            .delete(format!("/servers/{}/members/{}", server.id, bot.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        assert!(harness
+            .db
+            .fetch_bot_commands(&bot.id)
+            .await
+            .expect("`BotCommand`s")
+            .is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Bot Command
+///
+/// Delete a command by its id.
+#[openapi(tag = "Bots")]
+#[delete("/<bot_id>/commands/<command>")]
+pub async fn delete_command(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    command: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let command = db.fetch_bot_command(command.id).await?;
+    if command.bot != bot.id {
+        return Err(create_error!(NotFound));
+    }
+
+    command.delete(db).await.map(|_| EmptyResponse)
+}
@@ -0,0 +1,52 @@
+use revolt_database::{util::reference::Reference, Database, PartialBotCommand, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Bot Command
+///
+/// Edit a command's description or options.
+#[openapi(tag = "Bots")]
+#[patch("/<bot_id>/commands/<command>", data = "<data>")]
+pub async fn edit_command(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    command: Reference<'_>,
+    data: Json<v0::DataEditCommand>,
+) -> Result<Json<v0::BotCommand>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let mut command = db.fetch_bot_command(command.id).await?;
+    if command.bot != bot.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if data.description.is_none() && data.options.is_none() {
+        return Ok(Json(command.into()));
+    }
+
+    command
+        .update(
+            db,
+            PartialBotCommand {
+                description: data.description,
+                options: data.options,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(Json(command.into()))
+}
@@ -0,0 +1,30 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Bot Commands
+///
+/// Fetch all commands registered for a bot, both global and per-server.
+///
+/// Only the owner of the bot, or the bot itself, can fetch these.
+#[openapi(tag = "Bots")]
+#[get("/<bot_id>/commands")]
+pub async fn fetch_commands(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+) -> Result<Json<Vec<v0::BotCommand>>> {
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(
+        db.fetch_bot_commands(&bot.id)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+    ))
+}
@@ -113,5 +113,55 @@ mod test {
         let updated_bot: v0::Bot = response.into_json().await.expect("`Bot`");
         assert!(!bot.public);
         assert!(updated_bot.public);
+        drop(response);
+
+        // Interactions can only be delivered to public HTTPS endpoints
+        for url in [
+            "http://example.com/interactions",
+            "https://localhost/interactions",
+            "https://127.0.0.1/interactions",
+            "https://10.0.0.1/interactions",
+            "https://169.254.169.254/interactions",
+            "https://[::1]/interactions",
+        ] {
+            let response = harness
+                .client
+                .patch(format!("/bots/{}", bot.id))
+                .header(ContentType::JSON)
+                .body(
+                    json!(v0::DataEditBot {
+                        interactions_url: Some(url.to_string()),
+                        ..Default::default()
+                    })
+                    .to_string(),
+                )
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::BadRequest);
+        }
+
+        let response = harness
+            .client
+            .patch(format!("/bots/{}", bot.id))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataEditBot {
+                    interactions_url: Some("https://example.com/interactions".to_string()),
+                    ..Default::default()
+                })
+                .to_string(),
+            )
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let updated_bot: v0::Bot = response.into_json().await.expect("`Bot`");
+        assert_eq!(
+            updated_bot.interactions_url,
+            "https://example.com/interactions"
+        );
     }
 }
@@ -1,6 +1,10 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod command_create;
+mod command_delete;
+mod command_edit;
+mod commands_fetch;
 mod create;
 mod delete;
 mod edit;
@@ -8,6 +12,10 @@ mod fetch;
 mod fetch_owned;
 mod fetch_public;
 mod invite;
+mod subscription_create;
+mod subscription_delete;
+mod subscription_edit;
+mod subscriptions_fetch;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -18,5 +26,13 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         fetch_owned::fetch_owned_bots,
         edit::edit_bot,
         delete::delete_bot,
+        commands_fetch::fetch_commands,
+        command_create::create_command,
+        command_edit::edit_command,
+        command_delete::delete_command,
+        subscriptions_fetch::fetch_subscriptions,
+        subscription_create::create_subscription,
+        subscription_edit::edit_subscription,
+        subscription_delete::delete_subscription,
     ]
 }
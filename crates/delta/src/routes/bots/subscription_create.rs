@@ -0,0 +1,143 @@
+use revolt_database::{util::reference::Reference, Database, EventSubscription, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Event Subscription
+///
+/// Register an HTTPS endpoint to deliver a bot's events to.
+///
+/// Events are sent from the servers the bot is in, along with its
+/// direct messages and groups, as signed `POST` requests.
+#[openapi(tag = "Bots")]
+#[post("/<bot_id>/subscriptions", data = "<data>")]
+pub async fn create_subscription(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    data: Json<v0::DataCreateEventSubscription>,
+) -> Result<Json<v0::EventSubscription>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(
+        EventSubscription::create(db, &bot.id, data).await?.into(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Bot;
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn manage_subscriptions() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+
+        let (bot, _) = Bot::create(&harness.db, TestHarness::rand_string(), &user, None)
+            .await
+            .expect("`Bot`");
+
+        // Plain HTTP and internal endpoints are refused
+        for url in [
+            "http://example.com/events",
+            "https://localhost/events",
+            "https://192.168.1.1/events",
+            "https://[fe80::1]/events",
+        ] {
+            let response = harness
+                .client
+                .post(format!("/bots/{}/subscriptions", bot.id))
+                .header(ContentType::JSON)
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .body(
+                    json!(v0::DataCreateEventSubscription {
+                        url: url.to_string(),
+                        events: vec!["Message".to_string()],
+                    })
+                    .to_string(),
+                )
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::BadRequest);
+        }
+
+        let response = harness
+            .client
+            .post(format!("/bots/{}/subscriptions", bot.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataCreateEventSubscription {
+                    url: "https://example.com/events".to_string(),
+                    events: vec!["Message".to_string()],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let subscription: v0::EventSubscription =
+            response.into_json().await.expect("`EventSubscription`");
+        assert!(!subscription.secret.is_empty());
+
+        let response = harness
+            .client
+            .patch(format!(
+                "/bots/{}/subscriptions/{}",
+                bot.id, subscription.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataEditEventSubscription {
+                    url: None,
+                    events: Some(vec!["Message".to_string(), "MessageUpdate".to_string()]),
+                    enabled: None,
+                    regenerate_secret: true,
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let updated: v0::EventSubscription =
+            response.into_json().await.expect("`EventSubscription`");
+        assert_eq!(updated.events.len(), 2);
+        assert_ne!(updated.secret, subscription.secret);
+
+        let response = harness
+            .client
+            .delete(format!(
+                "/bots/{}/subscriptions/{}",
+                bot.id, subscription.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        assert!(harness
+            .db
+            .fetch_event_subscriptions(&bot.id)
+            .await
+            .expect("`EventSubscription`s")
+            .is_empty());
+    }
+}
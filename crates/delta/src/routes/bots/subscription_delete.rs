@@ -0,0 +1,28 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Event Subscription
+///
+/// Stop delivering a bot's events to an endpoint.
+#[openapi(tag = "Bots")]
+#[delete("/<bot_id>/subscriptions/<subscription>")]
+pub async fn delete_subscription(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    subscription: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let subscription = db.fetch_event_subscription(subscription.id).await?;
+    if subscription.bot != bot.id {
+        return Err(create_error!(NotFound));
+    }
+
+    subscription.delete(db).await.map(|_| EmptyResponse)
+}
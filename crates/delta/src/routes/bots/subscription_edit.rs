@@ -0,0 +1,59 @@
+use revolt_database::{util::reference::Reference, Database, PartialEventSubscription, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Event Subscription
+///
+/// Change where or which events are delivered, resume delivery to
+/// an endpoint which was disabled, or generate a new signing secret.
+#[openapi(tag = "Bots")]
+#[patch("/<bot_id>/subscriptions/<subscription>", data = "<data>")]
+pub async fn edit_subscription(
+    db: &State<Database>,
+    user: User,
+    bot_id: Reference<'_>,
+    subscription: Reference<'_>,
+    data: Json<v0::DataEditEventSubscription>,
+) -> Result<Json<v0::EventSubscription>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let bot = bot_id.as_bot(db).await?;
+    if bot.owner != user.id && bot.id != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let mut subscription = db.fetch_event_subscription(subscription.id).await?;
+    if subscription.bot != bot.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if data.url.is_none()
+        && data.events.is_none()
+        && data.enabled.is_none()
+        && !data.regenerate_secret
+    {
+        return Ok(Json(subscription.into()));
+    }
+
+    subscription
+        .update(
+            db,
+            PartialEventSubscription {
+                url: data.url,
+                events: data.events,
+                disabled: data.enabled.map(|enabled| !enabled),
+                ..Default::default()
+            },
+            data.regenerate_secret,
+        )
+        .await?;
+
+    Ok(Json(subscription.into()))
+}
@@ -29,6 +29,7 @@ pub async fn ack(
         .await
         .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
 
+    channel.send_receipt(db, &user, message.id).await?;
     channel
         .ack(&user.id, message.id, amqp)
         .await
@@ -66,7 +66,7 @@ pub async fn delete(
                 remove_user_from_voice_channel(voice_client, &user_voice_channel, &user.id).await?;
             };
         }
-        Channel::TextChannel { name, server, .. } => {
+        Channel::TextChannel { name, server, .. } | Channel::ForumChannel { name, server, .. } => {
             permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
             channel.delete(db).await?;
 
@@ -79,6 +79,14 @@ pub async fn delete(
 
             delete_voice_channel(voice_client, &UserVoiceChannel::from_channel(&channel)).await?;
         }
+        Channel::Thread { owner, .. } => {
+            if owner != &user.id {
+                permissions
+                    .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+            }
+
+            channel.delete(db).await?;
+        }
     };
 
     Ok(EmptyResponse)
@@ -46,6 +46,10 @@ pub async fn edit(
         && data.owner.is_none()
         && data.voice.is_none()
         && data.slowmode.is_none()
+        && data.message_ttl.is_none()
+        && data.announcement.is_none()
+        && data.default_sort.is_none()
+        && data.require_tag.is_none()
         && data.remove.is_empty()
     {
         return Ok(Json(channel.into()));
@@ -98,12 +102,23 @@ pub async fn edit(
     let before_channel = channel.clone();
 
     match &mut channel {
+        Channel::DirectMessage { message_ttl, .. } => {
+            if data.remove.contains(&v0::FieldsChannel::MessageTtl) {
+                message_ttl.take();
+            }
+
+            if let Some(new_message_ttl) = data.message_ttl {
+                *message_ttl = Some(new_message_ttl);
+                partial.message_ttl = Some(new_message_ttl);
+            }
+        }
         Channel::Group {
             id,
             name,
             description,
             icon,
             nsfw,
+            message_ttl,
             ..
         } => {
             if data.remove.contains(&v0::FieldsChannel::Icon) {
@@ -120,6 +135,9 @@ pub async fn edit(
                     v0::FieldsChannel::Icon => {
                         icon.take();
                     }
+                    v0::FieldsChannel::MessageTtl => {
+                        message_ttl.take();
+                    }
                     _ => {}
                 }
             }
@@ -144,6 +162,11 @@ pub async fn edit(
                 partial.nsfw = Some(new_nsfw);
             }
 
+            if let Some(new_message_ttl) = data.message_ttl {
+                *message_ttl = Some(new_message_ttl);
+                partial.message_ttl = Some(new_message_ttl);
+            }
+
             // Send out mutation system messages.
             if let Some(name) = &partial.name {
                 SystemMessage::ChannelRenamed {
@@ -208,6 +231,8 @@ pub async fn edit(
             nsfw,
             voice,
             slowmode,
+            message_ttl,
+            announcement,
             ..
         } => {
             if data.remove.contains(&v0::FieldsChannel::Icon) {
@@ -230,6 +255,9 @@ pub async fn edit(
                     v0::FieldsChannel::Slowmode => {
                         slowmode.take();
                     }
+                    v0::FieldsChannel::MessageTtl => {
+                        message_ttl.take();
+                    }
                     _ => {}
                 }
             }
@@ -263,6 +291,83 @@ pub async fn edit(
                 *slowmode = Some(new_slowmode);
                 partial.slowmode = Some(new_slowmode);
             }
+
+            if let Some(new_message_ttl) = data.message_ttl {
+                *message_ttl = Some(new_message_ttl);
+                partial.message_ttl = Some(new_message_ttl);
+            }
+
+            if let Some(new_announcement) = data.announcement {
+                *announcement = new_announcement;
+                partial.announcement = Some(new_announcement);
+            }
+        }
+        Channel::ForumChannel {
+            id,
+            name,
+            description,
+            icon,
+            nsfw,
+            default_sort,
+            require_tag,
+            message_ttl,
+            ..
+        } => {
+            if data.remove.contains(&v0::FieldsChannel::Icon) {
+                if let Some(icon) = &icon {
+                    db.mark_attachment_as_deleted(&icon.id).await?;
+                }
+            }
+
+            for field in &data.remove {
+                match field {
+                    v0::FieldsChannel::Description => {
+                        description.take();
+                    }
+                    v0::FieldsChannel::Icon => {
+                        icon.take();
+                    }
+                    v0::FieldsChannel::MessageTtl => {
+                        message_ttl.take();
+                    }
+                    _ => {}
+                }
+            }
+
+            if let Some(icon_id) = data.icon {
+                partial.icon = Some(File::use_channel_icon(db, &icon_id, id, &user.id).await?);
+                *icon = partial.icon.clone();
+            }
+
+            if let Some(new_name) = data.name {
+                *name = new_name.clone();
+                partial.name = Some(new_name);
+            }
+
+            if let Some(new_description) = data.description {
+                partial.description = Some(new_description);
+                *description = partial.description.clone();
+            }
+
+            if let Some(new_nsfw) = data.nsfw {
+                *nsfw = new_nsfw;
+                partial.nsfw = Some(new_nsfw);
+            }
+
+            if let Some(new_message_ttl) = data.message_ttl {
+                *message_ttl = Some(new_message_ttl);
+                partial.message_ttl = Some(new_message_ttl);
+            }
+
+            if let Some(new_default_sort) = data.default_sort {
+                *default_sort = new_default_sort.clone().into();
+                partial.default_sort = Some(new_default_sort.into());
+            }
+
+            if let Some(new_require_tag) = data.require_tag {
+                *require_tag = new_require_tag;
+                partial.require_tag = Some(new_require_tag);
+            }
         }
         _ => return Err(create_error!(InvalidOperation)),
     };
@@ -0,0 +1,96 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    ChannelExport, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Export Channel
+///
+/// Request a transcript of every message in the given channel.
+///
+/// The export is processed in the background, a `ChannelExportUpdate` event
+/// is sent once the JSON and HTML transcripts are ready to download.
+///
+/// Requires `ReadMessageHistory` and `ExportMessages`.
+#[openapi(tag = "Channel Information")]
+#[post("/<target>/exports")]
+pub async fn create_export(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+) -> Result<Json<v0::ChannelExport>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ReadMessageHistory)?;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ExportMessages)?;
+
+    ChannelExport::create(db, channel.id(), &user.id)
+        .await
+        .map(|export| Json(export.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Member;
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn export_requires_permission() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+
+        let (_, channels) = Member::create(&harness.db, &server, &owner, Some(channels))
+            .await
+            .expect("Failed to create member");
+        Member::create(&harness.db, &server, &user, Some(channels.clone()))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/exports", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/exports", channel.id()))
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let export: v0::ChannelExport = response.into_json().await.unwrap();
+        assert_eq!(export.status, v0::ChannelExportStatus::Pending);
+        assert_eq!(export.requester, owner.id);
+
+        // Requesting again while pending returns the same export
+        let response = harness
+            .client
+            .post(format!("/channels/{}/exports", channel.id()))
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        let again: v0::ChannelExport = response.into_json().await.unwrap();
+        assert_eq!(again.id, export.id);
+    }
+}
@@ -0,0 +1,23 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Channel Export
+///
+/// Fetch the progress of an export you requested, including its transcripts once complete.
+#[openapi(tag = "Channel Information")]
+#[get("/<target>/exports/<export_id>")]
+pub async fn fetch_export(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    export_id: Reference<'_>,
+) -> Result<Json<v0::ChannelExport>> {
+    let export = db.fetch_channel_export(export_id.id).await?;
+    if export.channel != target.id || export.requester != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(export.into()))
+}
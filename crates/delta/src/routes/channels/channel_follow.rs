@@ -0,0 +1,103 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogEntryAction, Channel, Database, User, Webhook,
+};
+use revolt_models::v0;
+use revolt_permissions::{
+    calculate_channel_permissions, ChannelPermission, DEFAULT_WEBHOOK_PERMISSIONS,
+};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+
+use crate::util::audit_log_reason::AuditLogReason;
+
+/// # Follow Announcement Channel
+///
+/// Subscribe a channel to messages published in an announcement channel.
+///
+/// Published messages are delivered through a webhook created in the
+/// destination channel, deleting the webhook unfollows the channel.
+///
+/// Requires `ManageWebhooks` in the destination channel.
+#[openapi(tag = "Webhooks")]
+#[post("/<target>/followers", data = "<data>")]
+pub async fn follow_channel(
+    db: &State<Database>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    data: Json<v0::DataFollowChannel>,
+) -> Result<Json<v0::Webhook>> {
+    let data = data.into_inner();
+
+    let source = target.as_channel(db).await?;
+    let Channel::TextChannel {
+        server,
+        announcement: true,
+        ..
+    } = &source
+    else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&source);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let destination = Reference::from_unchecked(&data.channel_id)
+        .as_channel(db)
+        .await?;
+
+    let Channel::TextChannel {
+        server: destination_server,
+        ..
+    } = &destination
+    else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    if source.id() == destination.id() {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&destination);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageWebhooks)?;
+
+    if db
+        .fetch_webhooks_following(source.id())
+        .await?
+        .iter()
+        .any(|webhook| webhook.channel_id == destination.id())
+    {
+        return Err(create_error!(AlreadyFollowing));
+    }
+
+    let server = db.fetch_server(server).await?;
+
+    let webhook = Webhook {
+        id: Ulid::new().to_string(),
+        name: server.name.chars().take(32).collect(),
+        avatar: None,
+        creator_id: user.id.clone(),
+        channel_id: destination.id().to_string(),
+        permissions: *DEFAULT_WEBHOOK_PERMISSIONS,
+        token: Some(nanoid::nanoid!(64)),
+        source_channel_id: Some(source.id().to_string()),
+    };
+
+    webhook.create(db).await?;
+
+    AuditLogEntryAction::WebhookCreate {
+        webhook: webhook.id.clone(),
+        name: webhook.name.clone(),
+        channel: webhook.channel_id.clone(),
+    }
+    .insert(db, destination_server.clone(), reason, user.id, None)
+    .await;
+
+    Ok(Json(webhook.into()))
+}
@@ -0,0 +1,125 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Read Receipts
+///
+/// Fetch the last message read by each other recipient of a direct message or group.
+///
+/// Only recipients who share read receipts are included.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/receipts")]
+pub async fn fetch_receipts(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+) -> Result<Json<Vec<v0::ChannelReceipt>>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let recipients = channel
+        .users()
+        .map_err(|_| create_error!(InvalidOperation))?
+        .into_iter()
+        .filter(|id| id != &user.id)
+        .collect::<Vec<String>>();
+
+    let sharing = db
+        .fetch_users(&recipients)
+        .await?
+        .into_iter()
+        .filter(|recipient| recipient.read_receipts == Some(true))
+        .map(|recipient| recipient.id)
+        .collect::<Vec<String>>();
+
+    Ok(Json(
+        db.fetch_channel_unreads(channel.id())
+            .await?
+            .into_iter()
+            .filter(|unread| sharing.contains(&unread.id.user))
+            .filter_map(|unread| {
+                Some(v0::ChannelReceipt {
+                    message_id: unread.last_id?,
+                    user: unread.id.user,
+                })
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Channel, PartialUser};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn fetch_receipts_of_sharing_recipients() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (_, _, sharing) = harness.new_user().await;
+        let (_, _, private) = harness.new_user().await;
+
+        harness
+            .db
+            .update_user(
+                &sharing.id,
+                &PartialUser {
+                    read_receipts: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+        let group = Channel::create_group(
+            &harness.db,
+            v0::DataCreateGroup {
+                users: [sharing.id.clone(), private.id.clone()].into(),
+                ..Default::default()
+            },
+            user.id.clone(),
+        )
+        .await
+        .expect("`Channel`");
+
+        let message_id = ulid::Ulid::new().to_string();
+        for recipient in [&sharing.id, &private.id] {
+            #[allow(clippy::disallowed_methods)]
+            harness
+                .db
+                .acknowledge_message(group.id(), recipient, &message_id)
+                .await
+                .unwrap();
+        }
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}/receipts", group.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let receipts: Vec<v0::ChannelReceipt> =
+            response.into_json().await.expect("`Vec<ChannelReceipt>`");
+        assert_eq!(
+            receipts,
+            vec![v0::ChannelReceipt {
+                user: sharing.id,
+                message_id,
+            }]
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use revolt_database::{
+    events::client::EventV1,
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission, PermissionQuery};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use rocket_empty::EmptyResponse;
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Invoke Command
+///
+/// Invoke a command belonging to a bot in this channel.
+///
+/// The bot receives a `CommandInvoke` event with the validated options.
+#[openapi(tag = "Interactions")]
+#[post("/<target>/commands", data = "<data>")]
+pub async fn invoke_command(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    data: Json<v0::DataInvokeCommand>,
+) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if user.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    // The bot must be able to see the channel the command is invoked in
+    let bot = db.fetch_bot(&data.bot).await?;
+    if let Some(server) = channel.server() {
+        db.fetch_member(server, &bot.id).await?;
+    } else if !channel.users()?.contains(&bot.id) {
+        return Err(create_error!(NotFound));
+    }
+
+    // Commands limited to this server take precedence over global ones
+    let command = db
+        .fetch_server_commands(&[bot.id.clone()], channel.server())
+        .await?
+        .into_iter()
+        .filter(|command| command.name == data.name)
+        .max_by_key(|command| command.server.is_some())
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    command
+        .validate_options(db, &channel, &data.options)
+        .await?;
+
+    query.are_we_a_member().await;
+    let invocation = v0::CommandInvocation {
+        id: Ulid::new().to_string(),
+        command: command.id,
+        name: command.name,
+        channel: channel.id().to_string(),
+        options: data.options,
+        user: user
+            .clone()
+            .into_known_static(revolt_presence::is_online(&user.id).await)
+            .await,
+        member: query
+            .member_ref()
+            .as_ref()
+            .map(|member| member.clone().into_owned().into()),
+    };
+
+    EventV1::CommandInvoke(invocation).private(bot.id).await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,214 @@
+use revolt_database::{
+    util::{
+        idempotency::IdempotencyKey, permissions::DatabasePermissionQuery, reference::Reference,
+    },
+    Channel, Database, Message, User, AMQP,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Forum Post
+///
+/// Create a new post in a forum channel along with its opening message.
+#[openapi(tag = "Forums")]
+#[post("/<target>/posts", data = "<data>")]
+pub async fn create_post(
+    db: &State<Database>,
+    amqp: &State<AMQP>,
+    user: User,
+    target: Reference<'_>,
+    data: Json<v0::DataCreateForumPost>,
+    idempotency: IdempotencyKey,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let forum = target.as_channel(db).await?;
+    if !matches!(forum, Channel::ForumChannel { .. }) {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    let post =
+        Channel::create_forum_post(db, &forum, data.title, data.applied_tags, user.id.clone())
+            .await?;
+
+    // Send the opening message into the post, a post can't exist without it
+    if let Err(error) = Message::create_from_user(
+        db,
+        Some(amqp),
+        post.clone(),
+        &user,
+        data.message,
+        idempotency,
+    )
+    .await
+    {
+        post.delete(db).await?;
+        return Err(error);
+    }
+
+    Ok(Json(post.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{events::client::EventV1, Channel, ForumTag, PartialChannel};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn create_post_with_required_tag() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (mut server, _) = harness.new_server(&user).await;
+
+        let mut forum = Channel::create_server_channel(
+            &harness.db,
+            &mut server,
+            v0::DataCreateServerChannel {
+                channel_type: v0::LegacyServerChannelType::Forum,
+                name: "Support".to_string(),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect("`Channel`");
+
+        forum
+            .update(
+                &harness.db,
+                PartialChannel {
+                    tags: Some(vec![ForumTag {
+                        id: "bug".to_string(),
+                        name: "Bug".to_string(),
+                        emoji: None,
+                    }]),
+                    require_tag: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update forum");
+
+        let post = |applied_tags: Vec<String>| {
+            json!(v0::DataCreateForumPost {
+                title: "It crashed".to_string(),
+                applied_tags,
+                message: v0::DataMessageSend {
+                    content: Some("Steps to reproduce".to_string()),
+                    nonce: None,
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade: None,
+                    interactions: None,
+                    components: None,
+                    poll: None,
+                    expires_in: None,
+                    flags: None,
+                },
+            })
+            .to_string()
+        };
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/posts", forum.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(post(vec![]))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        // A rejected opening message doesn't leave an empty post behind
+        let response = harness
+            .client
+            .post(format!("/channels/{}/posts", forum.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataCreateForumPost {
+                    title: "It crashed".to_string(),
+                    applied_tags: vec!["bug".to_string()],
+                    message: v0::DataMessageSend {
+                        content: None,
+                        nonce: None,
+                        attachments: None,
+                        replies: None,
+                        embeds: None,
+                        masquerade: None,
+                        interactions: None,
+                        components: None,
+                        poll: None,
+                        expires_in: None,
+                        flags: None,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::UnprocessableEntity);
+        drop(response);
+
+        assert!(harness
+            .db
+            .fetch_threads(&[forum.id().to_string()], true)
+            .await
+            .expect("`Vec<Channel>`")
+            .is_empty());
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/posts", forum.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(post(vec!["bug".to_string()]))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let post: v0::Channel = response.into_json().await.expect("`Channel`");
+
+        let v0::Channel::Thread {
+            id,
+            parent,
+            applied_tags,
+            ..
+        } = &post
+        else {
+            panic!("Expected a thread");
+        };
+
+        assert_eq!(parent, forum.id());
+        assert_eq!(applied_tags, &vec!["bug".to_string()]);
+
+        harness
+            .wait_for_event(&server.id, |event| match event {
+                EventV1::ThreadCreate(channel) => channel.id() == id,
+                _ => false,
+            })
+            .await;
+
+        harness.wait_for_message(id).await;
+    }
+}
@@ -0,0 +1,81 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, PartialChannel, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Forum Post
+///
+/// Rename, archive or change the tags of a post in a forum channel.
+#[openapi(tag = "Forums")]
+#[patch("/<target>/posts/<post>", data = "<data>")]
+pub async fn edit_post(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    post: Reference<'_>,
+    data: Json<v0::DataEditThread>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let forum = target.as_channel(db).await?;
+    let Channel::ForumChannel {
+        id,
+        tags,
+        require_tag,
+        ..
+    } = &forum
+    else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let mut post = post.as_channel(db).await?;
+    let Channel::Thread { parent, owner, .. } = &post else {
+        return Err(create_error!(NotFound));
+    };
+
+    if parent != id {
+        return Err(create_error!(NotFound));
+    }
+
+    // Authors may manage their own posts
+    if owner != &user.id {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    if let Some(applied_tags) = &data.applied_tags {
+        Channel::validate_forum_tags(tags, *require_tag, applied_tags)?;
+    }
+
+    if data.name.is_some() || data.applied_tags.is_some() {
+        post.update(
+            db,
+            PartialChannel {
+                name: data.name,
+                applied_tags: data.applied_tags,
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+    }
+
+    if let Some(archived) = data.archived {
+        post.set_thread_archived(db, archived).await?;
+    }
+
+    Ok(Json(post.into()))
+}
@@ -0,0 +1,66 @@
+use std::cmp::Reverse;
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, ForumSortOrder, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Forum Posts
+///
+/// Fetch the posts in a forum channel.
+#[openapi(tag = "Forums")]
+#[get("/<target>/posts?<options..>")]
+pub async fn fetch_posts(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    options: v0::OptionsFetchForumPosts,
+) -> Result<Json<Vec<v0::Channel>>> {
+    let forum = target.as_channel(db).await?;
+    let Channel::ForumChannel {
+        id, default_sort, ..
+    } = &forum
+    else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let mut posts = db
+        .fetch_threads(&[id.clone()], options.include_archived.unwrap_or_default())
+        .await?;
+
+    if let Some(tag) = &options.tag {
+        posts.retain(|post| {
+            matches!(post, Channel::Thread { applied_tags, .. } if applied_tags.contains(tag))
+        });
+    }
+
+    let sort = options
+        .sort
+        .map(Into::into)
+        .unwrap_or_else(|| default_sort.clone());
+
+    // Ids and message ids are ULIDs, so they sort chronologically
+    posts.sort_by_cached_key(|post| {
+        Reverse(match (post, &sort) {
+            (
+                Channel::Thread {
+                    last_message_id: Some(last_message_id),
+                    ..
+                },
+                ForumSortOrder::LatestActivity,
+            ) => last_message_id.clone(),
+            _ => post.id().to_string(),
+        })
+    });
+
+    Ok(Json(posts.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,69 @@
+use revolt_config::config;
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, ForumTag, PartialChannel, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Forum Tag
+///
+/// Create a new tag which can be applied to posts in a forum channel.
+#[openapi(tag = "Forums")]
+#[post("/<target>/tags", data = "<data>")]
+pub async fn create_tag(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    data: Json<v0::DataCreateForumTag>,
+) -> Result<Json<v0::ForumTag>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut forum = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let Channel::ForumChannel { tags, .. } = &forum else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let config = config().await;
+    if tags.len() >= config.features.limits.global.forum_tags {
+        return Err(create_error!(TooManyForumTags {
+            max: config.features.limits.global.forum_tags,
+        }));
+    }
+
+    let tag = ForumTag {
+        id: Ulid::new().to_string(),
+        name: data.name,
+        emoji: data.emoji,
+    };
+
+    let mut tags = tags.clone();
+    tags.push(tag.clone());
+
+    forum
+        .update(
+            db,
+            PartialChannel {
+                tags: Some(tags),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(tag.into()))
+}
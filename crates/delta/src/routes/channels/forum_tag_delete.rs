@@ -0,0 +1,57 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, PartialChannel, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Forum Tag
+///
+/// Delete a tag from a forum channel.
+#[openapi(tag = "Forums")]
+#[delete("/<target>/tags/<tag_id>")]
+pub async fn delete_tag(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    tag_id: String,
+) -> Result<EmptyResponse> {
+    let mut forum = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let Channel::ForumChannel { tags, .. } = &forum else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    if !tags.iter().any(|tag| tag.id == tag_id) {
+        return Err(create_error!(NotFound));
+    }
+
+    let tags = tags
+        .iter()
+        .filter(|tag| tag.id != tag_id)
+        .cloned()
+        .collect();
+
+    forum
+        .update(
+            db,
+            PartialChannel {
+                tags: Some(tags),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    // Existing posts should no longer reference the tag
+    forum
+        .remove_tag_from_posts(db, &tag_id)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,119 @@
+use revolt_database::{
+    events::client::EventV1,
+    util::{
+        interactions::{fetch_interaction, take_interaction},
+        reference::Reference,
+    },
+    Database, Message, PartialMessage, User,
+};
+use revolt_models::v0::{self, Embed, MessageFlags};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use rocket_empty::EmptyResponse;
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Respond to Interaction
+///
+/// Respond to a component being used on one of this bot's messages,
+/// either by updating the message or replying only to the user who used it.
+///
+/// Interactions can be responded to once, within 15 minutes.
+#[openapi(tag = "Interactions")]
+#[post("/<target>/interactions/<interaction>", data = "<data>")]
+pub async fn respond(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    interaction: Reference<'_>,
+    data: Json<v0::DataInteractionResponse>,
+) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if user.bot.is_none() {
+        return Err(create_error!(IsNotBot));
+    }
+
+    // Check the response before consuming the interaction, so that
+    // a bot can try again if its response is rejected
+    let pending = fetch_interaction(&user.id, interaction.id).await?;
+    if pending.channel != target.id {
+        return Err(create_error!(NotFound));
+    }
+
+    match data {
+        v0::DataInteractionResponse::UpdateMessage(edit) => {
+            Message::validate_sum(
+                &edit.content,
+                edit.embeds.as_deref().unwrap_or_default(),
+                user.limits().await.message_length,
+            )?;
+
+            if let Some(embeds) = &edit.embeds {
+                for embed in embeds {
+                    embed.validate().map_err(|error| {
+                        create_error!(FailedValidation {
+                            error: error.to_string()
+                        })
+                    })?;
+                }
+            }
+
+            let mut message = db.fetch_message(&pending.message).await?;
+            let mut partial = PartialMessage {
+                content: edit.content,
+                components: edit.components,
+                ..Default::default()
+            };
+
+            if let Some(intents) = edit.attachments {
+                partial.attachments = Some(message.apply_attachment_details(intents)?);
+            }
+
+            take_interaction(&user.id, interaction.id).await?;
+
+            if let Some(embeds) = edit.embeds {
+                let mut new_embeds: Vec<Embed> = vec![];
+                for embed in embeds {
+                    new_embeds.push(message.create_embed(db, embed).await?);
+                }
+
+                partial.embeds = Some(new_embeds);
+            }
+
+            // Keep a copy of the message as it was before this edit
+            message.push_revision(db, &partial).await?;
+            message.update(db, partial, vec![]).await?;
+        }
+        v0::DataInteractionResponse::EphemeralReply(reply) => {
+            take_interaction(&user.id, interaction.id).await?;
+
+            // Ephemeral replies are never stored, only sent to the user who interacted
+            let message = Message {
+                id: Ulid::new().to_string(),
+                channel: pending.channel,
+                author: user.id.clone(),
+                content: Some(reply.content),
+                replies: Some(vec![pending.message]),
+                flags: Some(1 << MessageFlags::Ephemeral as u32),
+                ..Default::default()
+            };
+
+            let author = user
+                .clone()
+                .into_known_static(revolt_presence::is_online(&user.id).await)
+                .await;
+
+            EventV1::Message(message.into_model(Some(author), None))
+                .private(pending.user.id)
+                .await;
+        }
+    }
+
+    Ok(EmptyResponse)
+}
@@ -13,7 +13,7 @@ use rocket_empty::EmptyResponse;
 ///
 /// Requires `ManageMessages` permission.
 #[openapi(tag = "Interactions")]
-#[delete("/<target>/messages/<msg>/reactions")]
+#[delete("/<target>/messages/<msg>/reactions", rank = 2)]
 pub async fn clear_reactions(
     db: &State<Database>,
     user: User,
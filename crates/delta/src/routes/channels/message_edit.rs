@@ -82,6 +82,22 @@ pub async fn edit(
 
     partial.embeds = Some(new_embeds);
 
+    // 4. Update attachment descriptions and spoilers
+    if let Some(intents) = edit.attachments {
+        partial.attachments = Some(message.apply_attachment_details(intents)?);
+    }
+
+    // 5. Replace components
+    if let Some(components) = edit.components {
+        if user.bot.is_none() {
+            return Err(create_error!(IsNotBot));
+        }
+
+        partial.components = Some(components);
+    }
+
+    // Keep a copy of the message as it was before this edit
+    message.push_revision(db, &partial).await?;
     message.update(db, partial, vec![]).await?;
 
     // Queue up a task for processing embeds if the we have sufficient permissions
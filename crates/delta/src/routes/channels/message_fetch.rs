@@ -11,7 +11,7 @@ use rocket::{serde::json::Json, State};
 ///
 /// Retrieves a message by its id.
 #[openapi(tag = "Messaging")]
-#[get("/<target>/messages/<msg>")]
+#[get("/<target>/messages/<msg>", rank = 2)]
 pub async fn fetch(
     db: &State<Database>,
     user: User,
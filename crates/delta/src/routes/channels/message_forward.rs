@@ -0,0 +1,210 @@
+use revolt_database::{util::reference::Reference, Database, User, AMQP};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Forward Message
+///
+/// Forward a message into another channel.
+///
+/// Requires `ReadMessageHistory` in the channel the message was sent in
+/// and `SendMessage` in the channel it is forwarded to.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/<msg>/forward", data = "<data>")]
+pub async fn forward(
+    db: &State<Database>,
+    amqp: &State<AMQP>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    data: Json<v0::DataForwardMessage>,
+) -> Result<Json<v0::Message>> {
+    let data = data.into_inner();
+
+    let source = target.as_channel(db).await?;
+    let message = msg.as_message_in_channel(db, source.id()).await?;
+    let destination = Reference::from_unchecked(&data.channel)
+        .as_channel(db)
+        .await?;
+
+    message
+        .forward(db, Some(amqp), &source, &destination, &user)
+        .await
+        .map(Json)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{util::idempotency::IdempotencyKey, Channel, Member, Message};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn forward_message() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (mut server, channels) = harness.new_server(&user).await;
+        let destination = harness.new_channel(&server).await;
+        let forum = Channel::create_server_channel(
+            &harness.db,
+            &mut server,
+            v0::DataCreateServerChannel {
+                channel_type: v0::LegacyServerChannelType::Forum,
+                name: "Forum".to_string(),
+                ..Default::default()
+            },
+            true,
+        )
+        .await
+        .expect("Failed to create forum");
+
+        let (member, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let message = Message::create_from_api(
+            &harness.db,
+            None,
+            channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Worth sharing".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
+            Some(user.clone().into(&harness.db, Some(&user)).await),
+            Some(member.into()),
+            user.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/forward",
+                channel.id(),
+                &message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataForwardMessage {
+                    channel: destination.id().to_string(),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let forwarded: v0::Message = response.into_json().await.expect("`Message`");
+        assert_eq!(forwarded.channel, destination.id());
+
+        let forward = forwarded.forward.expect("Message is not a forward");
+        assert_eq!(forward.id, message.id);
+        assert_eq!(forward.channel, channel.id());
+        assert_eq!(forward.author, user.id);
+        assert_eq!(forward.content.as_deref(), Some("Worth sharing"));
+
+        // Forums only accept messages within their posts
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/forward",
+                channel.id(),
+                &message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataForwardMessage {
+                    channel: forum.id().to_string(),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+    }
+
+    #[rocket::async_test]
+    async fn forward_expiring_message() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+        let destination = harness.new_channel(&server).await;
+
+        let (member, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let message = Message::create_from_api(
+            &harness.db,
+            None,
+            channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Gone in a minute".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: Some(60),
+                flags: None,
+            },
+            v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
+            Some(user.clone().into(&harness.db, Some(&user)).await),
+            Some(member.into()),
+            user.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/forward",
+                channel.id(),
+                &message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataForwardMessage {
+                    channel: destination.id().to_string(),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        // The forward expires along with the original
+        let forwarded: v0::Message = response.into_json().await.expect("`Message`");
+        assert_eq!(forwarded.expires_at, message.expires_at);
+    }
+}
@@ -0,0 +1,145 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Message History
+///
+/// Fetch prior versions of a message, oldest first.
+///
+/// Requires `ManageMessages` unless you are the author of the message.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/messages/<msg>/history")]
+pub async fn fetch_history(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<Json<Vec<v0::MessageRevision>>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    // Fetch relevant message
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+
+    // Only the author and moderators may see prior versions
+    if message.author != user.id {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    Ok(Json(message.revisions.into_iter().map(Into::into).collect()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{util::idempotency::IdempotencyKey, Member, Message};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn edit_records_revision() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (member, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let message = Message::create_from_api(
+            &harness.db,
+            None,
+            channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Original".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
+            Some(user.clone().into(&harness.db, Some(&user)).await),
+            Some(member.into()),
+            user.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        // Rejected edits don't leave a revision behind
+        let response = harness
+            .client
+            .patch(format!("/channels/{}/messages/{}", channel.id(), &message.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataEditMessage {
+                    content: Some("Rejected".to_string()),
+                    embeds: None,
+                    attachments: None,
+                    components: Some(vec![]),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        // Repeating an edit which changes nothing doesn't add another revision
+        for _ in 0..2 {
+            let response = harness
+                .client
+                .patch(format!("/channels/{}/messages/{}", channel.id(), &message.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .header(ContentType::JSON)
+                .body(
+                    json!(v0::DataEditMessage {
+                        content: Some("Edited".to_string()),
+                        embeds: None,
+                        attachments: None,
+                        components: None,
+                    })
+                    .to_string(),
+                )
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::Ok);
+            drop(response);
+        }
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}/messages/{}/history", channel.id(), &message.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let revisions: Vec<v0::MessageRevision> =
+            response.into_json().await.expect("`Vec<MessageRevision>`");
+
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].content.as_deref(), Some("Original"));
+    }
+}
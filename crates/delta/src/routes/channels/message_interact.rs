@@ -0,0 +1,263 @@
+use revolt_database::{
+    events::client::EventV1,
+    util::{
+        interactions::{deliver_interaction, store_interaction},
+        permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    Database, User,
+};
+use revolt_models::v0::{self, Component};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission, PermissionQuery};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use rocket_empty::EmptyResponse;
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Use Component
+///
+/// Click a button or choose options from a select menu on a bot's message.
+///
+/// The bot receives an `InteractionCreate` event, and a request to its interactions URL if it has one set.
+#[openapi(tag = "Interactions")]
+#[post("/<target>/messages/<msg>/interact", data = "<data>")]
+pub async fn interact(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    data: Json<v0::DataInteract>,
+) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if user.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let component = message
+        .components
+        .as_deref()
+        .and_then(|rows| v0::find_component(rows, &data.component_id))
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    if component.disabled() {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let valid = match component {
+        Component::Button(_) => data.values.is_empty(),
+        Component::Select(menu) => menu.accepts(&data.values),
+    };
+
+    if !valid {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    // Only bots can attach components, so the author is the bot to deliver to
+    let bot = db.fetch_bot(&message.author).await?;
+
+    query.are_we_a_member().await;
+    let interaction = v0::Interaction {
+        id: Ulid::new().to_string(),
+        channel: channel.id().to_string(),
+        message: message.id,
+        component_id: data.component_id,
+        values: data.values,
+        user: user
+            .clone()
+            .into_known_static(revolt_presence::is_online(&user.id).await)
+            .await,
+        member: query
+            .member_ref()
+            .as_ref()
+            .map(|member| member.clone().into_owned().into()),
+    };
+
+    store_interaction(&bot.id, &interaction).await?;
+
+    if !bot.interactions_url.is_empty() {
+        tokio::spawn(deliver_interaction(
+            bot.interactions_url,
+            bot.interactions_secret,
+            interaction.clone(),
+        ));
+    }
+
+    EventV1::InteractionCreate(interaction)
+        .private(bot.id)
+        .await;
+
+    Ok(EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{events::client::EventV1, Bot, PartialMessage};
+    use revolt_models::v0::{self, ActionRow, Button, ButtonStyle, Component};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn interaction_round_trip() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (bot, bot_user) = Bot::create(&harness.db, TestHarness::rand_string(), &user, None)
+            .await
+            .expect("`Bot`");
+
+        let (channel, _, mut message) = harness.new_message(&bot_user, &server, channels).await;
+        message
+            .update(
+                &harness.db,
+                PartialMessage {
+                    components: Some(vec![ActionRow {
+                        components: vec![Component::Button(Button {
+                            id: Some("confirm".to_string()),
+                            style: ButtonStyle::Success,
+                            label: Some("Confirm".to_string()),
+                            emoji: None,
+                            url: None,
+                            disabled: false,
+                        })],
+                    }]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("`Message`");
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/interact",
+                channel.id(),
+                message.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataInteract {
+                    component_id: "confirm".to_string(),
+                    values: vec![],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        drop(response);
+
+        let event = harness
+            .wait_for_event(&format!("{}!", bot.id), |event| {
+                matches!(event, EventV1::InteractionCreate(_))
+            })
+            .await;
+
+        let EventV1::InteractionCreate(interaction) = event else {
+            unreachable!()
+        };
+
+        assert_eq!(interaction.component_id, "confirm");
+        assert_eq!(interaction.user.id, user.id);
+
+        // Rejected responses do not use up the interaction
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/interactions/{}",
+                TestHarness::rand_string(),
+                interaction.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-bot-token", bot.token.to_string()))
+            .body(
+                json!(v0::DataInteractionResponse::EphemeralReply(
+                    v0::DataEphemeralReply {
+                        content: "Confirmed".to_string(),
+                    }
+                ))
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NotFound);
+        drop(response);
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/interactions/{}",
+                channel.id(),
+                interaction.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-bot-token", bot.token.to_string()))
+            .body(
+                json!(v0::DataInteractionResponse::EphemeralReply(
+                    v0::DataEphemeralReply {
+                        content: "Confirmed".to_string(),
+                    }
+                ))
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        drop(response);
+
+        let event = harness
+            .wait_for_event(&format!("{}!", user.id), |event| match event {
+                EventV1::Message(message) => message.author == bot.id,
+                _ => false,
+            })
+            .await;
+
+        let EventV1::Message(reply) = event else {
+            unreachable!()
+        };
+
+        assert_eq!(reply.content.as_deref(), Some("Confirmed"));
+
+        // Interactions can only be responded to once
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/interactions/{}",
+                channel.id(),
+                interaction.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-bot-token", bot.token.to_string()))
+            .body(
+                json!(v0::DataInteractionResponse::EphemeralReply(
+                    v0::DataEphemeralReply {
+                        content: "Again".to_string(),
+                    }
+                ))
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
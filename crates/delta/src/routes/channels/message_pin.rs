@@ -132,6 +132,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
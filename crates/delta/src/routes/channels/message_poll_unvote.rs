@@ -0,0 +1,36 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Remove Vote from Poll
+///
+/// Retract your vote for an option on a message's poll.
+#[openapi(tag = "Interactions")]
+#[delete("/<target>/messages/<msg>/poll/<option>")]
+pub async fn unvote_poll(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    option: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::React)?;
+
+    // Fetch relevant message
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+
+    // Retract the vote
+    message
+        .remove_poll_vote(db, &user.id, option.id)
+        .await
+        .map(|_| EmptyResponse)
+}
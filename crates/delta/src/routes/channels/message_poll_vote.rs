@@ -0,0 +1,161 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Vote on Poll
+///
+/// Cast a vote for an option on a message's poll.
+///
+/// Voting on a single choice poll replaces any existing vote.
+#[openapi(tag = "Interactions")]
+#[put("/<target>/messages/<msg>/poll/<option>")]
+pub async fn vote_poll(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    option: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::React)?;
+
+    // Fetch relevant message
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+
+    // Cast the vote
+    message
+        .add_poll_vote(db, &user.id, option.id)
+        .await
+        .map(|_| EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{
+        events::client::EventV1,
+        util::{idempotency::IdempotencyKey, reference::Reference},
+        Member, Message,
+    };
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn vote_single_choice_poll() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (member, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let message = Message::create_from_api(
+            &harness.db,
+            None,
+            channel.clone(),
+            v0::DataMessageSend {
+                content: None,
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: Some(v0::DataPoll {
+                    question: "Which one?".to_string(),
+                    options: vec![
+                        v0::DataPollOption {
+                            text: "First".to_string(),
+                        },
+                        v0::DataPollOption {
+                            text: "Second".to_string(),
+                        },
+                    ],
+                    multi_select: false,
+                    anonymous: false,
+                    duration: None,
+                }),
+                expires_in: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
+            Some(user.clone().into(&harness.db, Some(&user)).await),
+            Some(member.into()),
+            user.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        let options: Vec<String> = message
+            .poll
+            .as_ref()
+            .expect("Message has no poll")
+            .options
+            .iter()
+            .map(|option| option.id.clone())
+            .collect();
+
+        for option in &options {
+            let response = harness
+                .client
+                .put(format!(
+                    "/channels/{}/messages/{}/poll/{}",
+                    channel.id(),
+                    &message.id,
+                    option
+                ))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::NoContent);
+        }
+
+        harness
+            .wait_for_event(channel.id(), |event| match event {
+                EventV1::MessagePollVote {
+                    id,
+                    user_id,
+                    option_id,
+                    voted,
+                    ..
+                } => {
+                    id == &message.id
+                        && user_id.as_ref() == Some(&user.id)
+                        && option_id == &options[0]
+                        && !voted
+                }
+                _ => false,
+            })
+            .await;
+
+        let updated_message = Reference::from_unchecked(&message.id)
+            .as_message(&harness.db)
+            .await
+            .expect("Failed to find updated message");
+
+        let poll = updated_message.poll.expect("Message has no poll");
+        assert!(!poll
+            .votes
+            .get(&options[0])
+            .is_some_and(|users| users.contains(&user.id)));
+        assert!(poll
+            .votes
+            .get(&options[1])
+            .is_some_and(|users| users.contains(&user.id)));
+    }
+}
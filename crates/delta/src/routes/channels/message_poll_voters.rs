@@ -0,0 +1,59 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Poll Voters
+///
+/// Fetch the users who voted for each option on a message's poll.
+///
+/// Requires `ManageMessages` if the poll is anonymous.
+#[openapi(tag = "Interactions")]
+#[get("/<target>/messages/<msg>/poll/voters")]
+pub async fn fetch_voters(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<Json<Vec<v0::PollOption>>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    // Fetch relevant message
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let poll = message.poll.ok_or_else(|| create_error!(NotFound))?;
+
+    // Only moderators may see who voted on anonymous polls
+    if poll.anonymous {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    Ok(Json(
+        poll.options
+            .into_iter()
+            .map(|option| {
+                let voters: Vec<String> = poll
+                    .votes
+                    .get(&option.id)
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .collect();
+
+                v0::PollOption {
+                    id: option.id,
+                    text: option.text,
+                    votes: voters.len() as u64,
+                    voters: Some(voters),
+                }
+            })
+            .collect(),
+    ))
+}
@@ -0,0 +1,148 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User, AMQP,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Publish Message
+///
+/// Publish a message in an announcement channel to every channel following it.
+///
+/// Requires `ManageMessages` unless you are the author of the message.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/<msg>/publish")]
+pub async fn publish(
+    db: &State<Database>,
+    amqp: &State<AMQP>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    let mut message = msg.as_message_in_channel(db, channel.id()).await?;
+
+    if message.author != user.id {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    message
+        .publish(db, Some(amqp), &channel)
+        .await
+        .map(|_| EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{MessageFilter, MessageQuery, MessageTimePeriod, PartialChannel};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn publish_to_followers() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+        let (mut channel, _, message) = harness.new_message(&user, &server, channels).await;
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    announcement: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to mark channel as announcement channel");
+
+        let (other_server, _) = harness.new_server(&user).await;
+        let destination = harness.new_channel(&other_server).await;
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/followers", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataFollowChannel {
+                    channel_id: destination.id().to_string(),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let webhook: v0::Webhook = response.into_json().await.expect("`Webhook`");
+        assert_eq!(webhook.channel_id, destination.id());
+        assert_eq!(webhook.source_channel_id.as_deref(), Some(channel.id()));
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/publish",
+                channel.id(),
+                &message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        drop(response);
+
+        let messages = harness
+            .db
+            .fetch_messages(MessageQuery {
+                limit: None,
+                filter: MessageFilter {
+                    channel: Some(destination.id().to_string()),
+                    ..Default::default()
+                },
+                time_period: MessageTimePeriod::Absolute {
+                    before: None,
+                    after: None,
+                    sort: None,
+                },
+            })
+            .await
+            .expect("Failed to fetch messages");
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].author, webhook.id);
+        assert_eq!(messages[0].content, message.content);
+
+        // Messages can only be published once
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/publish",
+                channel.id(),
+                &message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        drop(response);
+
+        // A copy read before the message was published can't publish it again
+        let mut stale = message.clone();
+        assert!(stale
+            .publish(&harness.db, Some(&harness.amqp), &channel)
+            .await
+            .is_err());
+    }
+}
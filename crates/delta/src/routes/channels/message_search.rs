@@ -11,6 +11,10 @@ use validator::Validate;
 /// # Search for Messages
 ///
 /// This route searches for messages within the given parameters.
+///
+/// The query may contain operators alongside free text: `from:<user>`,
+/// `mentions:<user>`, `has:attachment|embed|link`, `before:<YYYY-MM-DD>`,
+/// `after:<YYYY-MM-DD>` and `in:<channel>`.
 #[openapi(tag = "Messaging")]
 #[post("/<target>/search", data = "<options>")]
 pub async fn search(
@@ -51,15 +55,20 @@ pub async fn search(
         include_users,
     } = options;
 
+    let mut filter = MessageFilter {
+        channel: Some(channel.id().to_string()),
+        pinned,
+        ..Default::default()
+    };
+
+    if let Some(query) = query {
+        filter.apply_search(db, &query).await?;
+    }
+
     Message::fetch_with_users(
         db,
         MessageQuery {
-            filter: MessageFilter {
-                channel: Some(channel.id().to_string()),
-                query,
-                pinned,
-                ..Default::default()
-            },
+            filter,
             time_period: MessageTimePeriod::Absolute {
                 before,
                 after,
@@ -1,16 +1,8 @@
-use std::time::Duration;
-
-use redis_kiss::{get_connection, redis, AsyncCommands};
-use revolt_database::events::client::EventV1;
-use revolt_database::util::permissions::DatabasePermissionQuery;
 use revolt_database::{
     util::idempotency::IdempotencyKey, util::reference::Reference, Database, User,
 };
-use revolt_database::{Channel, Interactions, Message, AMQP};
+use revolt_database::{Message, AMQP};
 use revolt_models::v0;
-use revolt_models::v0::ChannelSlowmode;
-use revolt_permissions::PermissionQuery;
-use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
 use rocket::serde::json::Json;
 use rocket::State;
@@ -36,160 +28,11 @@ pub async fn message_send(
         })
     })?;
 
-    // Ensure we have permissions to send a message
     let channel = target.as_channel(db).await?;
-    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
-    let permissions = calculate_channel_permissions(&mut query).await;
-    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
-
-    // Verify permissions for masquerade
-    if let Some(masq) = &data.masquerade {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
-
-        if masq.colour.is_some() {
-            permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageRole)?;
-        }
-    }
-
-    // Check permissions for embeds
-    if data.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
-    }
-
-    // Check permissions for files
-    if data.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
-        permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
-    }
-
-    if !permissions.has_channel_permission(ChannelPermission::BypassSlowmode) {
-        if let Channel::TextChannel {
-            slowmode: Some(channel_slowmode),
-            id: channel_id,
-            ..
-        } = &channel
-        {
-            if *channel_slowmode > 0 {
-                if let Ok(conn) = get_connection().await {
-                    let mut conn = conn.into_inner();
-
-                    let slowmode_key = format!("slowmode:{}:{}", user.id, channel_id);
-
-                    // Atomic check-and-set: only set if absent and apply expiry in one command.
-                    let set_result: Option<String> = conn
-                        .set_options(
-                            &slowmode_key,
-                            "1", // The value doesn't matter, only the key's existence
-                            redis::SetOptions::default()
-                                .conditional_set(redis::ExistenceCheck::NX)
-                                .with_expiration(redis::SetExpiry::EX(*channel_slowmode as usize)),
-                        )
-                        .await
-                        .unwrap_or(None);
-
-                    if set_result.is_some() {
-                        let idx_key = format!("slowmode_idx:{}", user.id);
-                        conn.sadd::<_, _, ()>(&idx_key, channel_id.as_str())
-                            .await
-                            .ok();
-                        conn.expire::<_, ()>(&idx_key, *channel_slowmode as usize)
-                            .await
-                            .ok();
-                    }
-
-                    // If `set_result` is None, the `NX` condition failed because the key already exists.
-                    // This means the user is currently in slowmode.
-                    if set_result.is_none() {
-                        // Fetch the remaining TTL to accurately populate the retry_after field
-                        let ttl: i64 = conn.ttl(&slowmode_key).await.unwrap_or(0);
-
-                        // Redis returns positive integers for valid TTLs
-                        if ttl > 0 {
-                            EventV1::UserSlowmodes {
-                                slowmodes: vec![ChannelSlowmode {
-                                    channel_id: channel_id.to_string(),
-                                    duration: *channel_slowmode,
-                                    retry_after: ttl as u64,
-                                }],
-                            }
-                            .private(user.id.clone())
-                            .await;
-                            return Err(create_error!(InSlowmode {
-                                retry_after: ttl as u64
-                            }));
-                        }
-                    } else {
-                        EventV1::UserSlowmodes {
-                            slowmodes: vec![ChannelSlowmode {
-                                channel_id: channel_id.to_string(),
-                                duration: *channel_slowmode,
-                                retry_after: *channel_slowmode,
-                            }],
-                        }
-                        .private(user.id.clone())
-                        .await;
-                    }
-                }
-                // If Redis connection fails, just skip the slowmode check
-            }
-        }
-    }
-
-    // Ensure interactions information is correct
-    if let Some(interactions) = &data.interactions {
-        let interactions: Interactions = interactions.clone().into();
-        interactions.validate(db, &permissions).await?;
-    }
-
-    // Disallow mentions for new users (TRUST-0: <12 hours age) in public servers
-    let allow_mentions = if let Some(server) = query.server_ref() {
-        if server.discoverable {
-            (ulid::Ulid::from_string(&user.id)
-                .unwrap()
-                .datetime()
-                .elapsed()
-                .expect("Time went backwards"))
-                >= Duration::from_hours(12)
-        } else {
-            true
-        }
-    } else {
-        true
-    };
 
-    // Create the message
-    let author: v0::User = user.clone().into(db, Some(&user)).await;
-
-    // Make sure we have server member (edge case if server owner)
-    query.are_we_a_member().await;
-
-    // Create model user / members
-    let model_user = user
-        .clone()
-        .into_known_static(revolt_presence::is_online(&user.id).await)
-        .await;
-
-    let model_member: Option<v0::Member> = query
-        .member_ref()
-        .as_ref()
-        .map(|member| member.clone().into_owned().into());
-
-    Ok(Json(
-        Message::create_from_api(
-            db,
-            Some(amqp),
-            channel,
-            data,
-            v0::MessageAuthor::User(&author),
-            Some(model_user.clone()),
-            model_member.clone(),
-            user.limits().await,
-            idempotency,
-            permissions.has_channel_permission(ChannelPermission::SendEmbeds),
-            allow_mentions,
-        )
-        .await?
-        .into_model(Some(model_user), model_member),
-    ))
+    Message::create_from_user(db, Some(amqp), channel, &user, data, idempotency)
+        .await
+        .map(Json)
 }
 
 #[cfg(test)]
@@ -197,9 +40,11 @@ mod test {
     use std::collections::HashMap;
 
     use crate::{rocket, util::test::TestHarness};
+    use iso8601_timestamp::Timestamp;
     use revolt_database::{
         util::{idempotency::IdempotencyKey, reference::Reference},
-        Channel, Member, Message, MessageFlagsValue, PartialChannel, PartialMember, Role, Server,
+        Channel, Member, Message, MessageFlagsValue, PartialChannel, PartialMember, PartialMessage,
+        Role, Server,
     };
     use revolt_models::v0::{self, DataCreateServerChannel, MessageFlags};
     use revolt_permissions::{ChannelPermission, OverrideField};
@@ -268,6 +113,7 @@ mod test {
             last_message_id: None,
             voice: None,
             slowmode: None,
+            ..Default::default()
         };
         locked_channel
             .update(&harness.db, partial, vec![])
@@ -295,6 +141,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -335,6 +184,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -384,6 +236,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -430,6 +285,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -481,6 +339,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -518,6 +379,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -549,6 +413,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -599,6 +466,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(
@@ -644,6 +514,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(
@@ -714,6 +587,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(
@@ -754,4 +630,194 @@ mod test {
             "Message has no role mentions"
         );
     }
+
+    #[rocket::async_test]
+    async fn message_expiry() {
+        let harness = TestHarness::new().await;
+        let (_, _, user) = harness.new_user().await;
+        let (server, _) = harness.new_server(&user).await;
+        let mut channel = harness.new_channel(&server).await;
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    message_ttl: Some(60),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to set channel message expiry");
+
+        // Message should inherit the channel's default expiry
+        let message = Message::create_from_api(
+            &harness.db,
+            Some(&harness.amqp),
+            channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Gone in a minute".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
+            Some(user.clone().into(&harness.db, Some(&user)).await),
+            None,
+            user.limits().await,
+            IdempotencyKey::unchecked_from_string("0".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        assert!(message.expires_at.is_some(), "Message has no expiry");
+        assert_eq!(
+            Message::delete_expired(&harness.db)
+                .await
+                .expect("Failed to delete expired messages"),
+            0
+        );
+
+        // Once expired, the message should be deleted
+        harness
+            .db
+            .update_message(
+                &message.id,
+                &PartialMessage {
+                    expires_at: Some(Timestamp::now_utc()),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update message");
+
+        assert_eq!(
+            Message::delete_expired(&harness.db)
+                .await
+                .expect("Failed to delete expired messages"),
+            1
+        );
+        assert!(harness.db.fetch_message(&message.id).await.is_err());
+    }
+
+    #[rocket::async_test]
+    async fn thread_message_expiry() {
+        let harness = TestHarness::new().await;
+        let (_, _, user) = harness.new_user().await;
+        let (server, _) = harness.new_server(&user).await;
+        let mut channel = harness.new_channel(&server).await;
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    message_ttl: Some(60),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to set channel message expiry");
+
+        let author = user.clone().into(&harness.db, Some(&user)).await;
+        let limits = user.limits().await;
+        let send = |channel: Channel, content: &str| {
+            Message::create_from_api(
+                &harness.db,
+                Some(&harness.amqp),
+                channel,
+                v0::DataMessageSend {
+                    content: Some(content.to_string()),
+                    nonce: None,
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade: None,
+                    interactions: None,
+                    components: None,
+                    poll: None,
+                    expires_in: None,
+                    flags: None,
+                },
+                v0::MessageAuthor::User(&author),
+                Some(author.clone()),
+                None,
+                limits.clone(),
+                IdempotencyKey::unchecked_from_string(content.to_string()),
+                false,
+                false,
+            )
+        };
+
+        let message = send(channel.clone(), "Start a thread")
+            .await
+            .expect("Failed to create message");
+
+        let mut message = harness
+            .db
+            .fetch_message(&message.id)
+            .await
+            .expect("Failed to fetch message");
+
+        let thread = Channel::create_thread(
+            &harness.db,
+            &channel,
+            &mut message,
+            v0::DataCreateThread {
+                name: "Thread".to_string(),
+            },
+            user.id.clone(),
+        )
+        .await
+        .expect("Failed to create thread");
+
+        // Messages in a thread should inherit the parent channel's default expiry
+        let reply = send(thread, "Gone in a minute")
+            .await
+            .expect("Failed to create message in thread");
+
+        assert!(reply.expires_at.is_some(), "Thread message has no expiry");
+
+        // Expired replies are removed from the thread summary
+        harness
+            .db
+            .update_message(
+                &reply.id,
+                &PartialMessage {
+                    expires_at: Some(Timestamp::now_utc()),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update message");
+
+        assert_eq!(
+            Message::delete_expired(&harness.db)
+                .await
+                .expect("Failed to delete expired messages"),
+            1
+        );
+
+        let summary = harness
+            .db
+            .fetch_message(&message.id)
+            .await
+            .expect("Failed to fetch message")
+            .thread
+            .expect("Message has no thread summary");
+
+        assert_eq!(summary.reply_count, 0);
+        assert_eq!(summary.last_message_id, None);
+    }
 }
@@ -15,7 +15,7 @@ use crate::util::audit_log_reason::AuditLogReason;
 ///
 /// Unpins a message by its id.
 #[openapi(tag = "Messaging")]
-#[delete("/<target>/messages/<msg>/pin")]
+#[delete("/<target>/messages/<msg>/pin", rank = 2)]
 pub async fn message_unpin(
     db: &State<Database>,
     amqp: &State<AMQP>,
@@ -131,6 +131,9 @@ mod test {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&harness.db, Some(&user)).await),
@@ -4,10 +4,21 @@ use rocket::Route;
 mod channel_ack;
 mod channel_delete;
 mod channel_edit;
+mod channel_export_create;
+mod channel_export_fetch;
 mod channel_fetch;
+mod channel_follow;
+mod channel_receipts;
+mod command_invoke;
+mod forum_post_create;
+mod forum_post_edit;
+mod forum_posts_fetch;
+mod forum_tag_create;
+mod forum_tag_delete;
 mod group_add_member;
 mod group_create;
 mod group_remove_member;
+mod interaction_respond;
 mod invite_create;
 mod members_fetch;
 mod message_bulk_delete;
@@ -15,15 +26,34 @@ mod message_clear_reactions;
 mod message_delete;
 mod message_edit;
 mod message_fetch;
+mod message_forward;
+mod message_history;
+mod message_interact;
 mod message_pin;
+mod message_poll_unvote;
+mod message_poll_vote;
+mod message_poll_voters;
+mod message_publish;
 mod message_query;
 mod message_react;
 mod message_search;
 mod message_send;
 mod message_unpin;
 mod message_unreact;
+mod permissions_explain;
+mod permissions_remove_member;
 mod permissions_set;
 mod permissions_set_default;
+mod permissions_set_member;
+mod scheduled_message_create;
+mod scheduled_message_delete;
+mod scheduled_message_edit;
+mod scheduled_messages_fetch;
+mod thread_create;
+mod thread_edit;
+mod thread_fetch;
+mod thread_follow;
+mod thread_unfollow;
 mod voice_join;
 mod voice_stop_ring;
 mod webhook_create;
@@ -32,10 +62,13 @@ mod webhook_fetch_all;
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
         channel_ack::ack,
+        channel_receipts::fetch_receipts,
         channel_fetch::fetch,
         members_fetch::fetch_members,
         channel_delete::delete,
         channel_edit::edit,
+        channel_export_create::create_export,
+        channel_export_fetch::fetch_export,
         invite_create::create_invite,
         message_send::message_send,
         message_query::query,
@@ -43,9 +76,26 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         message_pin::message_pin,
         message_fetch::fetch,
         message_edit::edit,
+        message_history::fetch_history,
+        message_forward::forward,
+        message_publish::publish,
         message_bulk_delete::bulk_delete_messages,
         message_delete::delete,
         message_unpin::message_unpin,
+        scheduled_message_create::create_scheduled_message,
+        scheduled_messages_fetch::fetch_scheduled_messages,
+        scheduled_message_edit::edit_scheduled_message,
+        scheduled_message_delete::delete_scheduled_message,
+        thread_create::create_thread,
+        thread_fetch::fetch_thread,
+        thread_edit::edit_thread,
+        thread_follow::follow_thread,
+        thread_unfollow::unfollow_thread,
+        forum_post_create::create_post,
+        forum_posts_fetch::fetch_posts,
+        forum_post_edit::edit_post,
+        forum_tag_create::create_tag,
+        forum_tag_delete::delete_tag,
         group_create::create_group,
         group_add_member::add_member,
         group_remove_member::remove_member,
@@ -53,10 +103,20 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         voice_stop_ring::stop_ring,
         permissions_set::set_role_permissions,
         permissions_set_default::set_default_channel_permissions,
+        permissions_set_member::set_member_permissions,
+        permissions_remove_member::remove_member_permissions,
+        permissions_explain::explain_permissions,
         message_react::react_message,
         message_unreact::unreact_message,
         message_clear_reactions::clear_reactions,
+        message_poll_vote::vote_poll,
+        message_poll_unvote::unvote_poll,
+        message_poll_voters::fetch_voters,
+        message_interact::interact,
+        interaction_respond::respond,
+        command_invoke::invoke_command,
         webhook_create::create_webhook,
+        channel_follow::follow_channel,
         webhook_fetch_all::fetch_webhooks,
     ]
 }
@@ -0,0 +1,284 @@
+use iso8601_timestamp::Timestamp;
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, Member, MemberCompositeKey, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{
+    calculate_channel_permissions, explain_channel_permissions, ChannelPermission,
+};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+
+/// Member record for someone who has just joined a server
+fn new_member(server: &str, user: &str) -> Member {
+    Member {
+        id: MemberCompositeKey {
+            server: server.to_string(),
+            user: user.to_string(),
+        },
+        joined_at: Timestamp::now_utc(),
+        nickname: None,
+        pronouns: None,
+        avatar: None,
+        roles: vec![],
+        role_expiries: vec![],
+        timeout: None,
+        can_publish: true,
+        can_receive: true,
+    }
+}
+
+/// # Explain Permissions
+///
+/// Walk through how permissions in this channel are calculated for a member.
+///
+/// Explaining your own permissions requires `ViewChannel` in the channel. Explaining
+/// permissions for anyone else, or previewing a set of roles, requires `ManagePermissions`.
+///
+/// Channel must be a server channel.
+#[openapi(tag = "Channel Permissions")]
+#[get("/<target>/permissions/explain?<options..>")]
+pub async fn explain_permissions(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    options: v0::OptionsExplainPermissions,
+) -> Result<Json<v0::PermissionExplanation>> {
+    let channel = target.as_channel(db).await?;
+    let server = Reference::from_unchecked(
+        channel
+            .server()
+            .ok_or_else(|| create_error!(InvalidOperation))?,
+    )
+    .as_server(db)
+    .await?;
+
+    let permissions = {
+        let mut query = DatabasePermissionQuery::new(db, &user)
+            .channel(&channel)
+            .server(&server);
+
+        calculate_channel_permissions(&mut query).await
+    };
+
+    if options.user.as_ref().is_some_and(|id| id != &user.id) || options.roles.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+    } else {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+    }
+
+    let (target_user, mut member) = if options.roles.is_some() && options.user.is_none() {
+        // Preview as a new member who does not own the server
+        let preview = User {
+            id: Ulid::nil().to_string(),
+            ..Default::default()
+        };
+
+        let member = new_member(&server.id, &preview.id);
+        (preview, member)
+    } else {
+        let target_user = match &options.user {
+            Some(id) => Reference::from_unchecked(id).as_user(db).await?,
+            None => user,
+        };
+
+        // The owner of a server may not have a member record
+        let member = match Reference::from_unchecked(&target_user.id)
+            .as_member(db, &server.id)
+            .await
+        {
+            Ok(member) => member,
+            Err(_) if server.owner == target_user.id => new_member(&server.id, &target_user.id),
+            Err(error) => return Err(error),
+        };
+
+        (target_user, member)
+    };
+
+    if let Some(roles) = options.roles {
+        if roles.iter().any(|id| !server.roles.contains_key(id)) {
+            return Err(create_error!(NotFound));
+        }
+
+        member.roles = roles;
+    }
+
+    let mut roles: Vec<(&String, i64)> = member
+        .roles
+        .iter()
+        .filter_map(|id| server.roles.get(id).map(|role| (id, role.rank)))
+        .collect();
+
+    roles.sort_by(|a, b| b.1.cmp(&a.1));
+    let roles = roles.into_iter().map(|(id, _)| id.clone()).collect();
+
+    let mut query = DatabasePermissionQuery::new(db, &target_user)
+        .channel(&channel)
+        .server(&server)
+        .member(&member);
+
+    let (permissions, steps) = explain_channel_permissions(&mut query).await;
+
+    Ok(Json(v0::PermissionExplanation {
+        permissions: permissions.into(),
+        roles,
+        steps,
+    }))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, MemberCompositeKey, PartialChannel};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, OverrideField, PermissionStep};
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn explain_and_preview_permissions() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+        let channel_id = channels[0].id().to_string();
+
+        let (_, session, user) = harness.new_user().await;
+        Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        // Members can explain their own permissions
+        let response = harness
+            .client
+            .get(format!("/channels/{channel_id}/permissions/explain"))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let explanation: v0::PermissionExplanation =
+            response.into_json().await.expect("`PermissionExplanation`");
+
+        assert_eq!(
+            explanation.steps.first(),
+            Some(&PermissionStep::ServerDefault {
+                permissions: server.default_permissions as u64
+            })
+        );
+
+        // ... but not anyone else's
+        let response = harness
+            .client
+            .get(format!(
+                "/channels/{channel_id}/permissions/explain?user={}",
+                owner.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // The owner can preview the channel with a given role
+        let role = harness
+            .new_role(
+                &server,
+                1,
+                Some(OverrideField {
+                    a: 0,
+                    d: ChannelPermission::ViewChannel as i64,
+                }),
+            )
+            .await;
+
+        let response = harness
+            .client
+            .get(format!(
+                "/channels/{channel_id}/permissions/explain?roles={}",
+                role.id
+            ))
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let explanation: v0::PermissionExplanation =
+            response.into_json().await.expect("`PermissionExplanation`");
+
+        assert_eq!(explanation.permissions, 0);
+        assert!(explanation.steps.iter().any(|step| matches!(
+            step,
+            PermissionStep::ServerRole { role: id, .. } if id == &role.id
+        )));
+        assert_eq!(explanation.roles, vec![role.id]);
+        assert_eq!(
+            explanation.steps.last(),
+            Some(&PermissionStep::CannotView { permissions: 0 })
+        );
+    }
+
+    #[rocket::async_test]
+    async fn cannot_explain_hidden_channel() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, _) = harness.new_server(&owner).await;
+        let mut channel = harness.new_channel(&server).await;
+
+        let (_, session, user) = harness.new_user().await;
+        Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    default_permissions: Some(OverrideField {
+                        a: 0,
+                        d: ChannelPermission::ViewChannel as i64,
+                    }),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to hide channel");
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}/permissions/explain", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // The owner can always explain their permissions, even without a member record
+        harness
+            .db
+            .force_delete_member(&MemberCompositeKey {
+                server: server.id.clone(),
+                user: owner.id.clone(),
+            })
+            .await
+            .expect("Failed to delete member");
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}/permissions/explain", channel.id()))
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
@@ -0,0 +1,88 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Channel, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::audit_log_reason::AuditLogReason;
+
+/// # Remove Member Permission
+///
+/// Removes the permissions set for the specified member in this channel.
+#[openapi(tag = "Channel Permissions")]
+#[delete("/<target>/permissions/members/<member_id>")]
+pub async fn remove_member_permissions(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    member_id: String,
+) -> Result<Json<v0::Channel>> {
+    let mut channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    let current_value = match &channel {
+        Channel::TextChannel {
+            user_permissions, ..
+        }
+        | Channel::ForumChannel {
+            user_permissions, ..
+        } => user_permissions
+            .get(&member_id)
+            .copied()
+            .ok_or_else(|| create_error!(NotFound))?,
+        _ => return Err(create_error!(InvalidOperation)),
+    };
+
+    query.set_server_from_channel().await;
+    let server = query
+        .server_ref()
+        .as_ref()
+        .ok_or_else(|| create_error!(InvalidOperation))?
+        .clone()
+        .into_owned();
+
+    // We can't change our own permissions
+    if member_id == user.id {
+        return Err(create_error!(NotElevated));
+    }
+
+    // Members who have left can always be cleaned up, otherwise respect the hierarchy
+    if let Ok(member) = Reference::from_unchecked(&member_id)
+        .as_member(db, &server.id)
+        .await
+    {
+        if server.owner != user.id
+            && member.get_ranking(&server) <= query.get_member_rank().unwrap_or(i64::MIN)
+        {
+            return Err(create_error!(NotElevated));
+        }
+    }
+
+    // Ensure we are able to take away what was granted
+    permissions
+        .throw_permission_override(current_value.into(), &Default::default())
+        .await?;
+
+    channel.set_user_permission(db, &member_id, None).await?;
+
+    sync_voice_permissions(db, voice_client, &channel, Some(&server), None).await?;
+
+    AuditLogEntryAction::ChannelUserPermissionsEdit {
+        channel: channel.id().to_string(),
+        user: member_id,
+        permissions: None,
+    }
+    .insert(db, server.id, reason, user.id, None)
+    .await;
+
+    Ok(Json(channel.into()))
+}
@@ -0,0 +1,262 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Channel, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{
+    calculate_channel_permissions, ChannelPermission, Override, OverrideField,
+};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::audit_log_reason::AuditLogReason;
+
+/// # Set Member Permission
+///
+/// Sets permissions for the specified member in this channel.
+///
+/// These take precedence over any role permissions.
+#[openapi(tag = "Channel Permissions")]
+#[put("/<target>/permissions/members/<member_id>", data = "<data>")]
+pub async fn set_member_permissions(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    member_id: Reference<'_>,
+    data: Json<v0::DataSetMemberPermissions>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+
+    let mut channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    let current_value: Override = match &channel {
+        Channel::TextChannel {
+            user_permissions, ..
+        }
+        | Channel::ForumChannel {
+            user_permissions, ..
+        } => user_permissions
+            .get(member_id.id)
+            .copied()
+            .unwrap_or_default()
+            .into(),
+        _ => return Err(create_error!(InvalidOperation)),
+    };
+
+    query.set_server_from_channel().await;
+    let server = query
+        .server_ref()
+        .as_ref()
+        .ok_or_else(|| create_error!(InvalidOperation))?
+        .clone()
+        .into_owned();
+
+    let member = member_id.as_member(db, &server.id).await?;
+
+    // Prevent us from editing ourselves or members who do not rank below us
+    if member.id.user == user.id
+        || (server.owner != user.id
+            && member.get_ranking(&server) <= query.get_member_rank().unwrap_or(i64::MIN))
+    {
+        return Err(create_error!(NotElevated));
+    }
+
+    // Ensure we have access to grant these permissions forwards
+    permissions
+        .throw_permission_override(current_value, &data.permissions)
+        .await?;
+
+    let override_field: OverrideField = data.permissions.into();
+    channel
+        .set_user_permission(db, &member.id.user, Some(override_field))
+        .await?;
+
+    sync_voice_permissions(db, voice_client, &channel, Some(&server), None).await?;
+
+    AuditLogEntryAction::ChannelUserPermissionsEdit {
+        channel: channel.id().to_string(),
+        user: member.id.user,
+        permissions: Some(override_field),
+    }
+    .insert(db, server.id, reason, user.id, None)
+    .await;
+
+    Ok(Json(channel.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, PartialChannel, PartialMember};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, Override, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn member_override_grants_access() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, _) = harness.new_server(&owner).await;
+        let mut channel = harness.new_channel(&server).await;
+
+        let (_, session, user) = harness.new_user().await;
+        Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    default_permissions: Some(OverrideField {
+                        a: 0,
+                        d: ChannelPermission::ViewChannel as i64,
+                    }),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to hide channel");
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        let response = harness
+            .client
+            .put(format!(
+                "/channels/{}/permissions/members/{}",
+                channel.id(),
+                user.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataSetMemberPermissions {
+                    permissions: Override {
+                        allow: ChannelPermission::ViewChannel as u64,
+                        deny: 0,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .delete(format!(
+                "/channels/{}/permissions/members/{}",
+                channel.id(),
+                user.id
+            ))
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .get(format!("/channels/{}", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+
+    #[rocket::async_test]
+    async fn cannot_override_own_permissions() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (server, _) = harness.new_server(&owner).await;
+        let channel = harness.new_channel(&server).await;
+
+        let (_, session, user) = harness.new_user().await;
+        let (member, _) = Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        let role = harness
+            .new_role(
+                &server,
+                1,
+                Some(OverrideField {
+                    a: ChannelPermission::ManagePermissions as i64,
+                    d: 0,
+                }),
+            )
+            .await;
+
+        harness
+            .db
+            .update_member(
+                &member.id,
+                &PartialMember {
+                    roles: Some(vec![role.id.clone()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to add role to user");
+
+        let response = harness
+            .client
+            .put(format!(
+                "/channels/{}/permissions/members/{}",
+                channel.id(),
+                user.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataSetMemberPermissions {
+                    permissions: Override {
+                        allow: ChannelPermission::ManagePermissions as u64,
+                        deny: 0,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+}
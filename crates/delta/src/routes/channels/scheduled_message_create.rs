@@ -0,0 +1,185 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, ScheduledMessage, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Schedule Message
+///
+/// Schedules a message to be sent to the given channel at a later time.
+///
+/// Permissions are checked again when the message is sent.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/scheduled", data = "<data>")]
+pub async fn create_scheduled_message(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    data: Json<v0::DataScheduleMessage>,
+) -> Result<Json<v0::ScheduledMessage>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    ScheduledMessage::create(
+        db,
+        channel.id().to_string(),
+        user.id.clone(),
+        data.message,
+        data.send_at,
+    )
+    .await
+    .map(|scheduled_message| Json(scheduled_message.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use iso8601_timestamp::{Duration, Timestamp};
+    use revolt_database::{Member, ScheduledMessage};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn schedule_and_cancel_message() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (_, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/messages/scheduled", channel.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataScheduleMessage {
+                    message: v0::DataMessageSend {
+                        content: Some("Announcement".to_string()),
+                        nonce: None,
+                        attachments: None,
+                        replies: None,
+                        embeds: None,
+                        masquerade: None,
+                        interactions: None,
+                        components: None,
+                        poll: None,
+                        expires_in: None,
+                        flags: None,
+                    },
+                    send_at: Timestamp::now_utc() + Duration::hours(1),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let scheduled_message: v0::ScheduledMessage = response.into_json().await.unwrap();
+
+        let scheduled_messages = harness
+            .db
+            .fetch_scheduled_messages(channel.id(), Some(&user.id))
+            .await
+            .expect("Failed to fetch scheduled messages");
+        assert_eq!(scheduled_messages.len(), 1);
+
+        let response = harness
+            .client
+            .delete(format!(
+                "/channels/{}/messages/scheduled/{}",
+                channel.id(),
+                scheduled_message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+
+        assert!(harness
+            .db
+            .fetch_scheduled_message(&scheduled_message.id)
+            .await
+            .is_err());
+    }
+
+    #[rocket::async_test]
+    async fn failed_message_is_kept() {
+        let harness = TestHarness::new().await;
+        let (_, _, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+
+        let (_, channels) = Member::create(&harness.db, &server, &user, Some(channels))
+            .await
+            .expect("Failed to create member");
+        let channel = &channels[0];
+
+        // Empty messages are rejected when sent
+        let scheduled_message = ScheduledMessage {
+            id: ulid::Ulid::new().to_string(),
+            channel: channel.id().to_string(),
+            author: user.id.clone(),
+            data: v0::DataMessageSend {
+                content: None,
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
+                flags: None,
+            },
+            send_at: Timestamp::now_utc() - Duration::minutes(1),
+            failure: None,
+            attempts: 0,
+            retry_at: None,
+        };
+
+        harness
+            .db
+            .insert_scheduled_message(&scheduled_message)
+            .await
+            .expect("Failed to insert scheduled message");
+
+        assert!(scheduled_message
+            .clone()
+            .send(&harness.db, Some(&harness.amqp))
+            .await
+            .is_err());
+
+        let failed = harness
+            .db
+            .fetch_scheduled_message(&scheduled_message.id)
+            .await
+            .expect("Failed message should be kept");
+        assert_eq!(failed.failure.as_deref(), Some("EmptyMessage"));
+
+        assert!(harness
+            .db
+            .fetch_due_scheduled_messages()
+            .await
+            .expect("Failed to fetch due messages")
+            .is_empty());
+    }
+}
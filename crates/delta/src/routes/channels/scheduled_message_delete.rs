@@ -0,0 +1,35 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Cancel Scheduled Message
+///
+/// Cancel a message you have scheduled or one you have permission to delete.
+#[openapi(tag = "Messaging")]
+#[delete("/<target>/messages/scheduled/<id>", rank = 1)]
+pub async fn delete_scheduled_message(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    id: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let scheduled_message = db.fetch_scheduled_message(id.id).await?;
+    if scheduled_message.channel != target.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if scheduled_message.author != user.id {
+        let channel = target.as_channel(db).await?;
+        let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+        calculate_channel_permissions(&mut query)
+            .await
+            .throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    scheduled_message.delete(db).await.map(|_| EmptyResponse)
+}
@@ -0,0 +1,58 @@
+use revolt_database::{
+    util::reference::Reference, Database, FieldsScheduledMessage, PartialScheduledMessage, User,
+};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Scheduled Message
+///
+/// Change the content or send time of a message you have scheduled.
+///
+/// Messages which previously failed to send are queued again.
+#[openapi(tag = "Messaging")]
+#[patch("/<target>/messages/scheduled/<id>", data = "<data>")]
+pub async fn edit_scheduled_message(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    id: Reference<'_>,
+    data: Json<v0::DataEditScheduledMessage>,
+) -> Result<Json<v0::ScheduledMessage>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut scheduled_message = db.fetch_scheduled_message(id.id).await?;
+    if scheduled_message.channel != target.id {
+        return Err(create_error!(NotFound));
+    }
+
+    // Only the author may change what is sent on their behalf
+    if scheduled_message.author != user.id {
+        return Err(create_error!(CannotEditMessage));
+    }
+
+    // Editing a message which failed to send queues it again
+    scheduled_message
+        .update(
+            db,
+            PartialScheduledMessage {
+                data: data.message,
+                send_at: data.send_at,
+                attempts: Some(0),
+                ..Default::default()
+            },
+            vec![
+                FieldsScheduledMessage::Failure,
+                FieldsScheduledMessage::RetryAt,
+            ],
+        )
+        .await?;
+
+    Ok(Json(scheduled_message.into()))
+}
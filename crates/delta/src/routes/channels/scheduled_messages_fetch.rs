@@ -0,0 +1,42 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Scheduled Messages
+///
+/// Fetch messages scheduled to be sent in the given channel.
+///
+/// Lists every scheduled message if you have `ManageMessages`, otherwise only your own.
+#[openapi(tag = "Messaging")]
+#[get("/<target>/messages/scheduled", rank = 1)]
+pub async fn fetch_scheduled_messages(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+) -> Result<Json<Vec<v0::ScheduledMessage>>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let author = if permissions.has_channel_permission(ChannelPermission::ManageMessages) {
+        None
+    } else {
+        Some(user.id.as_str())
+    };
+
+    let mut scheduled_messages = db.fetch_scheduled_messages(channel.id(), author).await?;
+    scheduled_messages.sort_by_key(|scheduled_message| scheduled_message.send_at);
+
+    Ok(Json(
+        scheduled_messages
+            .into_iter()
+            .map(|scheduled_message| scheduled_message.into())
+            .collect(),
+    ))
+}
@@ -0,0 +1,108 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Thread
+///
+/// Create a new thread from a message in a server channel.
+#[openapi(tag = "Threads")]
+#[post("/<target>/messages/<msg>/threads", data = "<data>")]
+pub async fn create_thread(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    data: Json<v0::DataCreateThread>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    let mut message = msg.as_message_in_channel(db, channel.id()).await?;
+
+    Ok(Json(
+        Channel::create_thread(db, &channel, &mut message, data, user.id)
+            .await?
+            .into(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{events::client::EventV1, util::reference::Reference};
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn create_thread() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+        let (channel, _, message) = harness.new_message(&user, &server, channels).await;
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{}/messages/{}/threads",
+                channel.id(),
+                message.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataCreateThread {
+                    name: "Side discussion".to_string(),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let thread: v0::Channel = response.into_json().await.expect("`Channel`");
+
+        let v0::Channel::Thread {
+            id,
+            parent,
+            members,
+            ..
+        } = &thread
+        else {
+            panic!("Expected a thread");
+        };
+
+        assert_eq!(parent, channel.id());
+        assert_eq!(members, &vec![user.id.clone()]);
+
+        harness
+            .wait_for_event(&server.id, |event| match event {
+                EventV1::ThreadCreate(channel) => channel.id() == id,
+                _ => false,
+            })
+            .await;
+
+        let message = Reference::from_unchecked(&message.id)
+            .as_message(&harness.db)
+            .await
+            .expect("`Message`");
+
+        assert_eq!(message.thread.map(|thread| thread.id), Some(id.clone()));
+    }
+}
@@ -0,0 +1,64 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, PartialChannel, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Thread
+///
+/// Rename, archive or unarchive the thread spawned from a message.
+#[openapi(tag = "Threads")]
+#[patch("/<target>/messages/<msg>/threads", data = "<data>")]
+pub async fn edit_thread(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+    data: Json<v0::DataEditThread>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let summary = message.thread.ok_or_else(|| create_error!(NotFound))?;
+    let mut thread = db.fetch_channel(&summary.id).await?;
+
+    // Thread owners may manage their own thread
+    if let Channel::Thread { owner, .. } = &thread {
+        if owner != &user.id {
+            permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+        }
+    }
+
+    if let Some(name) = data.name {
+        thread
+            .update(
+                db,
+                PartialChannel {
+                    name: Some(name),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await?;
+    }
+
+    if let Some(archived) = data.archived {
+        thread.set_thread_archived(db, archived).await?;
+    }
+
+    Ok(Json(thread.into()))
+}
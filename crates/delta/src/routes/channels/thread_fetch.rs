@@ -0,0 +1,31 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Thread
+///
+/// Fetch the thread spawned from a message.
+#[openapi(tag = "Threads")]
+#[get("/<target>/messages/<msg>/threads")]
+pub async fn fetch_thread(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<Json<v0::Channel>> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let thread = message.thread.ok_or_else(|| create_error!(NotFound))?;
+
+    Ok(Json(db.fetch_channel(&thread.id).await?.into()))
+}
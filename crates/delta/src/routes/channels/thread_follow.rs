@@ -0,0 +1,35 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Follow Thread
+///
+/// Follow the thread spawned from a message.
+#[openapi(tag = "Threads")]
+#[put("/<target>/messages/<msg>/threads/follow")]
+pub async fn follow_thread(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let summary = message.thread.ok_or_else(|| create_error!(NotFound))?;
+
+    db.fetch_channel(&summary.id)
+        .await?
+        .add_user_to_thread(db, &user.id)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,26 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Unfollow Thread
+///
+/// Stop following the thread spawned from a message.
+#[openapi(tag = "Threads")]
+#[delete("/<target>/messages/<msg>/threads/follow")]
+pub async fn unfollow_thread(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    msg: Reference<'_>,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let message = msg.as_message_in_channel(db, channel.id()).await?;
+    let summary = message.thread.ok_or_else(|| create_error!(NotFound))?;
+
+    db.fetch_channel(&summary.id)
+        .await?
+        .remove_user_from_thread(db, &user.id)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -58,6 +58,7 @@ pub async fn create_webhook(
         channel_id: channel.id().to_string(),
         permissions: *DEFAULT_WEBHOOK_PERMISSIONS,
         token: Some(nanoid::nanoid!(64)),
+        source_channel_id: None,
     };
 
     webhook.create(db).await?;
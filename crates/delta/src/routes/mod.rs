@@ -112,6 +112,8 @@ fn custom_openapi_spec() -> OpenApi {
               "Channel Invites",
               "Channel Permissions",
               "Messaging",
+              "Threads",
+              "Forums",
               "Interactions",
               "Groups",
               "Voice",
@@ -256,6 +258,16 @@ fn custom_openapi_spec() -> OpenApi {
                 description: Some("Send and manipulate messages".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Threads".to_owned(),
+                description: Some("Create, follow and archive message threads".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Forums".to_owned(),
+                description: Some("Create and browse posts in forum channels".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Groups".to_owned(),
                 description: Some("Create, invite users and manipulate groups".to_owned()),
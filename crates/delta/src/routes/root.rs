@@ -100,6 +100,12 @@ pub struct GlobalLimits {
     server_roles: i64,
     /// max server channels
     server_channels: i64,
+    /// max tags per forum channel
+    forum_tags: i64,
+    /// max options per poll
+    poll_options: i64,
+    /// max edit revisions kept per message
+    message_revisions: i64,
     body_limit_size: i64,
 
     /// restrict server creation to these users.
@@ -240,6 +246,9 @@ pub async fn root() -> Result<Json<RevoltConfig>> {
                     server_emoji: config.features.limits.global.server_emoji as i64,
                     server_roles: config.features.limits.global.server_roles as i64,
                     server_channels: config.features.limits.global.server_channels as i64,
+                    forum_tags: config.features.limits.global.forum_tags as i64,
+                    poll_options: config.features.limits.global.poll_options as i64,
+                    message_revisions: config.features.limits.global.message_revisions as i64,
                     body_limit_size: config.features.limits.global.body_limit_size as i64,
                     restrict_server_creation: config
                         .features
@@ -105,6 +105,10 @@ mod test {
                 archived: None,
                 voice: None,
                 slowmode: None,
+                message_ttl: None,
+                announcement: None,
+                default_sort: None,
+                require_tag: None,
                 remove: Vec::new(),
             })
             .dispatch()
@@ -127,6 +131,10 @@ mod test {
                 archived: None,
                 voice: None,
                 slowmode: None,
+                message_ttl: None,
+                announcement: None,
+                default_sort: None,
+                require_tag: None,
                 remove: Vec::new(),
             })
             .dispatch()
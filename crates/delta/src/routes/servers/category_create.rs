@@ -0,0 +1,98 @@
+use std::collections::{HashMap, HashSet};
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    AuditLogEntryAction, Category, Database, PartialServer, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+use crate::util::{audit_log_reason::AuditLogReason, categories::throw_if_cannot_move_channels};
+
+/// # Create Category
+///
+/// Create a new channel category in a server.
+///
+/// Channels given are moved out of any category they were previously in.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/categories", data = "<data>")]
+pub async fn create_category(
+    db: &State<Database>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    data: Json<v0::DataCreateCategory>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let mut channel_ids = HashSet::new();
+    for channel in &data.channels {
+        if !server.channels.contains(channel) {
+            return Err(create_error!(NotFound));
+        }
+
+        if !channel_ids.insert(channel) {
+            return Err(create_error!(InvalidOperation));
+        }
+    }
+
+    let category = Category {
+        id: Ulid::new().to_string(),
+        title: data.title,
+        channels: data.channels,
+        default_permissions: None,
+        role_permissions: HashMap::new(),
+    };
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    for existing in &mut categories {
+        existing
+            .channels
+            .retain(|channel| !category.channels.contains(channel));
+    }
+
+    categories.push(category.clone());
+
+    throw_if_cannot_move_channels(
+        &query,
+        &permissions,
+        &user,
+        &server,
+        server.categories.as_deref().unwrap_or_default(),
+        &categories,
+    )?;
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntryAction::CategoryCreate {
+        category: category.id.clone(),
+        title: category.title.clone(),
+    }
+    .insert(db, server.id.clone(), reason, user.id, None)
+    .await;
+
+    Ok(Json(category.into()))
+}
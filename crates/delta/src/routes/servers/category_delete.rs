@@ -0,0 +1,174 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Database, PartialServer, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+use crate::util::{audit_log_reason::AuditLogReason, categories::throw_if_cannot_move_channels};
+
+/// # Delete Category
+///
+/// Delete a channel category by its id.
+///
+/// Channels in the category are kept and become uncategorised.
+#[openapi(tag = "Server Information")]
+#[delete("/<target>/categories/<category_id>")]
+pub async fn delete_category(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    category_id: String,
+) -> Result<EmptyResponse> {
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let index = categories
+        .iter()
+        .position(|category| category.id == category_id)
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let category = categories.remove(index);
+
+    // Channels in the category lose its overrides
+    throw_if_cannot_move_channels(
+        &query,
+        &permissions,
+        &user,
+        &server,
+        server.categories.as_deref().unwrap_or_default(),
+        &categories,
+    )?;
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    // Channels no longer inherit this category's permissions
+    for channel_id in &category.channels {
+        let channel = Reference::from_unchecked(channel_id).as_channel(db).await?;
+
+        sync_voice_permissions(db, voice_client, &channel, Some(&server), None).await?;
+    }
+
+    AuditLogEntryAction::CategoryDelete {
+        category: category_id,
+        title: category.title,
+    }
+    .insert(db, server.id.clone(), reason, user.id, None)
+    .await;
+
+    Ok(EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, PartialMember};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn deleting_category_with_channels_requires_manage_permissions() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+
+        let (_, session, user) = harness.new_user().await;
+        let (member, _) = Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        let role = harness
+            .new_role(
+                &server,
+                1,
+                Some(OverrideField {
+                    a: ChannelPermission::ManageChannel as i64,
+                    d: 0,
+                }),
+            )
+            .await;
+
+        harness
+            .db
+            .update_member(
+                &member.id,
+                &PartialMember {
+                    roles: Some(vec![role.id.clone()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to add role to user");
+
+        let mut categories = vec![];
+        for channels in [vec![channels[0].id().to_string()], vec![]] {
+            let response = harness
+                .client
+                .post(format!("/servers/{}/categories", server.id))
+                .header(ContentType::JSON)
+                .header(Header::new(
+                    "x-session-token",
+                    owner_session.token.to_string(),
+                ))
+                .body(
+                    json!(v0::DataCreateCategory {
+                        title: "Staff".to_string(),
+                        channels,
+                    })
+                    .to_string(),
+                )
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::Ok);
+            let category: v0::Category = response.into_json().await.expect("`Category`");
+            categories.push(category);
+        }
+
+        // Deleting a category moves its channels out of it ...
+        let response = harness
+            .client
+            .delete(format!(
+                "/servers/{}/categories/{}",
+                server.id, categories[0].id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // ... which is not a concern for empty categories
+        let response = harness
+            .client
+            .delete(format!(
+                "/servers/{}/categories/{}",
+                server.id, categories[1].id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::HashSet;
+
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Database, PartialServer, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+use crate::util::{audit_log_reason::AuditLogReason, categories::throw_if_cannot_move_channels};
+
+/// # Edit Category
+///
+/// Edit a channel category by its id.
+///
+/// Channels given are moved out of any other category they were in.
+#[openapi(tag = "Server Information")]
+#[patch("/<target>/categories/<category_id>", data = "<data>")]
+pub async fn edit_category(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    category_id: String,
+    data: Json<v0::DataEditCategory>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let before = categories
+        .iter()
+        .find(|category| category.id == category_id)
+        .cloned()
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let mut after = before.clone();
+    if let Some(title) = data.title {
+        after.title = title;
+    }
+
+    if let Some(channels) = data.channels {
+        let mut channel_ids = HashSet::new();
+        for channel in &channels {
+            if !server.channels.contains(channel) {
+                return Err(create_error!(NotFound));
+            }
+
+            if !channel_ids.insert(channel) {
+                return Err(create_error!(InvalidOperation));
+            }
+        }
+
+        after.channels = channels;
+    }
+
+    for category in &mut categories {
+        if category.id == category_id {
+            *category = after.clone();
+        } else {
+            category
+                .channels
+                .retain(|channel| !after.channels.contains(channel));
+        }
+    }
+
+    throw_if_cannot_move_channels(
+        &query,
+        &permissions,
+        &user,
+        &server,
+        server.categories.as_deref().unwrap_or_default(),
+        &categories,
+    )?;
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    // Moving channels between categories changes what they inherit
+    for channel_id in before.channels.iter().chain(&after.channels) {
+        let channel = Reference::from_unchecked(channel_id).as_channel(db).await?;
+
+        sync_voice_permissions(db, voice_client, &channel, Some(&server), None).await?;
+    }
+
+    AuditLogEntryAction::CategoryEdit {
+        category: category_id,
+        before,
+        after: after.clone(),
+    }
+    .insert(db, server.id.clone(), reason, user.id, None)
+    .await;
+
+    Ok(Json(after.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, PartialMember, PartialRole};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, Override, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn moving_channels_requires_manage_permissions() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+        let channel_id = channels[0].id().to_string();
+
+        let (_, session, user) = harness.new_user().await;
+        let (member, _) = Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        let role = harness
+            .new_role(
+                &server,
+                1,
+                Some(OverrideField {
+                    a: ChannelPermission::ManageChannel as i64,
+                    d: 0,
+                }),
+            )
+            .await;
+
+        harness
+            .db
+            .update_member(
+                &member.id,
+                &PartialMember {
+                    roles: Some(vec![role.id.clone()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to add role to user");
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/categories", server.id))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataCreateCategory {
+                    title: "Staff".to_string(),
+                    channels: vec![channel_id.clone()],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let category: v0::Category = response.into_json().await.expect("`Category`");
+
+        // Renaming only needs manage channel ...
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/categories/{}", server.id, category.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataEditCategory {
+                    title: Some("Team".to_string()),
+                    channels: None,
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        // ... but moving channels out changes the overrides which apply to them
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/categories/{}", server.id, category.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataEditCategory {
+                    title: None,
+                    channels: Some(vec![]),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // Even with manage permissions, channels can't be moved out from
+        // under overrides for roles which aren't below us
+        harness
+            .db
+            .update_role(
+                &server.id,
+                &role.id,
+                &PartialRole {
+                    permissions: Some(OverrideField {
+                        a: (ChannelPermission::ManageChannel as u64
+                            | ChannelPermission::ManagePermissions as u64)
+                            as i64,
+                        d: 0,
+                    }),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update role");
+
+        let response = harness
+            .client
+            .put(format!(
+                "/servers/{}/categories/{}/permissions/{}",
+                server.id, category.id, role.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataSetRolePermissions {
+                    permissions: Override {
+                        allow: ChannelPermission::SendMessage as u64,
+                        deny: 0,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/categories/{}", server.id, category.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataEditCategory {
+                    title: None,
+                    channels: Some(vec![]),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/categories/{}", server.id, category.id))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataEditCategory {
+                    title: None,
+                    channels: Some(vec![]),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
@@ -0,0 +1,235 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Database, PartialServer, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{
+    calculate_server_permissions, ChannelPermission, Override, OverrideField,
+};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::audit_log_reason::AuditLogReason;
+
+/// # Set Category Role Permission
+///
+/// Sets permissions for the specified role in every channel of this category.
+///
+/// Channel overrides for the role take precedence over these.
+#[openapi(tag = "Server Permissions")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/<role_id>",
+    data = "<data>",
+    rank = 2
+)]
+pub async fn set_category_role_permission(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    category_id: String,
+    role_id: String,
+    data: Json<v0::DataSetRolePermissions>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let rank = server
+        .roles
+        .get(&role_id)
+        .map(|role| role.rank)
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    // Prevent us from editing roles above us
+    if server.owner != user.id && rank <= query.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(create_error!(NotElevated));
+    }
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let category = categories
+        .iter_mut()
+        .find(|category| category.id == category_id)
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    // Ensure we have access to grant these permissions forwards
+    let current_value: Override = category
+        .role_permissions
+        .get(&role_id)
+        .copied()
+        .unwrap_or_default()
+        .into();
+
+    permissions
+        .throw_permission_override(current_value, &data.permissions)
+        .await?;
+
+    let override_field: OverrideField = data.permissions.into();
+    category
+        .role_permissions
+        .insert(role_id.clone(), override_field);
+
+    let category = category.clone();
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    for channel_id in &category.channels {
+        let channel = Reference::from_unchecked(channel_id).as_channel(db).await?;
+
+        sync_voice_permissions(db, voice_client, &channel, Some(&server), Some(&role_id)).await?;
+    }
+
+    AuditLogEntryAction::CategoryRolePermissionsEdit {
+        category: category_id,
+        role: role_id,
+        permissions: override_field,
+    }
+    .insert(db, server.id.clone(), reason, user.id, None)
+    .await;
+
+    Ok(Json(category.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Member, PartialMember};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, Override};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn category_overrides_apply_to_channels() {
+        let harness = TestHarness::new().await;
+        let (_, owner_session, owner) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+        let channel_id = channels[0].id().to_string();
+
+        let (_, session, user) = harness.new_user().await;
+        let (member, _) = Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        let role = harness.new_role(&server, 1, None).await;
+        harness
+            .db
+            .update_member(
+                &member.id,
+                &PartialMember {
+                    roles: Some(vec![role.id.clone()]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to add role to user");
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/categories", server.id))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataCreateCategory {
+                    title: "Staff".to_string(),
+                    channels: vec![channel_id.clone()],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let category: v0::Category = response.into_json().await.expect("`Category`");
+
+        // Hide the category from everyone ...
+        let response = harness
+            .client
+            .put(format!(
+                "/servers/{}/categories/{}/permissions/default",
+                server.id, category.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataSetRolePermissions {
+                    permissions: Override {
+                        allow: 0,
+                        deny: ChannelPermission::ViewChannel as u64,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .get(format!("/channels/{channel_id}"))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        // ... then grant it back to the role
+        let response = harness
+            .client
+            .put(format!(
+                "/servers/{}/categories/{}/permissions/{}",
+                server.id, category.id, role.id
+            ))
+            .header(ContentType::JSON)
+            .header(Header::new(
+                "x-session-token",
+                owner_session.token.to_string(),
+            ))
+            .body(
+                json!(v0::DataSetRolePermissions {
+                    permissions: Override {
+                        allow: ChannelPermission::ViewChannel as u64,
+                        deny: 0,
+                    },
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .get(format!("/channels/{channel_id}"))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+    }
+}
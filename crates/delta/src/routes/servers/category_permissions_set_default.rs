@@ -0,0 +1,83 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    voice::{sync_voice_permissions, VoiceClient},
+    AuditLogEntryAction, Database, PartialServer, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission, Override};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+use crate::util::audit_log_reason::AuditLogReason;
+
+/// # Set Category Default Permission
+///
+/// Sets permissions for the default role in every channel of this category.
+///
+/// Channel overrides take precedence over these.
+#[openapi(tag = "Server Permissions")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/default",
+    data = "<data>",
+    rank = 1
+)]
+pub async fn set_default_category_permissions(
+    db: &State<Database>,
+    voice_client: &State<VoiceClient>,
+    user: User,
+    reason: AuditLogReason,
+    target: Reference<'_>,
+    category_id: String,
+    data: Json<v0::DataSetRolePermissions>,
+) -> Result<Json<v0::Category>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    let permissions = calculate_server_permissions(&mut query).await;
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let category = categories
+        .iter_mut()
+        .find(|category| category.id == category_id)
+        .ok_or_else(|| create_error!(NotFound))?;
+
+    // Ensure we have permissions to grant these permissions forwards
+    let current_value: Option<Override> = category.default_permissions.map(Into::into);
+    permissions
+        .throw_permission_override(current_value, &data.permissions)
+        .await?;
+
+    let before = category.clone();
+    category.default_permissions = Some(data.permissions.into());
+
+    let after = category.clone();
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    for channel_id in &after.channels {
+        let channel = Reference::from_unchecked(channel_id).as_channel(db).await?;
+
+        sync_voice_permissions(db, voice_client, &channel, Some(&server), None).await?;
+    }
+
+    AuditLogEntryAction::CategoryEdit {
+        category: category_id,
+        before,
+        after: after.clone(),
+    }
+    .insert(db, server.id.clone(), reason, user.id, None)
+    .await;
+
+    Ok(Json(after.into()))
+}
@@ -0,0 +1,47 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Server Commands
+///
+/// Fetch commands which can be invoked in a server, from all bots in it.
+///
+/// Optionally filter to commands starting with a prefix, for autocompletion.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/commands?<options..>")]
+pub async fn fetch_commands(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    options: v0::OptionsQueryCommands,
+) -> Result<Json<Vec<v0::BotCommand>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    let bot_ids = db.fetch_server_bot_ids(&server.id).await?;
+
+    let mut commands: Vec<v0::BotCommand> = db
+        .fetch_server_commands(&bot_ids, Some(&server.id))
+        .await?
+        .into_iter()
+        .filter(|command| {
+            options
+                .prefix
+                .as_deref()
+                .map(|prefix| command.name.starts_with(prefix))
+                .unwrap_or(true)
+        })
+        .map(Into::into)
+        .collect();
+
+    commands.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(Json(commands))
+}
@@ -1,5 +1,6 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use iso8601_timestamp::Timestamp;
 use revolt_database::{
     events::client::EventV1,
     util::{
@@ -85,7 +86,11 @@ pub async fn edit(
         }
     }
 
-    if data.roles.is_some() || data.remove.contains(&v0::FieldsMember::Roles) {
+    if data.roles.is_some()
+        || data.role_expiries.is_some()
+        || data.remove.contains(&v0::FieldsMember::Roles)
+        || data.remove.contains(&v0::FieldsMember::RoleExpiries)
+    {
         permissions.throw_if_lacking_channel_permission(ChannelPermission::AssignRoles)?;
     }
 
@@ -163,6 +168,54 @@ pub async fn edit(
         return Err(create_error!(NotElevated));
     }
 
+    // Check expiries only apply to roles the member will have and which we can manage
+    if let Some(role_expiries) = &data.role_expiries {
+        if data.remove.contains(&v0::FieldsMember::RoleExpiries) {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let roles = data.roles.as_ref().unwrap_or(&member.roles);
+        let now = Timestamp::now_utc();
+        let mut seen = HashSet::new();
+
+        for expiry in role_expiries {
+            if !seen.insert(&expiry.role) || expiry.expires_at <= now {
+                return Err(create_error!(InvalidOperation));
+            }
+
+            if !roles.contains(&expiry.role) || !server.roles.contains_key(&expiry.role) {
+                return Err(create_error!(InvalidRole));
+            }
+        }
+    }
+
+    // Check that we can manage every role whose expiry is added, changed or removed
+    if data.role_expiries.is_some() || data.remove.contains(&v0::FieldsMember::RoleExpiries) {
+        let current_expiries: HashMap<&String, &Timestamp> = member
+            .role_expiries
+            .iter()
+            .map(|expiry| (&expiry.role, &expiry.expires_at))
+            .collect();
+
+        let new_expiries: HashMap<&String, &Timestamp> = data
+            .role_expiries
+            .iter()
+            .flatten()
+            .map(|expiry| (&expiry.role, &expiry.expires_at))
+            .collect();
+
+        for role_id in current_expiries.keys().chain(new_expiries.keys()) {
+            if current_expiries.get(role_id) != new_expiries.get(role_id)
+                && server
+                    .roles
+                    .get(*role_id)
+                    .is_some_and(|role| role.rank <= our_ranking)
+            {
+                return Err(create_error!(NotElevated));
+            }
+        }
+    }
+
     // Check permissions against roles in diff
     if let Some(roles) = &data.roles {
         let current_roles = member.roles.iter().collect::<HashSet<&String>>();
@@ -187,17 +240,50 @@ pub async fn edit(
         pronouns,
         avatar,
         roles,
+        role_expiries,
         timeout,
-        remove,
+        mut remove,
         can_publish,
         can_receive,
         voice_channel: _,
     } = data;
 
+    // Drop expiries for roles which are no longer assigned
+    let role_expiries = role_expiries
+        .map(|expiries| expiries.into_iter().map(Into::into).collect())
+        .or_else(|| {
+            roles.as_ref().and_then(|roles| {
+                if member
+                    .role_expiries
+                    .iter()
+                    .all(|expiry| roles.contains(&expiry.role))
+                {
+                    None
+                } else {
+                    Some(
+                        member
+                            .role_expiries
+                            .iter()
+                            .filter(|expiry| roles.contains(&expiry.role))
+                            .cloned()
+                            .collect(),
+                    )
+                }
+            })
+        });
+
+    if remove.contains(&v0::FieldsMember::Roles)
+        && !remove.contains(&v0::FieldsMember::RoleExpiries)
+        && !member.role_expiries.is_empty()
+    {
+        remove.push(v0::FieldsMember::RoleExpiries);
+    }
+
     let mut partial = PartialMember {
         nickname,
         pronouns,
         roles,
+        role_expiries,
         timeout,
         can_publish,
         can_receive,
@@ -323,3 +409,73 @@ pub async fn edit(
 
     Ok(Json(member.into()))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use iso8601_timestamp::{Duration, Timestamp};
+    use revolt_database::{Member, PartialMember, RoleExpiry};
+    use revolt_permissions::{ChannelPermission, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn cannot_remove_expiry_of_own_role() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (server, _) = harness.new_server(&owner).await;
+
+        let (_, session, user) = harness.new_user().await;
+        let (member, _) = Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("`Member`");
+
+        let role = harness
+            .new_role(
+                &server,
+                1,
+                Some(OverrideField {
+                    a: ChannelPermission::AssignRoles as i64,
+                    d: 0,
+                }),
+            )
+            .await;
+
+        harness
+            .db
+            .update_member(
+                &member.id,
+                &PartialMember {
+                    roles: Some(vec![role.id.clone()]),
+                    role_expiries: Some(vec![RoleExpiry {
+                        role: role.id.clone(),
+                        expires_at: Timestamp::now_utc() + Duration::minutes(5),
+                    }]),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to add role to user");
+
+        // Trial roles can't be made permanent by whoever holds them
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/members/{}", server.id, user.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(json!({ "remove": ["RoleExpiries"] }).to_string())
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        drop(response);
+
+        let member = harness
+            .db
+            .fetch_member(&server.id, &user.id)
+            .await
+            .expect("`Member`");
+
+        assert_eq!(member.role_expiries.len(), 1);
+    }
+}
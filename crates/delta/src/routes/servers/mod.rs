@@ -5,7 +5,13 @@ mod audit_log_query;
 mod ban_create;
 mod ban_list;
 mod ban_remove;
+mod category_create;
+mod category_delete;
+mod category_edit;
+mod category_permissions_set;
+mod category_permissions_set_default;
 mod channel_create;
+mod commands_fetch;
 mod emoji_list;
 mod invites_fetch;
 mod member_edit;
@@ -25,6 +31,11 @@ mod server_create;
 mod server_delete;
 mod server_edit;
 mod server_fetch;
+mod server_search;
+mod template_create;
+mod template_delete;
+mod template_sync;
+mod templates_fetch;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -33,6 +44,7 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         server_fetch::fetch,
         server_edit::edit,
         server_ack::ack,
+        server_search::search,
         channel_create::create_server_channel,
         member_fetch_all::fetch_all,
         member_remove::kick,
@@ -52,5 +64,15 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         emoji_list::list_emoji,
         roles_edit_positions::edit_role_ranks,
         audit_log_query::query,
+        commands_fetch::fetch_commands,
+        category_create::create_category,
+        category_edit::edit_category,
+        category_delete::delete_category,
+        category_permissions_set::set_category_role_permission,
+        category_permissions_set_default::set_default_category_permissions,
+        template_create::create_template,
+        templates_fetch::fetch_templates,
+        template_sync::sync_template,
+        template_delete::delete_template,
     ]
 }
@@ -10,7 +10,7 @@ use revolt_result::{create_error, Result};
 use rocket::{serde::json::Json, State};
 use validator::Validate;
 
-use crate::util::audit_log_reason::AuditLogReason;
+use crate::util::{audit_log_reason::AuditLogReason, categories::throw_if_cannot_move_channels};
 
 /// # Edit Server
 ///
@@ -137,7 +137,7 @@ pub async fn edit(
 
     if let Some(categories) = &mut partial.categories {
         let mut channel_ids = HashSet::new();
-        for category in categories {
+        for category in categories.iter_mut() {
             for channel in &category.channels {
                 if channel_ids.contains(channel) {
                     return Err(create_error!(InvalidOperation));
@@ -149,7 +149,27 @@ pub async fn edit(
             category
                 .channels
                 .retain(|item| server.channels.contains(item));
+
+            // Category permissions can only be changed through their own routes
+            let existing = server
+                .categories
+                .as_ref()
+                .and_then(|existing| existing.iter().find(|item| item.id == category.id));
+
+            category.default_permissions = existing.and_then(|item| item.default_permissions);
+            category.role_permissions = existing
+                .map(|item| item.role_permissions.clone())
+                .unwrap_or_default();
         }
+
+        throw_if_cannot_move_channels(
+            &query,
+            &permissions,
+            &user,
+            &server,
+            server.categories.as_deref().unwrap_or_default(),
+            categories,
+        )?;
     }
 
     // 3. Apply new icon
@@ -0,0 +1,197 @@
+use revolt_database::{
+    util::{
+        bulk_permissions::BulkDatabasePermissionQuery, permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    Database, Message, MessageFilter, MessageQuery, MessageTimePeriod, User,
+};
+use revolt_models::v0;
+use revolt_permissions::PermissionQuery;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Search for Messages in Server
+///
+/// Search for messages across every channel in a server you can read.
+///
+/// Each message includes the id of the channel it was sent in, the query
+/// supports the same operators as channel search.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/search", data = "<options>")]
+pub async fn search(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    options: Json<v0::DataMessageSearch>,
+) -> Result<Json<v0::BulkMessageResponse>> {
+    if user.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let options = options.into_inner();
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if options.query.is_some() && options.pinned.is_some() {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    if !query.are_we_a_member().await {
+        return Err(create_error!(NotFound));
+    }
+
+    // Work out which channels we can read in one pass
+    let channels = db.fetch_channels(&server.channels).await?;
+    let users = [user.clone()];
+    let mut readable = BulkDatabasePermissionQuery::new(db, server.clone())
+        .users(&users)
+        .channels_members_can_read(&channels)
+        .await?
+        .remove(&user.id)
+        .unwrap_or_default();
+
+    // Threads inherit permissions from their parent channel
+    let threads = db.fetch_threads(&readable, true).await?;
+    readable.extend(threads.iter().map(|thread| thread.id().to_string()));
+
+    let v0::DataMessageSearch {
+        query,
+        pinned,
+        limit,
+        before,
+        after,
+        sort,
+        include_users,
+    } = options;
+
+    let mut filter = MessageFilter {
+        channels: Some(readable),
+        pinned,
+        ..Default::default()
+    };
+
+    if let Some(query) = query {
+        filter.apply_search(db, &query).await?;
+    }
+
+    Message::fetch_with_users(
+        db,
+        MessageQuery {
+            filter,
+            time_period: MessageTimePeriod::Absolute {
+                before,
+                after,
+                sort: Some(sort),
+            },
+            limit,
+        },
+        &user,
+        include_users,
+        Some(server.id.as_str()),
+    )
+    .await
+    .map(Json)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{util::idempotency::IdempotencyKey, Member, Message, PartialChannel};
+    use revolt_models::v0;
+    use revolt_permissions::{ChannelPermission, OverrideField};
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn search_skips_hidden_channels() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&owner).await;
+        let (_, _, visible_message) = harness.new_message(&owner, &server, channels).await;
+
+        let mut hidden_channel = harness.new_channel(&server).await;
+        hidden_channel
+            .update(
+                &harness.db,
+                PartialChannel {
+                    default_permissions: Some(OverrideField {
+                        a: 0,
+                        d: ChannelPermission::ViewChannel as i64,
+                    }),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to hide channel");
+
+        Message::create_from_api(
+            &harness.db,
+            None,
+            hidden_channel.clone(),
+            v0::DataMessageSend {
+                content: Some("Secret message".to_string()),
+                nonce: None,
+                attachments: None,
+                replies: None,
+                embeds: None,
+                masquerade: None,
+                interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
+                flags: None,
+            },
+            v0::MessageAuthor::User(&owner.clone().into(&harness.db, Some(&owner)).await),
+            Some(owner.clone().into(&harness.db, Some(&owner)).await),
+            None,
+            owner.limits().await,
+            IdempotencyKey::unchecked_from_string("1".to_string()),
+            false,
+            false,
+        )
+        .await
+        .expect("Failed to create message");
+
+        let (_, session, user) = harness.new_user().await;
+        Member::create(&harness.db, &server, &user, None)
+            .await
+            .expect("Failed to create member");
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/search", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .header(ContentType::JSON)
+            .body(
+                json!(v0::DataMessageSearch {
+                    query: None,
+                    pinned: None,
+                    limit: None,
+                    before: None,
+                    after: None,
+                    sort: v0::MessageSort::Latest,
+                    include_users: None,
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let v0::BulkMessageResponse::JustMessages(messages) =
+            response.into_json().await.expect("`BulkMessageResponse`")
+        else {
+            panic!("Expected just messages");
+        };
+
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].id, visible_message.id);
+    }
+}
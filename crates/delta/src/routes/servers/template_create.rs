@@ -0,0 +1,151 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, ServerTemplate, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Template
+///
+/// Capture the server's roles, channels, categories and settings into a template.
+///
+/// The returned code can be passed to server creation to clone this structure.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/templates", data = "<data>")]
+pub async fn create_template(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    data: Json<v0::DataCreateServerTemplate>,
+) -> Result<Json<v0::ServerTemplate>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    ServerTemplate::create(db, &server, &user, data)
+        .await
+        .map(Into::into)
+        .map(Json)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::PartialServer;
+    use revolt_models::v0;
+    use rocket::http::{ContentType, Header, Status};
+
+    #[rocket::async_test]
+    async fn create_server_from_template() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (server, channels) = harness.new_server(&user).await;
+        let channel_id = channels[0].id().to_string();
+        let role = harness.new_role(&server, 1, None).await;
+
+        harness
+            .db
+            .update_server(
+                &server.id,
+                &PartialServer {
+                    description: Some("Where we hang out".to_string()),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .expect("Failed to update server");
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/categories", server.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataCreateCategory {
+                    title: "Text Channels".to_string(),
+                    channels: vec![channel_id.clone()],
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        drop(response);
+
+        let response = harness
+            .client
+            .post(format!("/servers/{}/templates", server.id))
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataCreateServerTemplate {
+                    name: "Community".to_string(),
+                    description: Some("Channels and roles for a community".to_string()),
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let template: v0::ServerTemplate = response.into_json().await.expect("`ServerTemplate`");
+        assert_eq!(template.version, 1);
+        assert_eq!(template.snapshot.roles.len(), 1);
+        assert_eq!(template.snapshot.channels.len(), 1);
+
+        let response = harness
+            .client
+            .post("/servers/create")
+            .header(ContentType::JSON)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .body(
+                json!(v0::DataCreateServer {
+                    name: "Cloned Server".to_string(),
+                    template: Some(template.code),
+                    ..Default::default()
+                })
+                .to_string(),
+            )
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let created: v0::CreateServerLegacyResponse = response
+            .into_json()
+            .await
+            .expect("`CreateServerLegacyResponse`");
+
+        // The server takes the source server's description, not the template's
+        assert_eq!(
+            created.server.description.as_deref(),
+            Some("Where we hang out")
+        );
+
+        assert_eq!(created.channels.len(), 1);
+        let cloned_channel = created.channels[0].id().to_string();
+        assert_ne!(cloned_channel, channel_id);
+
+        assert_eq!(created.server.roles.len(), 1);
+        let (cloned_role, _) = created.server.roles.iter().next().expect("`Role`");
+        assert_ne!(cloned_role, &role.id);
+
+        let categories = created.server.categories.expect("`Category`");
+        assert_eq!(categories.len(), 1);
+        assert_eq!(categories[0].title, "Text Channels");
+        assert_eq!(categories[0].channels, vec![cloned_channel]);
+    }
+}
@@ -0,0 +1,33 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Template
+///
+/// Delete a template taken from this server.
+#[openapi(tag = "Server Information")]
+#[delete("/<target>/templates/<code>")]
+pub async fn delete_template(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+    code: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let template = db.fetch_server_template(&code).await?;
+    if template.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    template.delete(db).await.map(|_| EmptyResponse)
+}
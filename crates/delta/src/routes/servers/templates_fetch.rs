@@ -0,0 +1,30 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Templates
+///
+/// Fetch all templates taken from this server.
+#[openapi(tag = "Server Information")]
+#[get("/<target>/templates")]
+pub async fn fetch_templates(
+    db: &State<Database>,
+    user: User,
+    target: Reference<'_>,
+) -> Result<Json<Vec<v0::ServerTemplate>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    db.fetch_server_templates(&server.id)
+        .await
+        .map(|v| v.into_iter().map(Into::into).collect())
+        .map(Json)
+}
@@ -52,6 +52,7 @@ pub async fn edit(
         && data.status.is_none()
         && data.profile.is_none()
         && data.avatar.is_none()
+        && data.read_receipts.is_none()
         && data.badges.is_none()
         && data.flags.is_none()
         && data.remove.is_empty()
@@ -82,6 +83,7 @@ pub async fn edit(
     let mut partial: PartialUser = PartialUser {
         display_name: data.display_name,
         pronouns: data.pronouns,
+        read_receipts: data.read_receipts,
         badges: data.badges,
         flags: data.flags,
         ..Default::default()
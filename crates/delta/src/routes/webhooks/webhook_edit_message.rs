@@ -27,6 +27,11 @@ pub async fn webhook_edit_message(
         })
     })?;
 
+    // Interactions can't be delivered to webhooks
+    if edit.components.is_some() {
+        return Err(create_error!(IsNotBot));
+    }
+
     Message::validate_sum(
         &edit.content,
         edit.embeds.as_deref().unwrap_or_default(),
@@ -73,6 +78,13 @@ pub async fn webhook_edit_message(
 
     partial.embeds = Some(new_embeds);
 
+    // 4. Update attachment descriptions and spoilers
+    if let Some(intents) = edit.attachments {
+        partial.attachments = Some(message.apply_attachment_details(intents)?);
+    }
+
+    // Keep a copy of the message as it was before this edit
+    message.push_revision(db, &partial).await?;
     message.update(db, partial, vec![]).await?;
 
     // Queue up a task for processing embeds
@@ -0,0 +1,70 @@
+use std::collections::HashMap;
+
+use revolt_database::{util::permissions::DatabasePermissionQuery, Category, Server, User};
+use revolt_permissions::{ChannelPermission, PermissionValue};
+use revolt_result::{create_error, Result};
+
+/// Find which category each channel is in
+fn categories_by_channel(categories: &[Category]) -> HashMap<&str, &Category> {
+    categories
+        .iter()
+        .flat_map(|category| {
+            category
+                .channels
+                .iter()
+                .map(move |channel| (channel.as_str(), category))
+        })
+        .collect()
+}
+
+/// Check that channels can be moved between categories
+///
+/// Moving a channel changes which category overrides apply to it, so this
+/// requires the same permission as editing the overrides of both the old
+/// and new category, and that none of their overridden roles rank above us.
+pub fn throw_if_cannot_move_channels(
+    query: &DatabasePermissionQuery,
+    permissions: &PermissionValue,
+    user: &User,
+    server: &Server,
+    before: &[Category],
+    after: &[Category],
+) -> Result<()> {
+    let before = categories_by_channel(before);
+    let after = categories_by_channel(after);
+
+    let mut affected: HashMap<&str, &Category> = HashMap::new();
+    for channel in &server.channels {
+        let old = before.get(channel.as_str());
+        let new = after.get(channel.as_str());
+        if old.map(|category| &category.id) != new.map(|category| &category.id) {
+            for category in old.into_iter().chain(new) {
+                affected.insert(&category.id, category);
+            }
+        }
+    }
+
+    if affected.is_empty() {
+        return Ok(());
+    }
+
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ManagePermissions)?;
+
+    if server.owner == user.id {
+        return Ok(());
+    }
+
+    let our_rank = query.get_member_rank().unwrap_or(i64::MIN);
+    for category in affected.values() {
+        if category
+            .role_permissions
+            .keys()
+            .filter_map(|role_id| server.roles.get(role_id))
+            .any(|role| role.rank <= our_rank)
+        {
+            return Err(create_error!(NotElevated));
+        }
+    }
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 pub mod audit_log_reason;
+pub mod categories;
 pub mod ratelimits;
 
 #[cfg(test)]
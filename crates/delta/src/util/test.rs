@@ -178,6 +178,9 @@ impl TestHarness {
                 embeds: None,
                 masquerade: None,
                 interactions: None,
+                components: None,
+                poll: None,
+                expires_in: None,
                 flags: None,
             },
             v0::MessageAuthor::User(&user.clone().into(&self.db, Some(user)).await),
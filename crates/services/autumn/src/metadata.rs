@@ -3,7 +3,7 @@ use std::io::Cursor;
 use crate::utils::apply_icc_profile;
 use image::{GenericImageView, ImageError, ImageReader};
 use revolt_database::Metadata;
-use revolt_files::{image_size, is_animated, video_size};
+use revolt_files::{audio_duration, audio_waveform, image_size, is_animated, video_size};
 use tempfile::NamedTempFile;
 
 /// Intersection of what infer can detect and what image-rs supports
@@ -59,7 +59,10 @@ pub fn generate_metadata(f: &NamedTempFile, mime_type: &str) -> Metadata {
             })
             .unwrap_or_default()
     } else if mime_type.starts_with("audio/") {
-        Metadata::Audio
+        Metadata::Audio {
+            duration: audio_duration(f),
+            waveform: audio_waveform(f),
+        }
     } else if mime_type == "plain/text" {
         Metadata::Text
     } else {
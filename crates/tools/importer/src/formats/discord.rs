@@ -0,0 +1,205 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::{
+    parse_timestamp, resolve, ImportedAttachment, ImportedAuthor, ImportedChannel, ImportedMessage,
+    ImportedReaction,
+};
+
+#[derive(Deserialize)]
+struct Export {
+    channel: Channel,
+    messages: Vec<Message>,
+}
+
+#[derive(Deserialize)]
+struct Channel {
+    name: String,
+    topic: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Message {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    timestamp: String,
+    timestamp_edited: Option<String>,
+    content: String,
+    author: Author,
+    #[serde(default)]
+    attachments: Vec<Attachment>,
+    #[serde(default)]
+    reactions: Vec<Reaction>,
+    reference: Option<Reference>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Author {
+    id: String,
+    name: String,
+    nickname: Option<String>,
+    avatar_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Attachment {
+    url: String,
+    file_name: String,
+}
+
+#[derive(Deserialize)]
+struct Reaction {
+    emoji: Emoji,
+    #[serde(default)]
+    users: Vec<Author>,
+}
+
+#[derive(Deserialize)]
+struct Emoji {
+    id: Option<String>,
+    name: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Reference {
+    message_id: Option<String>,
+}
+
+/// Read a DiscordChatExporter JSON export, or a directory of them
+pub fn read(path: &Path) -> Result<Vec<ImportedChannel>> {
+    if !path.is_dir() {
+        return Ok(vec![read_channel(path)?]);
+    }
+
+    let mut files = fs::read_dir(path)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    files.retain(|file| file.extension().is_some_and(|ext| ext == "json"));
+    files.sort();
+    files.iter().map(|file| read_channel(file)).collect()
+}
+
+fn read_channel(path: &Path) -> Result<ImportedChannel> {
+    let export: Export = serde_json::from_slice(&fs::read(path)?)
+        .with_context(|| format!("invalid export {}", path.display()))?;
+
+    let base = path.parent().unwrap_or(Path::new("."));
+
+    let messages = export
+        .messages
+        .into_iter()
+        // Only user messages, system messages have no equivalent
+        .filter(|message| matches!(message.kind.as_str(), "Default" | "Reply"))
+        .filter_map(|message| {
+            let sent_at = parse_timestamp(&message.timestamp)?;
+
+            Some(ImportedMessage {
+                id: message.id,
+                author: ImportedAuthor {
+                    id: message.author.id,
+                    name: message.author.nickname.unwrap_or(message.author.name),
+                    avatar: message
+                        .author
+                        .avatar_url
+                        .map(|avatar| resolve(base, avatar)),
+                },
+                sent_at,
+                edited_at: message
+                    .timestamp_edited
+                    .as_deref()
+                    .and_then(parse_timestamp),
+                content: message.content,
+                attachments: message
+                    .attachments
+                    .into_iter()
+                    .map(|attachment| ImportedAttachment {
+                        filename: attachment.file_name,
+                        source: resolve(base, attachment.url),
+                    })
+                    .collect(),
+                reactions: message
+                    .reactions
+                    .into_iter()
+                    // Custom emoji belong to the source server
+                    .filter(|reaction| reaction.emoji.id.as_deref().unwrap_or_default().is_empty())
+                    .map(|reaction| ImportedReaction {
+                        emoji: reaction.emoji.name,
+                        users: reaction.users.into_iter().map(|user| user.id).collect(),
+                    })
+                    .collect(),
+                reply_to: message.reference.and_then(|reference| reference.message_id),
+            })
+        })
+        .collect();
+
+    Ok(ImportedChannel {
+        name: export.channel.name,
+        description: export.channel.topic.filter(|topic| !topic.is_empty()),
+        messages,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::formats::{from_unix_millis, Format};
+
+    #[test]
+    fn read_export() {
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/discord");
+        let channels = Format::Discord
+            .read(&base.join("general.json"))
+            .expect("Failed to read export");
+
+        assert_eq!(channels.len(), 1);
+        let channel = &channels[0];
+        assert_eq!(channel.name, "general");
+        assert_eq!(channel.description.as_deref(), Some("General chat"));
+
+        // System messages are skipped
+        assert_eq!(channel.messages.len(), 2);
+
+        let message = &channel.messages[0];
+        assert_eq!(message.id, "300000000000000001");
+        assert_eq!(message.author.id, "400000000000000001");
+        assert_eq!(message.author.name, "Alice");
+        assert_eq!(
+            message.author.avatar.as_deref(),
+            base.join("general_files/avatar-alice.png").to_str()
+        );
+        assert_eq!(message.sent_at, from_unix_millis(1704110400000));
+        assert_eq!(message.edited_at, Some(from_unix_millis(1704110700000)));
+        assert_eq!(message.content, "Hello **world**");
+
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].filename, "cat.png");
+        assert_eq!(
+            Some(message.attachments[0].source.as_str()),
+            base.join("general_files/cat.png").to_str()
+        );
+
+        // Custom emoji are dropped
+        assert_eq!(message.reactions.len(), 1);
+        assert_eq!(message.reactions[0].emoji, "👍");
+        assert_eq!(message.reactions[0].users, vec!["400000000000000002"]);
+        assert_eq!(message.reply_to, None);
+
+        let reply = &channel.messages[1];
+        assert_eq!(reply.author.name, "bob");
+        assert_eq!(
+            reply.author.avatar.as_deref(),
+            Some("https://cdn.discordapp.com/avatars/bob.png")
+        );
+        assert_eq!(reply.edited_at, None);
+        assert_eq!(reply.reply_to.as_deref(), Some("300000000000000001"));
+    }
+}
@@ -0,0 +1,131 @@
+use std::{path::Path, str::FromStr, time::SystemTime};
+
+use anyhow::{bail, Result};
+
+mod discord;
+mod slack;
+mod telegram;
+
+pub use slack::is_private_file as is_slack_private_file;
+
+/// Channel read from an export
+#[derive(Debug, Default)]
+pub struct ImportedChannel {
+    /// Channel name
+    pub name: String,
+    /// Channel description
+    pub description: Option<String>,
+    /// Messages in this channel, in any order
+    pub messages: Vec<ImportedMessage>,
+}
+
+/// Author of an imported message
+#[derive(Debug, Clone, Default)]
+pub struct ImportedAuthor {
+    /// Id of the author on the source platform
+    pub id: String,
+    /// Display name of the author
+    pub name: String,
+    /// Avatar URL or path to an avatar within the export
+    pub avatar: Option<String>,
+}
+
+/// Message read from an export
+#[derive(Debug)]
+pub struct ImportedMessage {
+    /// Id of the message on the source platform
+    pub id: String,
+    /// Author of the message
+    pub author: ImportedAuthor,
+    /// When the message was sent
+    pub sent_at: SystemTime,
+    /// When the message was last edited
+    pub edited_at: Option<SystemTime>,
+    /// Message content, as markdown
+    pub content: String,
+    /// Files attached to the message
+    pub attachments: Vec<ImportedAttachment>,
+    /// Reactions on the message
+    pub reactions: Vec<ImportedReaction>,
+    /// Id of the message this message replies to on the source platform
+    pub reply_to: Option<String>,
+}
+
+/// File attached to an imported message
+#[derive(Debug)]
+pub struct ImportedAttachment {
+    /// Original file name
+    pub filename: String,
+    /// URL or path to the file within the export
+    pub source: String,
+}
+
+/// Reaction on an imported message
+#[derive(Debug)]
+pub struct ImportedReaction {
+    /// Unicode emoji
+    pub emoji: String,
+    /// Ids of the users who reacted on the source platform, if known
+    pub users: Vec<String>,
+}
+
+/// Supported export formats
+#[derive(Debug, Clone, Copy)]
+pub enum Format {
+    /// JSON export produced by DiscordChatExporter
+    Discord,
+    /// Slack workspace export, extracted to a directory
+    Slack,
+    /// Telegram Desktop JSON export
+    Telegram,
+}
+
+impl FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "discord" => Format::Discord,
+            "slack" => Format::Slack,
+            "telegram" => Format::Telegram,
+            _ => bail!("unknown export format `{s}`"),
+        })
+    }
+}
+
+impl Format {
+    /// Read all channels from an export
+    pub fn read(self, path: &Path) -> Result<Vec<ImportedChannel>> {
+        match self {
+            Format::Discord => discord::read(path),
+            Format::Slack => slack::read(path),
+            Format::Telegram => telegram::read(path),
+        }
+    }
+}
+
+/// Convert a timestamp to the time it represents
+pub fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let timestamp = iso8601_timestamp::Timestamp::parse(timestamp)?;
+    let millis = timestamp
+        .duration_since(iso8601_timestamp::Timestamp::UNIX_EPOCH)
+        .whole_milliseconds();
+
+    Some(from_unix_millis(millis.try_into().ok()?))
+}
+
+/// Convert milliseconds since the Unix epoch to the time they represent
+pub fn from_unix_millis(millis: u64) -> SystemTime {
+    SystemTime::UNIX_EPOCH + std::time::Duration::from_millis(millis)
+}
+
+/// Resolve a file reference from an export to a URL or an absolute path
+///
+/// Exports reference downloaded media relative to the export itself
+pub fn resolve(base: &Path, source: String) -> String {
+    if source.starts_with("http://") || source.starts_with("https://") {
+        source
+    } else {
+        base.join(source).to_string_lossy().into_owned()
+    }
+}
@@ -0,0 +1,358 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{de::DeserializeOwned, Deserialize};
+
+use super::{
+    from_unix_millis, ImportedAttachment, ImportedAuthor, ImportedChannel, ImportedMessage,
+    ImportedReaction,
+};
+
+#[derive(Deserialize)]
+struct User {
+    id: String,
+    name: String,
+    #[serde(default)]
+    profile: Profile,
+}
+
+#[derive(Deserialize, Default)]
+struct Profile {
+    display_name: Option<String>,
+    real_name: Option<String>,
+    image_72: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Channel {
+    name: String,
+    purpose: Option<Purpose>,
+}
+
+#[derive(Deserialize)]
+struct Purpose {
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    subtype: Option<String>,
+    user: Option<String>,
+    bot_id: Option<String>,
+    username: Option<String>,
+    #[serde(default)]
+    text: String,
+    ts: String,
+    thread_ts: Option<String>,
+    edited: Option<Edited>,
+    #[serde(default)]
+    files: Vec<SlackFile>,
+    #[serde(default)]
+    reactions: Vec<Reaction>,
+}
+
+#[derive(Deserialize)]
+struct Edited {
+    ts: String,
+}
+
+#[derive(Deserialize)]
+struct SlackFile {
+    name: Option<String>,
+    url_private_download: Option<String>,
+    url_private: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Reaction {
+    name: String,
+    #[serde(default)]
+    users: Vec<String>,
+}
+
+/// Shortcodes of common emoji, Slack stores reactions by name only
+const EMOJI: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("thumbsup", "👍"),
+    ("-1", "👎"),
+    ("thumbsdown", "👎"),
+    ("heart", "❤️"),
+    ("joy", "😂"),
+    ("smile", "😄"),
+    ("slightly_smiling_face", "🙂"),
+    ("laughing", "😆"),
+    ("wink", "😉"),
+    ("thinking_face", "🤔"),
+    ("eyes", "👀"),
+    ("tada", "🎉"),
+    ("fire", "🔥"),
+    ("rocket", "🚀"),
+    ("clap", "👏"),
+    ("pray", "🙏"),
+    ("raised_hands", "🙌"),
+    ("ok_hand", "👌"),
+    ("wave", "👋"),
+    ("muscle", "💪"),
+    ("100", "💯"),
+    ("white_check_mark", "✅"),
+    ("heavy_check_mark", "✔️"),
+    ("x", "❌"),
+    ("warning", "⚠️"),
+    ("sob", "😭"),
+    ("cry", "😢"),
+    ("scream", "😱"),
+    ("sweat_smile", "😅"),
+    ("rolling_on_the_floor_laughing", "🤣"),
+    ("star", "⭐"),
+    ("sparkles", "✨"),
+    ("point_up", "☝️"),
+];
+
+/// Read a Slack workspace export that has been extracted to a directory
+pub fn read(path: &Path) -> Result<Vec<ImportedChannel>> {
+    let users: Vec<User> = read_json(&path.join("users.json"))?;
+    let users: HashMap<String, ImportedAuthor> = users
+        .into_iter()
+        .map(|user| {
+            let name = [user.profile.display_name, user.profile.real_name]
+                .into_iter()
+                .flatten()
+                .find(|name| !name.is_empty())
+                .unwrap_or(user.name);
+
+            (
+                user.id.clone(),
+                ImportedAuthor {
+                    id: user.id,
+                    name,
+                    avatar: user.profile.image_72,
+                },
+            )
+        })
+        .collect();
+
+    let channels: Vec<Channel> = read_json(&path.join("channels.json"))?;
+    channels
+        .into_iter()
+        .map(|channel| read_channel(path, channel, &users))
+        .collect()
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T> {
+    serde_json::from_slice(&fs::read(path)?)
+        .with_context(|| format!("invalid export file {}", path.display()))
+}
+
+fn read_channel(
+    path: &Path,
+    channel: Channel,
+    users: &HashMap<String, ImportedAuthor>,
+) -> Result<ImportedChannel> {
+    // Messages are split into one file per day
+    let mut days = fs::read_dir(path.join(&channel.name))?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    days.retain(|day| day.extension().is_some_and(|ext| ext == "json"));
+    days.sort();
+
+    let mut messages = vec![];
+    for day in days {
+        let day: Vec<Message> = read_json(&day)?;
+        messages.extend(
+            day.into_iter()
+                .filter_map(|message| convert(message, users)),
+        );
+    }
+
+    Ok(ImportedChannel {
+        name: channel.name,
+        description: channel
+            .purpose
+            .map(|purpose| purpose.value)
+            .filter(|purpose| !purpose.is_empty()),
+        messages,
+    })
+}
+
+fn convert(message: Message, users: &HashMap<String, ImportedAuthor>) -> Option<ImportedMessage> {
+    // Joins, leaves and topic changes have no equivalent
+    if !matches!(
+        message.subtype.as_deref(),
+        None | Some("bot_message" | "thread_broadcast" | "file_share" | "me_message")
+    ) {
+        return None;
+    }
+
+    let author = match (&message.user, &message.bot_id) {
+        (Some(user), _) => users.get(user).cloned().unwrap_or_else(|| ImportedAuthor {
+            id: user.clone(),
+            name: user.clone(),
+            avatar: None,
+        }),
+        (None, Some(bot)) => ImportedAuthor {
+            id: bot.clone(),
+            name: message.username.clone().unwrap_or_else(|| bot.clone()),
+            avatar: None,
+        },
+        (None, None) => return None,
+    };
+
+    Some(ImportedMessage {
+        sent_at: from_unix_millis(parse_ts(&message.ts)?),
+        edited_at: message
+            .edited
+            .and_then(|edited| parse_ts(&edited.ts))
+            .map(from_unix_millis),
+        content: convert_text(&message.text, users),
+        attachments: message
+            .files
+            .into_iter()
+            .filter_map(|file| {
+                Some(ImportedAttachment {
+                    source: file.url_private_download.or(file.url_private)?,
+                    filename: file.name.unwrap_or_else(|| "file".to_owned()),
+                })
+            })
+            .collect(),
+        reactions: message
+            .reactions
+            .into_iter()
+            .filter_map(|reaction| {
+                let name = reaction.name.split("::").next().unwrap_or_default();
+                let (_, emoji) = EMOJI.iter().find(|(code, _)| *code == name)?;
+
+                Some(ImportedReaction {
+                    emoji: emoji.to_string(),
+                    users: reaction.users,
+                })
+            })
+            .collect(),
+        reply_to: message.thread_ts.filter(|thread| thread != &message.ts),
+        id: message.ts,
+        author,
+    })
+}
+
+/// Whether a file URL can only be downloaded with a Slack token
+pub fn is_private_file(source: &str) -> bool {
+    source.starts_with("https://files.slack.com/")
+}
+
+/// Convert a Slack timestamp (seconds with a fractional part) to milliseconds
+fn parse_ts(ts: &str) -> Option<u64> {
+    let (seconds, fraction) = ts.split_once('.').unwrap_or((ts, ""));
+    let millis = format!("{:0<3}", &fraction[..fraction.len().min(3)]);
+    Some(seconds.parse::<u64>().ok()? * 1000 + millis.parse::<u64>().ok()?)
+}
+
+/// Convert Slack's markup to markdown
+fn convert_text(text: &str, users: &HashMap<String, ImportedAuthor>) -> String {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+
+        output.push_str(&rest[..start]);
+        let inner = &rest[start + 1..start + end];
+        let (target, label) = inner.split_once('|').unwrap_or((inner, ""));
+
+        if let Some(user) = target.strip_prefix('@') {
+            output.push('@');
+            output.push_str(
+                users
+                    .get(user)
+                    .map(|user| user.name.as_str())
+                    .unwrap_or(user),
+            );
+        } else if target.starts_with('#') {
+            output.push('#');
+            output.push_str(if label.is_empty() {
+                &target[1..]
+            } else {
+                label
+            });
+        } else if let Some(special) = target.strip_prefix('!') {
+            output.push('@');
+            output.push_str(special);
+        } else if label.is_empty() {
+            output.push_str(target);
+        } else {
+            output.push_str(&format!("[{label}]({target})"));
+        }
+
+        rest = &rest[start + end + 1..];
+    }
+
+    output.push_str(rest);
+    output
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::formats::{from_unix_millis, Format};
+
+    #[test]
+    fn read_export() {
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/slack");
+        let channels = Format::Slack.read(&base).expect("Failed to read export");
+
+        assert_eq!(channels.len(), 1);
+        let channel = &channels[0];
+        assert_eq!(channel.name, "general");
+        assert_eq!(
+            channel.description.as_deref(),
+            Some("Company wide announcements")
+        );
+
+        // Channel joins are skipped
+        assert_eq!(channel.messages.len(), 3);
+
+        let message = &channel.messages[0];
+        assert_eq!(message.id, "1704110460.123456");
+        assert_eq!(message.author.id, "U01");
+        assert_eq!(message.author.name, "Alice");
+        assert_eq!(
+            message.author.avatar.as_deref(),
+            Some("https://avatars.slack-edge.com/alice_72.png")
+        );
+        assert_eq!(message.sent_at, from_unix_millis(1704110460123));
+        assert_eq!(message.edited_at, Some(from_unix_millis(1704110520000)));
+        assert_eq!(
+            message.content,
+            "Welcome @Bob Example! See #general and [the docs](https://example.com) & more"
+        );
+
+        // Thread parents do not reply to themselves
+        assert_eq!(message.reply_to, None);
+
+        // Skin tones are ignored and unknown emoji are dropped
+        assert_eq!(message.reactions.len(), 1);
+        assert_eq!(message.reactions[0].emoji, "👍");
+        assert_eq!(message.reactions[0].users, vec!["U02"]);
+
+        let reply = &channel.messages[1];
+        assert_eq!(reply.author.name, "Bob Example");
+        assert_eq!(reply.content, "Thanks @here");
+        assert_eq!(reply.reply_to.as_deref(), Some("1704110460.123456"));
+        assert_eq!(reply.attachments.len(), 1);
+        assert_eq!(reply.attachments[0].filename, "notes.txt");
+        assert_eq!(
+            reply.attachments[0].source,
+            "https://files.slack.com/files-pri/T01-F01/download/notes.txt"
+        );
+
+        let bot = &channel.messages[2];
+        assert_eq!(bot.author.id, "B01");
+        assert_eq!(bot.author.name, "deploybot");
+        assert_eq!(bot.content, "Deployed");
+    }
+}
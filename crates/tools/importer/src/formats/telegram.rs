@@ -0,0 +1,235 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use super::{
+    from_unix_millis, resolve, ImportedAttachment, ImportedAuthor, ImportedChannel,
+    ImportedMessage, ImportedReaction,
+};
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Export {
+    Account { chats: ChatList },
+    Chat(Chat),
+}
+
+#[derive(Deserialize)]
+struct ChatList {
+    list: Vec<Chat>,
+}
+
+#[derive(Deserialize)]
+struct Chat {
+    name: Option<String>,
+    id: i64,
+    #[serde(default)]
+    messages: Vec<Message>,
+}
+
+#[derive(Deserialize)]
+struct Message {
+    id: i64,
+    #[serde(rename = "type")]
+    kind: String,
+    date_unixtime: String,
+    edited_unixtime: Option<String>,
+    from: Option<String>,
+    from_id: Option<String>,
+    reply_to_message_id: Option<i64>,
+    #[serde(default)]
+    text: Text,
+    photo: Option<String>,
+    file: Option<String>,
+    file_name: Option<String>,
+    #[serde(default)]
+    reactions: Vec<Reaction>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Text {
+    Plain(String),
+    Rich(Vec<TextPart>),
+}
+
+impl Default for Text {
+    fn default() -> Self {
+        Text::Plain(String::new())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TextPart {
+    Plain(String),
+    Entity { text: String },
+}
+
+#[derive(Deserialize)]
+struct Reaction {
+    #[serde(rename = "type")]
+    kind: String,
+    emoji: Option<String>,
+    #[serde(default)]
+    recent: Vec<Reactor>,
+}
+
+#[derive(Deserialize)]
+struct Reactor {
+    from_id: Option<String>,
+}
+
+/// Read a Telegram Desktop export of a single chat or a whole account
+pub fn read(path: &Path) -> Result<Vec<ImportedChannel>> {
+    // Exports are a directory containing result.json alongside any media
+    let (base, file) = if path.is_dir() {
+        (path, path.join("result.json"))
+    } else {
+        (path.parent().unwrap_or(Path::new(".")), path.to_owned())
+    };
+
+    let export: Export = serde_json::from_slice(&fs::read(&file)?)
+        .with_context(|| format!("invalid export {}", file.display()))?;
+
+    let chats = match export {
+        Export::Account { chats } => chats.list,
+        Export::Chat(chat) => vec![chat],
+    };
+
+    Ok(chats
+        .into_iter()
+        .map(|chat| ImportedChannel {
+            name: chat.name.unwrap_or_else(|| chat.id.to_string()),
+            description: None,
+            messages: chat
+                .messages
+                .into_iter()
+                .filter_map(|message| convert(base, message))
+                .collect(),
+        })
+        .collect())
+}
+
+fn convert(base: &Path, message: Message) -> Option<ImportedMessage> {
+    // Service messages (joins, pins, calls) have no equivalent
+    if message.kind != "message" {
+        return None;
+    }
+
+    let from_id = message.from_id?;
+    let content = match message.text {
+        Text::Plain(text) => text,
+        Text::Rich(parts) => parts
+            .into_iter()
+            .map(|part| match part {
+                TextPart::Plain(text) | TextPart::Entity { text } => text,
+            })
+            .collect(),
+    };
+
+    // Media which was not downloaded is replaced with a placeholder in parentheses
+    let attachments = [
+        message.photo.map(|photo| {
+            let filename = Path::new(&photo)
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "photo.jpg".to_owned());
+
+            (filename, photo)
+        }),
+        message.file.map(|file| {
+            let filename = message.file_name.unwrap_or_else(|| "file".to_owned());
+            (filename, file)
+        }),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|(_, source)| !source.starts_with('('))
+    .map(|(filename, source)| ImportedAttachment {
+        filename,
+        source: resolve(base, source),
+    })
+    .collect();
+
+    Some(ImportedMessage {
+        id: message.id.to_string(),
+        author: ImportedAuthor {
+            name: message.from.unwrap_or_else(|| from_id.clone()),
+            id: from_id,
+            avatar: None,
+        },
+        sent_at: from_unix_millis(message.date_unixtime.parse::<u64>().ok()? * 1000),
+        edited_at: message
+            .edited_unixtime
+            .and_then(|time| time.parse::<u64>().ok())
+            .map(|time| from_unix_millis(time * 1000)),
+        content,
+        attachments,
+        reactions: message
+            .reactions
+            .into_iter()
+            // Custom emoji belong to the source platform
+            .filter(|reaction| reaction.kind == "emoji")
+            .filter_map(|reaction| {
+                Some(ImportedReaction {
+                    emoji: reaction.emoji?,
+                    users: reaction
+                        .recent
+                        .into_iter()
+                        .filter_map(|reactor| reactor.from_id)
+                        .collect(),
+                })
+            })
+            .collect(),
+        reply_to: message.reply_to_message_id.map(|id| id.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::formats::{from_unix_millis, Format};
+
+    #[test]
+    fn read_export() {
+        let base = Path::new(env!("CARGO_MANIFEST_DIR")).join("fixtures/telegram");
+        let channels = Format::Telegram.read(&base).expect("Failed to read export");
+
+        assert_eq!(channels.len(), 1);
+        let channel = &channels[0];
+        assert_eq!(channel.name, "Book Club");
+
+        // Service messages are skipped
+        assert_eq!(channel.messages.len(), 2);
+
+        let message = &channel.messages[0];
+        assert_eq!(message.id, "2");
+        assert_eq!(message.author.id, "user1");
+        assert_eq!(message.author.name, "Alice");
+        assert_eq!(message.sent_at, from_unix_millis(1704110460000));
+        assert_eq!(message.edited_at, Some(from_unix_millis(1704110520000)));
+        assert_eq!(message.content, "Read Dune next?");
+
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].filename, "photo_1.jpg");
+        assert_eq!(
+            Some(message.attachments[0].source.as_str()),
+            base.join("photos/photo_1.jpg").to_str()
+        );
+
+        // Custom emoji are dropped
+        assert_eq!(message.reactions.len(), 1);
+        assert_eq!(message.reactions[0].emoji, "👍");
+        assert_eq!(message.reactions[0].users, vec!["user2"]);
+
+        let reply = &channel.messages[1];
+        assert_eq!(reply.content, "Sounds good");
+        assert_eq!(reply.reply_to.as_deref(), Some("2"));
+
+        // Media which was not downloaded is skipped
+        assert!(reply.attachments.is_empty());
+    }
+}
@@ -0,0 +1,383 @@
+use std::{collections::HashMap, path::Path};
+
+use indexmap::{IndexMap, IndexSet};
+use iso8601_timestamp::Timestamp;
+use log::warn;
+use revolt_config::config;
+use revolt_database::{
+    search, Channel, Database, File, FileHash, FileUsedFor, FileUsedForType, Masquerade, Message,
+    Metadata, Server, Webhook,
+};
+use revolt_files::{image_size_vec, upload_to_s3};
+use revolt_models::v0;
+use revolt_permissions::DEFAULT_WEBHOOK_PERMISSIONS;
+use revolt_result::{create_error, Result};
+use sha2::Digest;
+use ulid::Ulid;
+
+use crate::formats::{
+    is_slack_private_file, ImportedAttachment, ImportedAuthor, ImportedChannel, ImportedMessage,
+};
+
+/// Recreates exported channels within a server
+pub struct Importer<'a> {
+    db: &'a Database,
+    server: Server,
+    /// Source user ids mapped to the accounts they belong to
+    users: HashMap<String, String>,
+    http: reqwest::Client,
+    /// Token used to download private files from Slack
+    slack_token: Option<String>,
+    /// Masquerade avatars already resolved, by source author id
+    avatars: HashMap<String, Option<String>>,
+}
+
+impl<'a> Importer<'a> {
+    /// Prepare to import into a server
+    pub async fn new(
+        db: &'a Database,
+        server_id: &str,
+        users: HashMap<String, String>,
+        slack_token: Option<String>,
+    ) -> Result<Importer<'a>> {
+        Ok(Importer {
+            db,
+            server: db.fetch_server(server_id).await?,
+            users,
+            http: reqwest::Client::new(),
+            slack_token,
+            avatars: HashMap::new(),
+        })
+    }
+
+    /// Create a channel from an export, returning the number of messages imported
+    ///
+    /// If anything fails part way through, the channel is deleted again
+    /// so that the import can be retried without leaving duplicates behind.
+    pub async fn import_channel(&mut self, channel: ImportedChannel) -> Result<usize> {
+        let target = Channel::create_server_channel(
+            self.db,
+            &mut self.server,
+            v0::DataCreateServerChannel {
+                name: channel.name.chars().take(32).collect(),
+                description: channel
+                    .description
+                    .map(|description| description.chars().take(1024).collect()),
+                ..Default::default()
+            },
+            true,
+        )
+        .await?;
+
+        match self.import_messages(&target, channel.messages).await {
+            Ok(count) => Ok(count),
+            Err(error) => {
+                if let Err(rollback) = target.delete(self.db).await {
+                    warn!("Failed to remove partially imported channel: {rollback:?}");
+                }
+
+                self.server.channels.retain(|id| id != target.id());
+                Err(error)
+            }
+        }
+    }
+
+    /// Insert every message of an export into a newly created channel
+    async fn import_messages(
+        &mut self,
+        target: &Channel,
+        mut messages: Vec<ImportedMessage>,
+    ) -> Result<usize> {
+        // Authors without accounts post through a webhook, masquerading as themselves
+        let webhook = Webhook {
+            id: Ulid::new().to_string(),
+            name: "Imported Messages".to_owned(),
+            avatar: None,
+            creator_id: self.server.owner.clone(),
+            channel_id: target.id().to_owned(),
+            permissions: *DEFAULT_WEBHOOK_PERMISSIONS,
+            token: None,
+            source_channel_id: None,
+        };
+
+        webhook.create(self.db).await?;
+
+        messages.sort_by_key(|message| message.sent_at);
+
+        let mut ids: HashMap<String, String> = HashMap::new();
+        for message in messages {
+            let source_id = message.id.clone();
+            let message = self.import_message(&webhook, message, &ids).await?;
+            ids.insert(source_id, message.id);
+        }
+
+        if let Some(last) = ids.values().max() {
+            self.db
+                .update_last_messsage_id(target.id(), Some(last.as_str()))
+                .await?;
+        }
+
+        Ok(ids.len())
+    }
+
+    /// Insert a single message, with its original timestamp encoded in its id
+    async fn import_message(
+        &mut self,
+        webhook: &Webhook,
+        imported: ImportedMessage,
+        ids: &HashMap<String, String>,
+    ) -> Result<Message> {
+        let id = Ulid::from_datetime(imported.sent_at).to_string();
+
+        let mut message = Message {
+            id: id.clone(),
+            channel: webhook.channel_id.clone(),
+            content: Some(imported.content).filter(|content| !content.is_empty()),
+            edited: imported.edited_at.map(Timestamp::from),
+            replies: imported
+                .reply_to
+                .and_then(|reply| ids.get(&reply).cloned())
+                .map(|reply| vec![reply]),
+            ..Default::default()
+        };
+
+        if let Some(user) = self.users.get(&imported.author.id) {
+            message.author = user.clone();
+        } else {
+            message.author = webhook.id.clone();
+            message.webhook = Some(std::convert::Into::<v0::Webhook>::into(webhook.clone()).into());
+            message.masquerade = Some(Masquerade {
+                name: Some(imported.author.name.chars().take(32).collect()),
+                avatar: self.avatar(webhook, &imported.author).await,
+                colour: None,
+            });
+        }
+
+        let mut attachments = vec![];
+        for attachment in &imported.attachments {
+            match self.upload_attachment(&id, attachment).await {
+                Ok(file) => attachments.push(file),
+                Err(error) => warn!(
+                    "Skipping attachment {} on message {}: {error:?}",
+                    attachment.source, imported.id
+                ),
+            }
+        }
+
+        if !attachments.is_empty() {
+            message.attachments = Some(attachments);
+        }
+
+        message.reactions = imported
+            .reactions
+            .into_iter()
+            .map(|reaction| {
+                let mut users: IndexSet<String> = reaction
+                    .users
+                    .iter()
+                    .filter_map(|user| self.users.get(user).cloned())
+                    .collect();
+
+                // Reactions from users without accounts are kept on behalf of the webhook
+                if users.len() < reaction.users.len() || users.is_empty() {
+                    users.insert(webhook.id.clone());
+                }
+
+                (reaction.emoji, users)
+            })
+            .collect::<IndexMap<_, _>>();
+
+        self.db.insert_message(&message).await?;
+        search::announce_message(&message).await;
+
+        Ok(message)
+    }
+
+    /// Resolve the masquerade avatar for an author, uploading it if it is part of the export
+    async fn avatar(&mut self, webhook: &Webhook, author: &ImportedAuthor) -> Option<String> {
+        if let Some(avatar) = self.avatars.get(&author.id) {
+            return avatar.clone();
+        }
+
+        let avatar = match &author.avatar {
+            Some(source) if source.starts_with("http") => Some(source.clone()),
+            Some(source) => match self.upload_avatar(webhook, source).await {
+                Ok(avatar) => Some(avatar),
+                Err(error) => {
+                    warn!("Skipping avatar for {}: {error:?}", author.name);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        self.avatars.insert(author.id.clone(), avatar.clone());
+        avatar
+    }
+
+    /// Upload an avatar from the export, returning its URL
+    async fn upload_avatar(&self, webhook: &Webhook, source: &str) -> Result<String> {
+        let filename = Path::new(source)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "avatar".to_owned());
+
+        let file = self
+            .upload(
+                filename,
+                self.load(source).await?,
+                FileUsedFor {
+                    object_type: FileUsedForType::WebhookAvatar,
+                    id: webhook.id.clone(),
+                },
+            )
+            .await?;
+
+        Ok(format!(
+            "{}/attachments/{}",
+            config().await.hosts.autumn,
+            file.id
+        ))
+    }
+
+    /// Upload an attachment belonging to a message
+    async fn upload_attachment(
+        &self,
+        message_id: &str,
+        attachment: &ImportedAttachment,
+    ) -> Result<File> {
+        let buf = self.load(&attachment.source).await?;
+        self.upload(
+            attachment.filename.clone(),
+            buf,
+            FileUsedFor {
+                object_type: FileUsedForType::Message,
+                id: message_id.to_owned(),
+            },
+        )
+        .await
+    }
+
+    /// Read a file from the export or download it
+    async fn load(&self, source: &str) -> Result<Vec<u8>> {
+        if source.starts_with("http://") || source.starts_with("https://") {
+            let mut request = self.http.get(source);
+            if is_slack_private_file(source) {
+                let Some(token) = &self.slack_token else {
+                    return Err(create_error!(InvalidOperation));
+                };
+
+                request = request.bearer_auth(token);
+            }
+
+            let response = request
+                .send()
+                .await
+                .and_then(|response| response.error_for_status())
+                .map_err(|_| create_error!(ProxyError))?;
+
+            response
+                .bytes()
+                .await
+                .map(|bytes| bytes.to_vec())
+                .map_err(|_| create_error!(ProxyError))
+        } else {
+            tokio::fs::read(source)
+                .await
+                .map_err(|_| create_error!(NotFound))
+        }
+    }
+
+    /// Store a file through file storage
+    async fn upload(&self, filename: String, buf: Vec<u8>, used_for: FileUsedFor) -> Result<File> {
+        let config = config().await;
+        let hash = format!("{:02x}", sha2::Sha256::digest(&buf));
+
+        let file_hash = match self.db.fetch_attachment_hash(&hash).await {
+            Ok(file_hash) if !file_hash.iv.is_empty() => file_hash,
+            existing => {
+                let content_type = content_type(&filename);
+                let metadata = if content_type.starts_with("image/") {
+                    image_size_vec(&buf, content_type)
+                        .map(|(width, height)| Metadata::Image {
+                            width: width as isize,
+                            height: height as isize,
+                            thumbhash: None,
+                            animated: None,
+                        })
+                        .unwrap_or_default()
+                } else if content_type.starts_with("audio/") {
+                    Metadata::Audio {
+                        duration: None,
+                        waveform: None,
+                    }
+                } else if content_type.starts_with("text/") {
+                    Metadata::Text
+                } else {
+                    Metadata::File
+                };
+
+                let file_hash = FileHash {
+                    id: hash.clone(),
+                    processed_hash: hash.clone(),
+
+                    created_at: Timestamp::now_utc(),
+
+                    bucket_id: config.files.s3.default_bucket,
+                    path: hash.clone(),
+                    iv: String::new(),
+
+                    metadata,
+                    content_type: content_type.to_owned(),
+                    size: buf.len() as isize,
+                };
+
+                if existing.is_err() {
+                    self.db.insert_attachment_hash(&file_hash).await?;
+                }
+
+                let nonce = upload_to_s3(&file_hash.bucket_id, &file_hash.path, &buf).await?;
+                self.db
+                    .set_attachment_hash_nonce(&file_hash.id, &nonce)
+                    .await?;
+                file_hash
+            }
+        };
+
+        let mut file = file_hash.into_file(
+            nanoid::nanoid!(42),
+            "attachments".to_owned(),
+            filename,
+            self.server.owner.clone(),
+        );
+
+        file.used_for = Some(used_for);
+        self.db.insert_attachment(&file).await?;
+        Ok(file)
+    }
+}
+
+/// Guess the content type of a file from its name
+fn content_type(filename: &str) -> &'static str {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "mp4" => "video/mp4",
+        "webm" => "video/webm",
+        "mov" => "video/quicktime",
+        "mp3" => "audio/mpeg",
+        "ogg" | "oga" => "audio/ogg",
+        "wav" => "audio/wav",
+        "txt" | "log" => "text/plain",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,95 @@
+use std::{collections::HashMap, path::PathBuf, process::exit};
+
+use formats::{is_slack_private_file, Format};
+use import::Importer;
+use log::{error, info};
+use revolt_database::DatabaseInfo;
+
+mod formats;
+mod import;
+
+const USAGE: &str = "\
+Usage: revolt-importer <discord|slack|telegram> <export> <server id> [user map]
+
+  export      DiscordChatExporter JSON file or directory of them,
+              extracted Slack export directory,
+              or Telegram Desktop export directory / result.json
+  server id   server to create the imported channels in
+  user map    JSON object of source user ids to account ids,
+              authors without an account are masqueraded through a webhook
+
+Environment:
+  SLACK_TOKEN token with the files:read scope, required to download
+              files from Slack exports";
+
+#[tokio::main]
+async fn main() {
+    let _guard = revolt_config::setup_logging(
+        concat!(env!("CARGO_PKG_NAME"), "@", env!("CARGO_PKG_VERSION")),
+        String::new(),
+    )
+    .await;
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.len() < 3 {
+        eprintln!("{USAGE}");
+        exit(1);
+    }
+
+    let format: Format = match args[0].parse() {
+        Ok(format) => format,
+        Err(err) => {
+            eprintln!("{err}\n\n{USAGE}");
+            exit(1);
+        }
+    };
+
+    let users: HashMap<String, String> = match args.get(3) {
+        Some(path) => {
+            let file = std::fs::read(path).expect("Failed to read user map");
+            serde_json::from_slice(&file).expect("Invalid user map")
+        }
+        None => HashMap::new(),
+    };
+
+    let channels = match format.read(&PathBuf::from(&args[1])) {
+        Ok(channels) => channels,
+        Err(err) => {
+            error!("Failed to read export: {err:?}");
+            exit(1);
+        }
+    };
+
+    // Slack only serves exported files to authenticated requests
+    let slack_token = std::env::var("SLACK_TOKEN").ok();
+    if slack_token.is_none()
+        && channels.iter().any(|channel| {
+            channel.messages.iter().any(|message| {
+                message
+                    .attachments
+                    .iter()
+                    .any(|attachment| is_slack_private_file(&attachment.source))
+            })
+        })
+    {
+        error!("Export contains files hosted by Slack, set SLACK_TOKEN to download them");
+        exit(1);
+    }
+
+    let db = DatabaseInfo::Auto.connect().await.expect("database");
+    let mut importer = Importer::new(&db, &args[2], users, slack_token)
+        .await
+        .expect("Failed to find server");
+
+    for channel in channels {
+        let name = channel.name.clone();
+        match importer.import_channel(channel).await {
+            Ok(count) => info!("Imported {count} messages into #{name}"),
+            Err(err) => {
+                // The failed channel has been removed, earlier channels are complete
+                error!("Failed to import #{name}, no messages were kept for it: {err:?}");
+                exit(1);
+            }
+        }
+    }
+}
@@ -40,6 +40,7 @@ deps() {
     crates/services/january/src \
     crates/services/gifbox/src \
     crates/daemons/crond/src \
+    crates/daemons/eventd/src \
     crates/daemons/pushd/src \
     crates/daemons/voice-ingress/src
   echo 'fn main() { panic!("stub"); }' |
@@ -49,6 +50,7 @@ deps() {
     tee crates/services/january/src/main.rs |
     tee crates/services/gifbox/src/main.rs |
     tee crates/daemons/crond/src/main.rs |
+    tee crates/daemons/eventd/src/main.rs |
     tee crates/daemons/pushd/src/main.rs |
     tee crates/daemons/voice-ingress/src/main.rs
   echo '' |
@@ -75,6 +77,7 @@ apps() {
     crates/bonfire/src/main.rs \
     crates/delta/src/main.rs \
     crates/daemons/crond/src/main.rs \
+    crates/daemons/eventd/src/main.rs \
     crates/daemons/pushd/src/main.rs \
     crates/daemons/voice-ingress/src/main.rs \
     crates/core/config/src/lib.rs \
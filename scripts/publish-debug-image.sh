@@ -27,6 +27,7 @@ docker build -t ghcr.io/stoatchat/autumn:$TAG - < crates/services/autumn/Dockerf
 docker build -t ghcr.io/stoatchat/january:$TAG - < crates/services/january/Dockerfile
 docker build -t ghcr.io/stoatchat/gifbox:$TAG - < crates/services/gifbox/Dockerfile
 docker build -t ghcr.io/stoatchat/crond:$TAG - < crates/daemons/crond/Dockerfile
+docker build -t ghcr.io/stoatchat/eventd:$TAG - < crates/daemons/eventd/Dockerfile
 docker build -t ghcr.io/stoatchat/pushd:$TAG - < crates/daemons/pushd/Dockerfile
 docker build -t ghcr.io/stoatchat/voice-ingress:$TAG - < crates/daemons/voice-ingress/Dockerfile
 
@@ -40,5 +41,6 @@ docker push ghcr.io/stoatchat/autumn:$TAG
 docker push ghcr.io/stoatchat/january:$TAG
 docker push ghcr.io/stoatchat/gifbox:$TAG
 docker push ghcr.io/stoatchat/crond:$TAG
+docker push ghcr.io/stoatchat/eventd:$TAG
 docker push ghcr.io/stoatchat/pushd:$TAG
 docker push ghcr.io/stoatchat/voice-ingress:$TAG